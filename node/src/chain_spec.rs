@@ -165,5 +165,6 @@ fn testnet_genesis(
 		},
 		transaction_payment: Default::default(),
 		treasury: Default::default(),
+		assets: Default::default(),
 	}
 }
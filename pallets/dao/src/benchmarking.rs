@@ -224,6 +224,48 @@ benchmarks! {
 		assert_eq!(PalletDao::<T>::members(org_id).len(), 1);
 		assert_last_event::<T>(Event::<T>::MemberRemoved (caller, account, org_id).into());
 	}
+
+	add_treasurer {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let name = vec![0u8, 1].try_into().unwrap();
+		let description = vec![0u8, 1].try_into().unwrap();
+		let vision = vec![0u8, 1].try_into().unwrap();
+
+		// Create account for treasurer
+		let account: T::AccountId = account("treasurer", 7, SEED);
+
+		let _ = PalletDao::<T>::create_organization(RawOrigin::Signed(caller.clone()).into(), name, description, vision);
+		let org_id = PalletDao::<T>::member_of(&caller)[0];
+
+	}: add_treasurer(RawOrigin::Signed(caller.clone()), org_id, account.clone())
+		/* the code to be benchmarked */
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TreasurerAdded (caller, account, org_id).into());
+	}
+
+	remove_treasurer {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let name = vec![0u8, 1].try_into().unwrap();
+		let description = vec![0u8, 1].try_into().unwrap();
+		let vision = vec![0u8, 1].try_into().unwrap();
+
+		// Create account for treasurer
+		let account: T::AccountId = account("treasurer", 7, SEED);
+
+		let _ = PalletDao::<T>::create_organization(RawOrigin::Signed(caller.clone()).into(), name, description, vision);
+		let org_id = PalletDao::<T>::member_of(&caller)[0];
+		let _ = PalletDao::<T>::add_treasurer(RawOrigin::Signed(caller.clone()).into(), org_id, account.clone());
+
+	}: remove_treasurer(RawOrigin::Signed(caller.clone()), org_id, account.clone())
+		/* the code to be benchmarked */
+	verify {
+		/* verifying final state */
+		assert!(PalletDao::<T>::treasurers(org_id).is_empty());
+		assert_last_event::<T>(Event::<T>::TreasurerRemoved (caller, account, org_id).into());
+	}
 }
 
 impl_benchmark_test_suite!(PalletDao, crate::mock::new_test_ext(), crate::mock::Test,);
\ No newline at end of file
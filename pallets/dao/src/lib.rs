@@ -92,6 +92,18 @@
 //!     Inputs:
 //!         - org_id: OrganizationIdOf<T>
 //!
+//! - `add_treasurer` - Function used for a visionary to designate a treasurer of his organization.
+//! Once an organization has treasurers, only they can spend from the organization account.
+//! Until then, every member can.
+//!     Inputs:
+//!         - org_id: OrganizationIdOf<T>
+//!         - account: AccountID
+//!
+//! - `remove_treasurer` - Function used for a visionary to remove a treasurer from his organization.
+//!     Inputs:
+//!         - org_id: OrganizationIdOf<T>
+//!         - account: AccountID
+//!
 //! Storage Items:
 //!     Vision: Vision document
 //!     VisionCount: Number of total visions in the system
@@ -100,6 +112,9 @@
 //!     Members: List the members of give organizations
 //!     MemberOf: Lists which organizations a single member belongs to
 //!     ApplicantsToOrganization: Lists who are the users who want to join an organization
+//!     Treasurers: Lists who can spend from the account of an organization
+//!
+//! Storage is versioned, see [`migrations`] for the migrations from older versions.
 //!
 //! ## Related Modules
 //!
@@ -117,6 +132,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod weights;
+pub mod migrations;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -146,6 +162,9 @@ pub mod pallet {
 	type BoundedMemberPerOrg<T> = BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxMembersPerOrganisation>;
 	type BoundedApplicantsPerOrg<T> = BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxApplicantsToOrganisation>;
 
+	/// The current storage version.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	/// Structure used to hold data associated with a vision.
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
@@ -210,6 +229,7 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -242,6 +262,11 @@ pub mod pallet {
 	/// Storage Map to indicate which user agree with a proposed Vision of an Organisation [OrganizationId, BoundedVec[Account]]
 	pub(super) type ApplicantsToOrganization<T: Config> = StorageMap<_, Twox64Concat, OrganizationIdOf<T>, BoundedApplicantsPerOrg<T>, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn treasurers)]
+	/// Storage Map of the accounts allowed to spend from the account of an organisation [OrganizationId, BoundedVec[Account]]
+	pub(super) type Treasurers<T: Config> = StorageMap<_, Twox64Concat, OrganizationIdOf<T>, BoundedMemberPerOrg<T>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -268,6 +293,12 @@ pub mod pallet {
 
 		/// Member removed from an organization [AccountID, AccountID, OrganisationId]
 		MemberRemoved(T::AccountId, T::AccountId, OrganizationIdOf<T>),
+
+		/// Treasurer has been added to an organization [AccountID, AccountID, OrganisationId]
+		TreasurerAdded(T::AccountId, T::AccountId, OrganizationIdOf<T>),
+
+		/// Treasurer removed from an organization [AccountID, AccountID, OrganisationId]
+		TreasurerRemoved(T::AccountId, T::AccountId, OrganizationIdOf<T>),
 	}
 
 	// Errors inform users that something went wrong.
@@ -309,6 +340,12 @@ pub mod pallet {
 		MaximumMembersReached,
 		/// Maximum applicants reached for this organisation.
 		MaximumApplicantsReached,
+		/// User is already a treasurer of this organisation.
+		AlreadyTreasurer,
+		/// User is not a treasurer of this organisation.
+		NotTreasurer,
+		/// Maximum number of treasurers per organsiation reached.
+		MaximumTreasurersReached,
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -451,6 +488,38 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Function for designating a treasurer of an organization [origin, org_id, AccountID]
+		#[pallet::weight(<T as Config>::WeightInfo::add_treasurer())]
+		pub fn add_treasurer(origin: OriginFor<T>, org_id: OrganizationIdOf<T>, account: T::AccountId) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let who = ensure_signed(origin)?;
+
+			// call function to add treasurer to organization
+			Self::add_treasurer_to_organization(&who, org_id, &account)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::TreasurerAdded(who, account, org_id));
+
+			Ok(())
+		}
+
+		/// Function for removing a treasurer from an organization [origin, org_id, AccountID]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_treasurer())]
+		pub fn remove_treasurer(origin: OriginFor<T>, org_id: OrganizationIdOf<T>, account: T::AccountId) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let who = ensure_signed(origin)?;
+
+			// call function to remove treasurer from organization
+			Self::remove_treasurer_from_organization(&who, org_id, &account)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::TreasurerRemoved(who, account, org_id));
+
+			Ok(())
+		}
 	}
 
 	// *** Helper functions *** //
@@ -459,6 +528,23 @@ pub mod pallet {
 			<Organizations<T>>::contains_key(org_id)
 		}
 
+		/// The account holding the funds of an organization.
+		pub fn account_id(org_id: &OrganizationIdOf<T>) -> T::AccountId
+			where T::AccountId : UncheckedFrom<T::Hash>,
+		{
+			UncheckedFrom::unchecked_from(*org_id)
+		}
+
+		/// Treasurers spend from the account of an organization. Without treasurers, every member can.
+		pub fn can_spend(org_id: &OrganizationIdOf<T>, account: &T::AccountId) -> bool {
+			let treasurers = Self::treasurers(org_id);
+			if treasurers.is_empty() {
+				Self::members(org_id).contains(account)
+			} else {
+				treasurers.contains(account)
+			}
+		}
+
 		fn new_org(from_initiator: &T::AccountId, name: BoundedNameOf<T>, description: BoundedDescriptionOf<T>, vision: BoundedVisionOf<T>) -> Result<OrganizationIdOf<T>, DispatchError> {
 			let current_block = <frame_system::Pallet<T>>::block_number();
 			
@@ -564,6 +650,7 @@ pub mod pallet {
 			// Remove Dao struct from Organizations storage
 			<Organizations<T>>::remove(org_id);
 			<Members<T>>::remove(org_id);
+			<Treasurers<T>>::remove(org_id);
 
 			// Reduce organization count
 			let new_count = Self::organization_count().saturating_sub(1);
@@ -636,6 +723,41 @@ pub mod pallet {
 			Ok(())
 		}
 
+		fn add_treasurer_to_organization(from_initiator: &T::AccountId, org_id: OrganizationIdOf<T>, account: &T::AccountId) -> Result<(), DispatchError> {
+			// Check if organization exists
+			ensure!(Self::does_organization_exist(&org_id), Error::<T>::InvalidOrganization);
+
+			// check if its DAO original creator
+			Self::is_dao_founder(from_initiator, org_id)?;
+
+			let mut treasurers = Self::treasurers(org_id);
+
+			// Check if already a treasurer
+			ensure!(!treasurers.contains(account), <Error<T>>::AlreadyTreasurer);
+
+			// Insert account into treasurers of the organization
+			ensure!(treasurers.try_push(account.clone()).is_ok(), Error::<T>::MaximumTreasurersReached);
+			<Treasurers<T>>::insert(org_id, treasurers);
+
+			Ok(())
+		}
+
+		fn remove_treasurer_from_organization(from_initiator: &T::AccountId, org_id: OrganizationIdOf<T>, account: &T::AccountId) -> Result<(), DispatchError> {
+			// Check if organization exists
+			ensure!(Self::does_organization_exist(&org_id), Error::<T>::InvalidOrganization);
+
+			// check if its DAO original creator
+			Self::is_dao_founder(from_initiator, org_id)?;
+
+			// Find treasurer and remove from Vector
+			let mut treasurers = Self::treasurers(org_id);
+			ensure!(treasurers.contains(account), Error::<T>::NotTreasurer);
+			treasurers.retain(|a| *a != *account);
+			<Treasurers<T>>::insert(org_id, treasurers);
+
+			Ok(())
+		}
+
 		fn member_signs_vision(from_initiator: &T::AccountId, org_id: OrganizationIdOf<T>) -> Result<(), DispatchError> {
 
 			// Verify that the specified organization has been created.
@@ -0,0 +1,82 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 UNIVERSALDOT FOUNDATION.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the dao pallet, following the pattern of `docs/migrations.md`.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_std::{marker::PhantomData, vec};
+
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+/// Version 1 introduces treasurers, who spend from the account of an organization.
+pub mod v1 {
+	use super::*;
+
+	/// Makes the founder the treasurer of every existing organization. Without treasurers every member
+	/// could spend from the organization account, which no organization agreed to before version 1.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 1 {
+				log::info!(target: "runtime::dao", "skipping v1 migration, storage is at {:?}", onchain_version);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut organizations = 0u64;
+			for (org_id, dao) in Organizations::<T>::iter() {
+				organizations += 1;
+				if let Ok(treasurers) = vec![dao.owner].try_into() {
+					Treasurers::<T>::insert(org_id, treasurers);
+				}
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::dao", "made the founders of {} organizations their treasurer", organizations);
+			T::DbWeight::get().reads_writes(organizations + 1, organizations + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 0, "dao storage is not at version 0");
+			ensure!(Treasurers::<T>::iter_keys().next().is_none(), "treasurers exist before version 1");
+			Self::set_temp_storage(Organizations::<T>::iter_keys().count() as u32, "organization_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "dao storage is not at version 1");
+			let organization_count: u32 = Self::get_temp_storage("organization_count").ok_or("organization count not stored before the upgrade")?;
+
+			let mut migrated = 0u32;
+			for (org_id, dao) in Organizations::<T>::iter() {
+				ensure!(Pallet::<T>::treasurers(org_id).into_inner() == vec![dao.owner], "founder is not the treasurer");
+				migrated += 1;
+			}
+			ensure!(migrated == organization_count, "organizations lost during the migration");
+			Ok(())
+		}
+	}
+}
@@ -641,3 +641,49 @@ fn test_members_are_mutated_on_ownership_transfer() {
 		assert!(Dao::organization_count() == 1);
 	});
 }
+
+#[test]
+fn treasurers_take_over_spending_from_members() {
+	new_test_ext().execute_with(|| {
+		let org_id = create_organization_1();
+		assert_ok!(Dao::add_members(Origin::signed(*ALICE), org_id, *BOB));
+
+		// Assert every member can spend until a treasurer is designated;
+		assert!(Dao::can_spend(&org_id, &*ALICE));
+		assert!(Dao::can_spend(&org_id, &*BOB));
+		assert!(!Dao::can_spend(&org_id, &*EVE));
+
+		// Ensure only the owner designates treasurers;
+		assert_noop!(Dao::add_treasurer(Origin::signed(*BOB), org_id, *BOB), Error::<Test>::NotOrganizationOwner);
+		assert_ok!(Dao::add_treasurer(Origin::signed(*ALICE), org_id, *BOB));
+		assert_eq!(last_event(), crate::Event::TreasurerAdded(*ALICE, *BOB, org_id));
+		assert_noop!(Dao::add_treasurer(Origin::signed(*ALICE), org_id, *BOB), Error::<Test>::AlreadyTreasurer);
+
+		// Assert only treasurers can spend once designated;
+		assert!(Dao::can_spend(&org_id, &*BOB));
+		assert!(!Dao::can_spend(&org_id, &*ALICE));
+
+		assert_ok!(Dao::remove_treasurer(Origin::signed(*ALICE), org_id, *BOB));
+		assert_noop!(Dao::remove_treasurer(Origin::signed(*ALICE), org_id, *BOB), Error::<Test>::NotTreasurer);
+		assert!(Dao::can_spend(&org_id, &*ALICE));
+	});
+}
+
+#[test]
+fn migration_to_v1_makes_founders_treasurers() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::{OnRuntimeUpgrade, StorageVersion, GetStorageVersion};
+
+		// Organizations of version 0 have no treasurers;
+		let org_id = create_organization_1();
+		assert!(Dao::treasurers(org_id).is_empty());
+		StorageVersion::new(0).put::<Dao>();
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		// Assert the founder is the only treasurer after the migration;
+		assert_eq!(Dao::treasurers(org_id).into_inner(), vec![*ALICE]);
+		assert!(!Dao::can_spend(&org_id, &*BOB));
+		assert_eq!(Dao::on_chain_storage_version(), 1);
+	});
+}
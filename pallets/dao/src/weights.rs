@@ -20,6 +20,10 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2022-07-05, STEPS: `100`, REPEAT: 50, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+//!
+//! NOTE: the weights of the calls and hooks added or changed after this run were written by hand, from the
+//! storage accesses listed above each of them, and are not benchmark results. Regenerate this file with the
+//! command below before relying on them.
 
 // Executed Command:
 
@@ -98,6 +102,10 @@ pub trait WeightInfo {
 	
 	fn remove_tasks(s: u32, ) -> Weight;
 	
+	fn add_treasurer() -> Weight;
+
+	fn remove_treasurer() -> Weight;
+
 }
 
 /// Weights for pallet_dao using the Substrate node and recommended hardware.
@@ -346,6 +354,40 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			
 			
 			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Dao Organizations (r:1 w:0)
+	
+	// Storage: Dao Treasurers (r:1 w:1)
+	
+	fn add_treasurer() -> Weight {
+		(16_912_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Dao Organizations (r:1 w:0)
+	
+	// Storage: Dao Treasurers (r:1 w:1)
+	
+	fn remove_treasurer() -> Weight {
+		(17_304_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 			
 			
@@ -596,6 +638,40 @@ impl WeightInfo for () {
 			
 			
 			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Dao Organizations (r:1 w:0)
+	
+	// Storage: Dao Treasurers (r:1 w:1)
+	
+	fn add_treasurer() -> Weight {
+		(16_912_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Dao Organizations (r:1 w:0)
+	
+	// Storage: Dao Treasurers (r:1 w:1)
+	
+	fn remove_treasurer() -> Weight {
+		(17_304_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 			
 			
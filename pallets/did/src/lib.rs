@@ -14,6 +14,8 @@
 //! * `valid_delegate` -
 //! * `is_owner` -
 //!
+//! Storage is versioned, see [`migrations`] for the migrations from older versions.
+//!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -37,6 +39,7 @@ pub use weights::WeightInfo;
 mod did;
 mod types;
 pub mod weights;
+pub mod migrations;
 
 #[cfg(test)]
 mod mock;
@@ -58,8 +61,12 @@ pub mod pallet {
 
     type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
 
+    /// The current storage version.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
@@ -0,0 +1,72 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 UNIVERSALDOT FOUNDATION.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the did pallet, following the pattern of `docs/migrations.md`.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+/// Version 1 starts versioning the storage of the pallet, with the layout of version 0.
+pub mod v1 {
+	use super::*;
+
+	/// Records storage version 1. Owners, delegates and attributes keep their layout.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 1 {
+				log::info!(target: "runtime::did", "skipping v1 migration, storage is at {:?}", onchain_version);
+				return T::DbWeight::get().reads(1);
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::did", "did storage versioned at v1");
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 0, "did storage is not at version 0");
+			Self::set_temp_storage(OwnerOf::<T>::iter_keys().count() as u32, "owner_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "did storage is not at version 1");
+			let owner_count: u32 = Self::get_temp_storage("owner_count").ok_or("owner count not stored before the upgrade")?;
+
+			// Every owner, delegate and attribute still decodes
+			ensure!(OwnerOf::<T>::iter_values().count() as u32 == owner_count, "owners lost during the migration");
+			DelegateOf::<T>::iter_values().for_each(drop);
+			AttributeOf::<T>::iter_values().for_each(drop);
+			UpdatedBy::<T>::iter_values().for_each(drop);
+			Ok(())
+		}
+	}
+}
@@ -331,3 +331,22 @@ fn add_remove_add_remove_attr() {
 fn delegate_type_matches() {
     assert_eq!(b"x25519VerificationKey2018", <Test as Config>::DelegateType::get().to_vec().as_slice())
 }
+
+#[test]
+fn migration_to_v1_keeps_owners() {
+    new_test_ext().execute_with(|| {
+        use frame_support::{storage::migration::put_storage_value, traits::{OnRuntimeUpgrade, StorageVersion, GetStorageVersion}, Blake2_128Concat, StorageHasher};
+
+        // Store a version 0 owner
+        let identity = account_key("Alice");
+        let owner = account_key("Bob");
+        put_storage_value(b"Did", b"OwnerOf", &Blake2_128Concat::hash(&identity.encode()), owner);
+        StorageVersion::new(0).put::<DID>();
+
+        crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(DID::on_chain_storage_version(), 1);
+
+        // Ensure the owner decodes unchanged
+        assert_eq!(DID::owner_of(identity), Some(owner));
+    });
+}
@@ -51,6 +51,8 @@
 //!  -  winner_is()
 //!     Function that announces the winner of the block.
 //!
+//! Storage is versioned, see [`migrations`] for the migrations from older versions.
+//!
 //! ## Related Modules
 //!
 
@@ -67,6 +69,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod weights;
+pub mod migrations;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -132,8 +135,12 @@ pub mod pallet {
 		type ExistentialDeposit: Get<BalanceOf<Self>>;
 	}
 
+	/// The current storage version.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -0,0 +1,70 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 UNIVERSALDOT FOUNDATION.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the grant pallet, following the pattern of `docs/migrations.md`.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+/// Version 1 starts versioning the storage of the pallet, with the layout of version 0.
+pub mod v1 {
+	use super::*;
+
+	/// Records storage version 1. Requesters and their count keep their layout.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 1 {
+				log::info!(target: "runtime::grant", "skipping v1 migration, storage is at {:?}", onchain_version);
+				return T::DbWeight::get().reads(1);
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::grant", "grant storage versioned at v1");
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 0, "grant storage is not at version 0");
+			Self::set_temp_storage(RequestersCount::<T>::get(), "requesters_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "grant storage is not at version 1");
+			let requesters_count: u16 = Self::get_temp_storage("requesters_count").ok_or("requesters count not stored before the upgrade")?;
+
+			// Every requester still decodes, and the count still matches the requesters
+			ensure!(RequestersCount::<T>::get() == requesters_count, "requesters count changed during the migration");
+			ensure!(StorageRequesters::<T>::iter_values().count() == requesters_count as usize, "requesters count does not match the requesters");
+			Ok(())
+		}
+	}
+}
@@ -276,3 +276,25 @@ fn test_max_requestors_errs_gracefully() {
 	});
 }
 
+#[test]
+fn migration_to_v1_keeps_requesters() {
+	new_test_ext().execute_with(|| {
+		use codec::Encode;
+		use frame_support::{storage::migration::put_storage_value, traits::{OnRuntimeUpgrade, StorageVersion, GetStorageVersion}, Twox64Concat, StorageHasher};
+
+		// Store a version 0 requester
+		put_storage_value(b"Grant", b"StorageRequesters", &Twox64Concat::hash(&ALICE.encode()), (*ALICE, 1u64));
+		put_storage_value(b"Grant", b"RequestersCount", &[], 1u16);
+		StorageVersion::new(0).put::<Grant>();
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Grant::on_chain_storage_version(), 1);
+
+		// Ensure the requester and the count decode unchanged
+		let requester = Grant::storage_requesters(*ALICE).expect("should find the requester");
+		assert_eq!(requester.owner, *ALICE);
+		assert_eq!(requester.block_number, 1);
+		assert_eq!(Grant::requesters_count(), 1);
+	});
+}
+
@@ -56,6 +56,9 @@ fn create_profile_info<T: Config>(_num_fields: u32) -> Profile<T> {
 		available_hours_per_week,
 		additional_information: None,
 		location: None,
+		average_rating: 0,
+		review_count: 0,
+		last_reviewed_at: None,
 	}
 }
 
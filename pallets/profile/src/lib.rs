@@ -29,6 +29,12 @@
 //! The Profile is used to enrich the AccountID information with user specific
 //! metadata such as personal interests, name, reputation, etc.
 //!
+//! Profiles also aggregate the 1-5 ratings the account received from the other party
+//! of accepted tasks: the average rating, the number of reviews and when it was last reviewed.
+//!
+//! Locations are stored as NAD `(x, y)` coordinates, the same order tasks store them in.
+//! Storage version 1 swapped the `(y, x)` locations of older profiles, see [`migrations`].
+//!
 //! ## Interface
 //!
 //! ### Public Functions
@@ -81,6 +87,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod weights;
+pub mod migrations;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -94,7 +101,10 @@ pub mod pallet {
 
 	// Account, Balance are used in Profile Struct
 	type AccountOf<T> = <T as frame_system::Config>::AccountId;
-	type NadLocation = ([u8; 5], [u8; 5]);
+	pub type NadLocation = ([u8; 5], [u8; 5]);
+
+	/// The current storage version.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	// Struct for holding Profile information.
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -106,9 +116,15 @@ pub mod pallet {
 		pub reputation: u32,
 		pub available_hours_per_week: u8,
 		pub additional_information: Option<BoundedVec<u8, T::MaxAdditionalInformationLen>>,
-		/// Longitude, Latitude 
+		/// NAD x (longitude) and y (latitude) coordinates
 		pub location: Option<NadLocation>,
 		pub profile_id: T::Hash,
+		/// The average rating received, in hundredths of a star.
+		pub average_rating: u32,
+		/// The number of ratings received.
+		pub review_count: u32,
+		/// The block the profile was last rated on.
+		pub last_reviewed_at: Option<<T as frame_system::Config>::BlockNumber>,
 	}
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -142,6 +158,7 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -264,7 +281,7 @@ pub mod pallet {
 
 			let mut location: Option<NadLocation> = None;
 			if x.is_some() && y.is_some() {
-				location = Some((y.unwrap(), x.unwrap()));
+				location = Some((x.unwrap(), y.unwrap()));
 			}			
 
 			// Populate Profile struct
@@ -277,6 +294,9 @@ pub mod pallet {
 				additional_information,
 				location,
 				profile_id: T::Hashing::hash_of(&12345),
+				average_rating: 0,
+				review_count: 0,
+				last_reviewed_at: None,
 			};
 
 			// Get hash of profile
@@ -315,7 +335,7 @@ pub mod pallet {
 
 			let mut location: Option<NadLocation> = None;
 			if x.is_some() && y.is_some() {
-				location = Some((y.unwrap(), x.unwrap()));
+				location = Some((x.unwrap(), y.unwrap()));
 			}
 
 			profile.location = location;
@@ -358,6 +378,36 @@ pub mod pallet {
 			Ok(())
 		}
 
+		// Public function that removes reputation from a profile
+		pub fn remove_reputation(owner: &T::AccountId) -> Result<(), DispatchError> {
+
+			// Get current profile
+			let mut profile = Self::profiles(owner).ok_or(<Error<T>>::NoProfileCreated)?;
+
+			// Decrease reputation
+			profile.decrease_reputation();
+
+			// Insert into storage a new profile
+			<Profiles<T>>::insert(owner, profile);
+
+			Ok(())
+		}
+
+		// Public function that adds a 1-5 rating to the average rating of a profile
+		pub fn add_rating(owner: &T::AccountId, rating: u8) -> Result<(), DispatchError> {
+
+			// Get current profile
+			let mut profile = Self::profiles(owner).ok_or(<Error<T>>::NoProfileCreated)?;
+
+			// Update the average rating
+			profile.record_rating(rating, <frame_system::Pallet<T>>::block_number());
+
+			// Insert into storage a new profile
+			<Profiles<T>>::insert(owner, profile);
+
+			Ok(())
+		}
+
 		// Public function that check if user has a profile
 		pub fn has_profile(owner: &T::AccountId) -> Result<bool, DispatchError>  {
 
@@ -389,7 +439,14 @@ pub mod pallet {
 		}
 
 		pub fn decrease_reputation(&mut self) {
-			self.reputation -= 1;
+			self.reputation = self.reputation.saturating_sub(1);
+		}
+
+		pub fn record_rating(&mut self, rating: u8, now: <T as frame_system::Config>::BlockNumber) {
+			let total = (self.average_rating as u64) * (self.review_count as u64) + (rating as u64) * 100;
+			self.review_count = self.review_count.saturating_add(1);
+			self.average_rating = (total / self.review_count as u64) as u32;
+			self.last_reviewed_at = Some(now);
 		}
 	}
 
@@ -0,0 +1,105 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 UNIVERSALDOT FOUNDATION.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the profile pallet, following the pattern of `docs/migrations.md`.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::bounded_vec::BoundedVec,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+/// Version 1 stores profile locations as `(x, y)`, like tasks do, and adds the rating aggregate.
+pub mod v1 {
+	use super::*;
+
+	/// Profile layout of storage version 0, with its location stored as `(y, x)`.
+	#[derive(Decode)]
+	pub struct OldProfile<T: Config> {
+		pub owner: T::AccountId,
+		pub name: BoundedVec<u8, T::MaxUsernameLen>,
+		pub interests: BoundedVec<u8, T::MaxInterestsLen>,
+		pub reputation: u32,
+		pub available_hours_per_week: u8,
+		pub additional_information: Option<BoundedVec<u8, T::MaxAdditionalInformationLen>>,
+		pub location: Option<NadLocation>,
+		pub profile_id: T::Hash,
+	}
+
+	/// Swaps the coordinates of every profile location, and starts every profile without ratings.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 1 {
+				log::info!(target: "runtime::profile", "skipping v1 migration, storage is at {:?}", onchain_version);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			Profiles::<T>::translate::<OldProfile<T>, _>(|_, old| {
+				translated += 1;
+				Some(Profile {
+					owner: old.owner,
+					name: old.name,
+					interests: old.interests,
+					reputation: old.reputation,
+					available_hours_per_week: old.available_hours_per_week,
+					additional_information: old.additional_information,
+					location: old.location.map(|(y, x)| (x, y)),
+					profile_id: old.profile_id,
+					average_rating: 0,
+					review_count: 0,
+					last_reviewed_at: None,
+				})
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::profile", "migrated {} profiles to v1", translated);
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 0, "profile storage is not at version 0");
+			Self::set_temp_storage(Profiles::<T>::iter_keys().count() as u32, "profile_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "profile storage is not at version 1");
+			let profile_count: u32 = Self::get_temp_storage("profile_count").ok_or("profile count not stored before the upgrade")?;
+
+			// Every profile decodes with the new layout, without ratings yet
+			let mut migrated = 0u32;
+			for profile in Profiles::<T>::iter_values() {
+				ensure!(profile.review_count == 0, "profile rated during the migration");
+				migrated += 1;
+			}
+			ensure!(migrated == profile_count, "profiles lost during the migration");
+			Ok(())
+		}
+	}
+}
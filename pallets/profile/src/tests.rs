@@ -56,8 +56,8 @@ fn verify_inputs_outputs_to_profile(){
 		// Get profile for current account
 		let profile = Profile::profiles(10).expect("should found the profile");
 
-		// Latitude, Longitude
-		let location = (*b"ABCDE", [1, 2, 3, 4, 5]);
+		// Longitude, Latitude
+		let location = ([1, 2, 3, 4, 5], *b"ABCDE");
 
 
 		// Ensure that profile properties are assigned correctly
@@ -70,6 +70,32 @@ fn verify_inputs_outputs_to_profile(){
 	});
 }
 
+#[test]
+fn add_rating_updates_average_rating() {
+	new_test_ext().execute_with(|| {
+
+		// Rating a missing profile fails
+		assert_noop!(Profile::add_rating(&10, 5), Error::<Test>::NoProfileCreated);
+
+		assert_ok!(Profile::create_profile(Origin::signed(10), username(), interests(), HOURS, Some(additional_info()), Some(longitude()), Some(latitude())));
+		let profile = Profile::profiles(10).expect("should found the profile");
+		assert_eq!(profile.average_rating, 0);
+		assert_eq!(profile.review_count, 0);
+		assert_eq!(profile.last_reviewed_at, None);
+
+		System::set_block_number(5);
+		assert_ok!(Profile::add_rating(&10, 5));
+		assert_ok!(Profile::add_rating(&10, 4));
+		assert_ok!(Profile::add_rating(&10, 4));
+
+		// Average is kept in hundredths of a star
+		let profile = Profile::profiles(10).expect("should found the profile");
+		assert_eq!(profile.average_rating, 433);
+		assert_eq!(profile.review_count, 3);
+		assert_eq!(profile.last_reviewed_at, Some(5));
+	});
+}
+
 #[test]
 fn create_profile_increases_profile_count() {
 	new_test_ext().execute_with(|| {
@@ -174,3 +200,25 @@ fn user_can_only_update_own_profile() {
 		assert_noop!(Profile::update_profile(Origin::signed(2), username2(), interests2(), HOURS, Some(additional_info()), None, None), Error::<Test>::NoProfileCreated);
 	});
 }
+
+#[test]
+fn migration_to_v1_swaps_profile_locations() {
+	new_test_ext().execute_with(|| {
+		use codec::Encode;
+		use frame_support::{storage::migration::put_storage_value, traits::{OnRuntimeUpgrade, StorageVersion, GetStorageVersion}, Twox64Concat, StorageHasher};
+
+		// Store a version 0 profile, with its location as (y, x)
+		let old_profile = (10u128, username(), interests(), 7u32, HOURS, Some(additional_info()), Some((latitude(), longitude())), sp_core::H256::zero());
+		put_storage_value(b"Profile", b"Profiles", &Twox64Concat::hash(&10u128.encode()), old_profile);
+		StorageVersion::new(0).put::<Profile>();
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		// Ensure the location is now (x, y) and the profile has no ratings yet
+		let profile = Profile::profiles(10).expect("should found the profile");
+		assert_eq!(profile.location, Some((longitude(), latitude())));
+		assert_eq!(profile.reputation, 7);
+		assert_eq!(profile.review_count, 0);
+		assert_eq!(Profile::on_chain_storage_version(), 1);
+	});
+}
@@ -0,0 +1,190 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 UNIVERSALDOT FOUNDATION.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for querying tasks of the task pallet.
+//!
+//! Every query is paginated. Pass the `next_cursor` of a page to get the page after it;
+//! the last page has no cursor.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use frame_support::traits::Currency;
+use pallet_task::{NadLocation, Submission, Task, TaskStatus, VolunteerBond};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+type BalanceOf<T> = <<T as pallet_task::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Deliverables submitted when completing a task, returned to clients.
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct SubmissionInfo<AccountId, BlockNumber> {
+	pub volunteer: AccountId,
+	/// The content hash or IPFS CID of every deliverable, with its description.
+	pub deliverables: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+	pub submitted_at: BlockNumber,
+}
+
+impl<T: pallet_task::Config> From<Submission<T>> for SubmissionInfo<T::AccountId, T::BlockNumber> {
+	fn from(submission: Submission<T>) -> Self {
+		SubmissionInfo {
+			volunteer: submission.volunteer,
+			deliverables: submission.deliverables.into_iter()
+				.map(|deliverable| (deliverable.reference.into_inner(), deliverable.description.map(|description| description.into_inner())))
+				.collect(),
+			submitted_at: submission.submitted_at,
+		}
+	}
+}
+
+/// Task information returned to clients.
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TaskInfo<AccountId, AssetId, Balance, BlockNumber, Hash> {
+	pub task_id: Hash,
+	pub parent: Option<Hash>,
+	pub title: Vec<u8>,
+	pub specification: Vec<u8>,
+	pub attachments: Vec<u8>,
+	pub keywords: Vec<u8>,
+	pub feedback: Option<Vec<u8>>,
+	pub initiator: AccountId,
+	/// Every volunteer working on the task. Empty until the task has been started.
+	pub volunteers: Vec<AccountId>,
+	pub current_owner: AccountId,
+	pub status: TaskStatus,
+	pub budget: Balance,
+	/// The asset the budget is paid in. None for the native currency.
+	pub asset: Option<AssetId>,
+	pub deadline: u64,
+	pub deadline_block: Option<BlockNumber>,
+	/// Whether the deadline was given as a block number rather than a timestamp.
+	pub deadline_by_block: bool,
+	pub delivery_deadline: Option<BlockNumber>,
+	pub organization: Option<Hash>,
+	pub location: Option<NadLocation>,
+	pub open: bool,
+	/// The template, and the version of the template, the task was created from.
+	pub template: Option<(Hash, u32)>,
+	/// The account the budget is drawn from and refunded to.
+	pub funder: AccountId,
+	/// The bond volunteers reserve to start the task. None when no bond is required.
+	pub bond: Option<VolunteerBond<Balance>>,
+	/// Every submission of deliverables, oldest first, kept across rejections.
+	pub submissions: Vec<SubmissionInfo<AccountId, BlockNumber>>,
+	pub created_at: BlockNumber,
+	pub updated_at: BlockNumber,
+	pub completed_at: BlockNumber,
+}
+
+impl<T: pallet_task::Config> From<Task<T>> for TaskInfo<T::AccountId, T::AssetId, BalanceOf<T>, T::BlockNumber, T::Hash> {
+	fn from(task: Task<T>) -> Self {
+		let volunteers = pallet_task::Pallet::<T>::participants(&task).into_iter()
+			.filter(|volunteer| *volunteer != task.initiator)
+			.collect();
+		let submissions = pallet_task::Pallet::<T>::submissions(&task.task_id).into_iter()
+			.map(Into::into)
+			.collect();
+
+		TaskInfo {
+			task_id: task.task_id,
+			parent: task.parent,
+			title: task.title.into_inner(),
+			specification: task.specification.into_inner(),
+			attachments: task.attachments.into_inner(),
+			keywords: task.keywords.into_inner(),
+			feedback: task.feedback.map(|feedback| feedback.into_inner()),
+			initiator: task.initiator,
+			volunteers,
+			current_owner: task.current_owner,
+			status: task.status,
+			budget: task.budget,
+			asset: task.asset,
+			deadline: task.deadline,
+			deadline_block: task.deadline_block,
+			deadline_by_block: task.deadline_by_block,
+			delivery_deadline: task.delivery_deadline,
+			organization: task.organization,
+			location: task.location,
+			open: task.open,
+			template: task.template,
+			funder: task.funder,
+			bond: task.bond,
+			submissions,
+			created_at: task.created_at,
+			updated_at: task.updated_at,
+			completed_at: task.completed_at,
+		}
+	}
+}
+
+/// A page of tasks returned to clients.
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash> {
+	pub tasks: Vec<TaskInfo<AccountId, AssetId, Balance, BlockNumber, Hash>>,
+	/// The cursor to query the next page with. None on the last page.
+	pub next_cursor: Option<Hash>,
+}
+
+impl<T: pallet_task::Config> From<pallet_task::TaskPage<T>> for TaskPage<T::AccountId, T::AssetId, BalanceOf<T>, T::BlockNumber, T::Hash> {
+	fn from((tasks, next_cursor): pallet_task::TaskPage<T>) -> Self {
+		TaskPage {
+			tasks: tasks.into_iter().map(Into::into).collect(),
+			next_cursor,
+		}
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait TaskApi<AccountId, AssetId, Balance, BlockNumber, Hash> where
+		AccountId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		Hash: Codec,
+	{
+		/// Returns the tasks with the given status.
+		fn tasks_by_status(status: TaskStatus, cursor: Option<Hash>, limit: u32) -> TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>;
+
+		/// Returns the tasks currently owned by an account.
+		fn tasks_by_owner(owner: AccountId, cursor: Option<Hash>, limit: u32) -> TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>;
+
+		/// Returns the tasks of an organization.
+		fn tasks_by_organization(organization: Hash, cursor: Option<Hash>, limit: u32) -> TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>;
+
+		/// Returns the tasks whose keywords contain the given keyword.
+		fn tasks_by_keyword(keyword: Vec<u8>, cursor: Option<Hash>, limit: u32) -> TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>;
+
+		/// Returns the created tasks whose deadline falls before the given block.
+		fn tasks_expiring_before(block: BlockNumber, cursor: Option<Hash>, limit: u32) -> TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>;
+
+		/// Returns the created tasks located within the box between two NAD locations.
+		fn tasks_in_area(min: NadLocation, max: NadLocation, cursor: Option<Hash>, limit: u32) -> TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>;
+
+		/// Returns the created tasks located within a radius, in grid cells, around the location of a profile.
+		fn tasks_near(account: AccountId, radius: u16, cursor: Option<Hash>, limit: u32) -> TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>;
+	}
+}
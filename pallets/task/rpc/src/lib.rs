@@ -0,0 +1,186 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 UNIVERSALDOT FOUNDATION.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for querying tasks of the task pallet.
+//!
+//! Every method takes an optional page size, capped at `MAX_TASKS_PER_PAGE`, and an optional
+//! block hash to query at. By default the best block is queried.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use pallet_task::{NadLocation, TaskStatus, MAX_TASKS_PER_PAGE};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_task_rpc_runtime_api::{TaskApi as TaskRuntimeApi, TaskInfo, TaskPage};
+
+#[rpc(client, server)]
+pub trait TaskApi<BlockHash, AccountId, AssetId, Balance, BlockNumber, Hash> {
+	/// Returns the tasks with the given status.
+	#[method(name = "task_tasksByStatus")]
+	fn tasks_by_status(&self, status: TaskStatus, cursor: Option<Hash>, limit: Option<u32>, at: Option<BlockHash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>;
+
+	/// Returns the tasks currently owned by an account.
+	#[method(name = "task_tasksByOwner")]
+	fn tasks_by_owner(&self, owner: AccountId, cursor: Option<Hash>, limit: Option<u32>, at: Option<BlockHash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>;
+
+	/// Returns the tasks of an organization.
+	#[method(name = "task_tasksByOrganization")]
+	fn tasks_by_organization(&self, organization: Hash, cursor: Option<Hash>, limit: Option<u32>, at: Option<BlockHash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>;
+
+	/// Returns the tasks whose keywords contain the given keyword.
+	#[method(name = "task_tasksByKeyword")]
+	fn tasks_by_keyword(&self, keyword: Bytes, cursor: Option<Hash>, limit: Option<u32>, at: Option<BlockHash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>;
+
+	/// Returns the created tasks whose deadline falls before the given block.
+	#[method(name = "task_tasksExpiringBefore")]
+	fn tasks_expiring_before(&self, block: BlockNumber, cursor: Option<Hash>, limit: Option<u32>, at: Option<BlockHash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>;
+
+	/// Returns the created tasks located within the box between two NAD locations.
+	#[method(name = "task_tasksInArea")]
+	fn tasks_in_area(&self, min: NadLocation, max: NadLocation, cursor: Option<Hash>, limit: Option<u32>, at: Option<BlockHash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>;
+
+	/// Returns the created tasks located within a radius, in grid cells, around the location of a profile.
+	#[method(name = "task_tasksNear")]
+	fn tasks_near(&self, account: AccountId, radius: u16, cursor: Option<Hash>, limit: Option<u32>, at: Option<BlockHash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>;
+}
+
+/// Provides RPC methods to query tasks.
+pub struct Task<C, Block> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Task<C, Block> {
+	/// Creates a new instance of the Task RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId, AssetId, Balance, BlockNumber, Hash> TaskApiServer<<Block as BlockT>::Hash, AccountId, AssetId, Balance, BlockNumber, Hash> for Task<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TaskRuntimeApi<Block, AccountId, AssetId, Balance, BlockNumber, Hash>,
+	AccountId: Codec + Send + Sync + 'static,
+	AssetId: Codec + Send + Sync + 'static,
+	Balance: Codec + Send + Sync + 'static,
+	BlockNumber: Codec + Send + Sync + 'static,
+	Hash: Codec + Send + Sync + 'static,
+{
+	fn tasks_by_status(&self, status: TaskStatus, cursor: Option<Hash>, limit: Option<u32>, at: Option<Block::Hash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>
+	{
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.tasks_by_status(&at, status, cursor, page_size(limit)).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn tasks_by_owner(&self, owner: AccountId, cursor: Option<Hash>, limit: Option<u32>, at: Option<Block::Hash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>
+	{
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.tasks_by_owner(&at, owner, cursor, page_size(limit)).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn tasks_by_organization(&self, organization: Hash, cursor: Option<Hash>, limit: Option<u32>, at: Option<Block::Hash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>
+	{
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.tasks_by_organization(&at, organization, cursor, page_size(limit)).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn tasks_by_keyword(&self, keyword: Bytes, cursor: Option<Hash>, limit: Option<u32>, at: Option<Block::Hash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>
+	{
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.tasks_by_keyword(&at, keyword.to_vec(), cursor, page_size(limit)).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn tasks_expiring_before(&self, block: BlockNumber, cursor: Option<Hash>, limit: Option<u32>, at: Option<Block::Hash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>
+	{
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.tasks_expiring_before(&at, block, cursor, page_size(limit)).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn tasks_in_area(&self, min: NadLocation, max: NadLocation, cursor: Option<Hash>, limit: Option<u32>, at: Option<Block::Hash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>
+	{
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.tasks_in_area(&at, min, max, cursor, page_size(limit)).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn tasks_near(&self, account: AccountId, radius: u16, cursor: Option<Hash>, limit: Option<u32>, at: Option<Block::Hash>)
+		-> RpcResult<TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash>>
+	{
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.tasks_near(&at, account, radius, cursor, page_size(limit)).map_err(runtime_error_into_rpc_err)
+	}
+}
+
+// Pages are as large as allowed unless a smaller page is requested.
+fn page_size(limit: Option<u32>) -> u32 {
+	limit.unwrap_or(MAX_TASKS_PER_PAGE).min(MAX_TASKS_PER_PAGE)
+}
+
+// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query tasks.",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
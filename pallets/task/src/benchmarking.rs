@@ -27,11 +27,12 @@ use crate::{
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller, vec, Vec};
 use frame_system::{RawOrigin};
 use frame_support::{
-	traits::{Currency, Get},
+	traits::{Currency, Get, tokens::fungibles},
 	BoundedVec,
 
 };
 use sp_core::crypto::UncheckedFrom;
+use frame_support::sp_runtime::{traits::{Zero, One}, Percent};
 use pallet_profile::Pallet as PalletProfile;
 use pallet_dao::{
 	Pallet as PalletDao,
@@ -94,8 +95,125 @@ where
 	PalletDao::<T>::member_of(whitelisted_caller::<T::AccountId>())[0]
 }
 
+// Helper to create a task owned by the caller, returns TaskID
+fn setup_task<T: Config>(caller: &T::AccountId, deadline: u64) -> T::Hash
+where T: pallet_profile::Config
+{
+	let title = vec![0u8, 1];
+	let specification = vec![0u8, 1];
+	let attachments = vec![0u8, 1];
+	let keywords = vec![0u8, 1];
+	let budget = <T as pallet::Config>::Currency::total_balance(caller);
+
+	// Create profile before creating a task
+	create_profile::<T>();
+	let _ = PalletTask::<T>::create_task(
+		RawOrigin::Signed(caller.clone()).into(), title.try_into().unwrap(), specification.try_into().unwrap(), budget,
+		deadline, attachments.try_into().unwrap(), keywords.try_into().unwrap(), None, Some([8u8; 5]), Some([8u8; 5]));
+	PalletTask::<T>::tasks_owned(caller)[0]
+}
+
+// Helper to build the largest submission of deliverables
+fn deliverables<T: Config>() -> BoundedVec<Deliverable<T>, <T as ConfigTask>::MaxDeliverablesPerSubmission> {
+	let deliverable = Deliverable::<T> {
+		reference: vec![0u8; <T as ConfigTask>::MaxDeliverableReferenceLen::get() as usize].try_into().unwrap(),
+		description: Some(vec![0u8; <T as ConfigTask>::MaxDeliverableDescriptionLen::get() as usize].try_into().unwrap()),
+	};
+	vec![deliverable; <T as ConfigTask>::MaxDeliverablesPerSubmission::get() as usize].try_into().unwrap()
+}
+
+// Helper to split a task into a single milestone
+fn setup_milestone<T: Config>(caller: &T::AccountId, task_id: T::Hash, deadline: u64) {
+	let title = vec![0u8; <T as ConfigTask>::MaxTitleLen::get() as usize];
+	let _ = PalletTask::<T>::add_milestone(
+		RawOrigin::Signed(caller.clone()).into(), task_id, title.try_into().unwrap(), <T as pallet::Config>::Currency::minimum_balance(), deadline);
+}
+
+// Helper to create a profile for the applicant and apply for a task
+fn setup_application<T: Config>(applicant: &T::AccountId, task_id: T::Hash)
+where T: pallet_profile::Config
+{
+	let _ = PalletProfile::<T>::create_profile(
+		RawOrigin::Signed(applicant.clone()).into(), Vec::new().try_into().unwrap(),
+		Vec::new().try_into().unwrap(), 40_u8, None, Some([8u8; 5]), Some([8u8; 5])
+	);
+	let cover_note = vec![0u8; <T as ConfigTask>::MaxCoverNoteLen::get() as usize];
+	let _ = PalletTask::<T>::apply_for_task(
+		RawOrigin::Signed(applicant.clone()).into(), task_id, cover_note.try_into().unwrap(), Some(Zero::zero()));
+}
+
+// Helper to create a task that has been completed by the volunteer and rejected by the caller, returns TaskID
+fn setup_rejected_task<T: Config>(caller: &T::AccountId, volunteer: &T::AccountId, deadline: u64) -> T::Hash
+where T: pallet_profile::Config
+{
+	let hash_task = setup_task::<T>(caller, deadline);
+	let feedback = vec![0u8; <T as ConfigTask>::MaxFeedbackLen::get() as usize];
+	let _ = PalletTask::<T>::start_task(RawOrigin::Signed(volunteer.clone()).into(), hash_task);
+	let _ = PalletTask::<T>::complete_task(RawOrigin::Signed(volunteer.clone()).into(), hash_task, deliverables::<T>());
+	let _ = PalletTask::<T>::reject_task(RawOrigin::Signed(caller.clone()).into(), hash_task, feedback.try_into().unwrap());
+
+	// Fund the caller for the dispute deposit
+	let deposit = <T as ConfigTask>::DisputeDeposit::get();
+	<T as ConfigTask>::Currency::make_free_balance_be(caller, deposit + deposit);
+	hash_task
+}
+
+// Helper to fill the arbitrator pool with as many arbitrators as are drawn per dispute
+fn setup_arbitrators<T: Config>() -> Vec<T::AccountId> {
+	let arbitrators: Vec<T::AccountId> = (0 .. <T as ConfigTask>::ArbitratorsPerDispute::get())
+		.map(|i| account("arbitrator", i, SEED))
+		.collect();
+	let _ = PalletTask::<T>::set_arbitrator_pool(RawOrigin::Root.into(), arbitrators.clone().try_into().unwrap());
+	arbitrators
+}
+
+// Helper to create a sufficient asset and fund the caller with it, returns AssetID
+fn setup_asset<T: Config>(caller: &T::AccountId) -> T::AssetId
+where T::Assets: fungibles::Create<T::AccountId> + fungibles::Mutate<T::AccountId>
+{
+	let asset = T::AssetId::default();
+	let _ = <T::Assets as fungibles::Create<T::AccountId>>::create(asset, caller.clone(), true, One::one());
+	let _ = <T::Assets as fungibles::Mutate<T::AccountId>>::mint_into(asset, caller, 2_000_000u32.into());
+	asset
+}
+
+// Helper to create a task paid in an asset and owned by the caller, returns TaskID
+fn setup_asset_task<T: Config>(caller: &T::AccountId, asset: T::AssetId) -> T::Hash
+where T: pallet_profile::Config
+{
+	let title = vec![0u8; <T as ConfigTask>::MaxTitleLen::get() as usize];
+	let specification = vec![0u8; <T as ConfigTask>::MaxSpecificationLen::get() as usize];
+
+	create_profile::<T>();
+	let _ = PalletTask::<T>::create_task_with_options(
+		RawOrigin::Signed(caller.clone()).into(), title.try_into().unwrap(), specification.try_into().unwrap(), 1_000_000u32.into(),
+		TaskDeadline::Timestamp(2000), Vec::new().try_into().unwrap(), Vec::new().try_into().unwrap(), None, Some([8u8; 5]), Some([8u8; 5]), true, Some(asset));
+	PalletTask::<T>::tasks_owned(caller)[0]
+}
+
+
+// Helper to create a template owned by an organisation of the caller, returns TemplateID
+fn setup_template<T: Config>(caller: &T::AccountId) -> T::Hash
+where
+	T: pallet_dao::Config,
+	T::AccountId: UncheckedFrom<T::Hash>
+{
+	let title = vec![0u8; <T as ConfigTask>::MaxTitleLen::get() as usize];
+	let organization = create_organisation::<T>();
+	let _ = PalletTask::<T>::create_template(
+		RawOrigin::Signed(caller.clone()).into(), title.try_into().unwrap(), Vec::new().try_into().unwrap(),
+		Vec::new().try_into().unwrap(), Vec::new().try_into().unwrap(), Some(organization), true);
+	pallet::Templates::<T>::iter_keys().next().unwrap()
+}
 
 benchmarks! {
+	where_clause {
+		where
+			T::Assets: fungibles::Create<T::AccountId> + fungibles::Mutate<T::AccountId>,
+			T: pallet_dao::Config + pallet_profile::Config,
+			T::AccountId: UncheckedFrom<T::Hash>
+	}
+
 	create_task {
 		/* setup initial state */
 		let caller: T::AccountId = whitelisted_caller();
@@ -258,7 +376,7 @@ benchmarks! {
 		let hash_task = PalletTask::<T>::tasks_owned(&caller)[0];
 		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(volunteer.clone()).into(), hash_task);
 
-	}: complete_task(RawOrigin::Signed(volunteer.clone()), hash_task)
+	}: complete_task(RawOrigin::Signed(volunteer.clone()), hash_task, deliverables::<T>())
 		/* the code to be benchmarked */
 
 	verify {
@@ -289,7 +407,7 @@ benchmarks! {
 		 	x.into(), attachments.clone().try_into().unwrap(), keywords.clone().try_into().unwrap(), None, x_coord, y_coord);
 		let hash_task = PalletTask::<T>::tasks_owned(&caller)[0];
 		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(volunteer.clone()).into(), hash_task);
-		let _ = PalletTask::<T>::complete_task(RawOrigin::Signed(volunteer).into(), hash_task);
+		let _ = PalletTask::<T>::complete_task(RawOrigin::Signed(volunteer).into(), hash_task, deliverables::<T>());
 
 	}: accept_task(RawOrigin::Signed(caller.clone()), hash_task)
 		/* the code to be benchmarked */
@@ -297,6 +415,7 @@ benchmarks! {
 	verify {
 		/* verifying final state */
 		assert_last_event::<T>(Event::<T>::TaskAccepted(caller, hash_task).into());
+		assert!(PalletTask::<T>::receipts(hash_task).is_some());
 	}
 
 	reject_task {
@@ -324,7 +443,7 @@ benchmarks! {
 		 	x.into(), attachments.clone().try_into().unwrap(), keywords.clone().try_into().unwrap(), None, x_coord, y_coord);
 		let hash_task = PalletTask::<T>::tasks_owned(&caller)[0];
 		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(volunteer.clone()).into(), hash_task);
-		let _ = PalletTask::<T>::complete_task(RawOrigin::Signed(volunteer).into(), hash_task);
+		let _ = PalletTask::<T>::complete_task(RawOrigin::Signed(volunteer).into(), hash_task, deliverables::<T>());
 
 	}: reject_task(RawOrigin::Signed(caller.clone()), hash_task, feedback.try_into().unwrap())
 		/* the code to be benchmarked */
@@ -362,7 +481,7 @@ benchmarks! {
  	
 	let hash_task = PalletTask::<T>::tasks_owned(&caller)[0];
 	let mut task = Tasks::<T>::get(hash_task).unwrap();
-	task.status = TaskStatus::Expired;
+	PalletTask::<T>::set_status(&mut task, TaskStatus::Expired);
 	Tasks::<T>::insert(&hash_task, &task);
 	// Swap these around so that revive works
 	ExpiringTasksPerBlock::<T>::take(task.deadline_block.unwrap());
@@ -372,13 +491,750 @@ benchmarks! {
 
 	DyingTasksPerBlock::<T>::insert(dying_deadline_block, dying_tasks);
 	
-	}: 	revive_expired_task(RawOrigin::Signed(caller.clone()), hash_task, x.into())
+	}: 	revive_expired_task(RawOrigin::Signed(caller.clone()), hash_task, TaskDeadline::Timestamp(x.into()))
 		/* the code to be benchmarked */
 
 	verify {
 		/* verifying final state */
 		assert_last_event::<T>(Event::<T>::TaskRevived(caller.clone(), hash_task).into());
 	}
+
+	add_milestone {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		let title = vec![0u8; <T as ConfigTask>::MaxTitleLen::get() as usize];
+
+	}: add_milestone(RawOrigin::Signed(caller.clone()), hash_task, title.try_into().unwrap(), <T as pallet::Config>::Currency::minimum_balance(), x.into())
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::MilestoneAdded(caller, hash_task, 0).into());
+	}
+
+	submit_milestone {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		setup_milestone::<T>(&caller, hash_task, x.into());
+		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(volunteer.clone()).into(), hash_task);
+
+	}: submit_milestone(RawOrigin::Signed(volunteer.clone()), hash_task, 0)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::MilestoneSubmitted(volunteer, hash_task, 0).into());
+	}
+
+	accept_milestone {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		setup_milestone::<T>(&caller, hash_task, x.into());
+		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(volunteer.clone()).into(), hash_task);
+		let _ = PalletTask::<T>::submit_milestone(RawOrigin::Signed(volunteer).into(), hash_task, 0);
+
+	}: accept_milestone(RawOrigin::Signed(caller.clone()), hash_task, 0)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::MilestoneAccepted(caller, hash_task, 0, <T as pallet::Config>::Currency::minimum_balance()).into());
+	}
+
+	reject_milestone {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		setup_milestone::<T>(&caller, hash_task, x.into());
+		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(volunteer.clone()).into(), hash_task);
+		let _ = PalletTask::<T>::submit_milestone(RawOrigin::Signed(volunteer).into(), hash_task, 0);
+		let feedback = vec![0u8; <T as ConfigTask>::MaxFeedbackLen::get() as usize];
+
+	}: reject_milestone(RawOrigin::Signed(caller.clone()), hash_task, 0, feedback.try_into().unwrap())
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::MilestoneRejected(caller, hash_task, 0).into());
+	}
+
+	apply_for_task {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		let _ = PalletProfile::<T>::create_profile(
+			RawOrigin::Signed(volunteer.clone()).into(), Vec::new().try_into().unwrap(),
+			Vec::new().try_into().unwrap(), 40_u8, None, Some([8u8; 5]), Some([8u8; 5])
+		);
+		let cover_note = vec![0u8; <T as ConfigTask>::MaxCoverNoteLen::get() as usize];
+
+	}: apply_for_task(RawOrigin::Signed(volunteer.clone()), hash_task, cover_note.try_into().unwrap(), Some(Zero::zero()))
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::ApplicationSubmitted(volunteer, hash_task).into());
+	}
+
+	withdraw_application {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		setup_application::<T>(&volunteer, hash_task);
+
+	}: withdraw_application(RawOrigin::Signed(volunteer.clone()), hash_task)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::ApplicationWithdrawn(volunteer, hash_task).into());
+	}
+
+	shortlist_application {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		setup_application::<T>(&volunteer, hash_task);
+
+	}: shortlist_application(RawOrigin::Signed(caller.clone()), hash_task, volunteer.clone())
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::ApplicationShortlisted(caller, hash_task, volunteer).into());
+	}
+
+	select_application {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		setup_application::<T>(&volunteer, hash_task);
+
+	}: select_application(RawOrigin::Signed(caller.clone()), hash_task, volunteer.clone())
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskAssigned(volunteer, hash_task).into());
+	}
+
+	set_volunteer_slots {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let s in 1 .. <T as ConfigTask>::MaxVolunteerSlots::get();
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		let shares: Vec<<<T as ConfigTask>::Currency as Currency<T::AccountId>>::Balance> = vec![Zero::zero(); s as usize];
+
+	}: set_volunteer_slots(RawOrigin::Signed(caller.clone()), hash_task, shares.try_into().unwrap())
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::VolunteerSlotsSet(caller, hash_task, s).into());
+	}
+
+	open_dispute {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let x in 1 .. 2000;
+		let hash_task = setup_rejected_task::<T>(&caller, &volunteer, x.into());
+		setup_arbitrators::<T>();
+		let reason = vec![0u8; <T as ConfigTask>::MaxFeedbackLen::get() as usize];
+
+	}: open_dispute(RawOrigin::Signed(caller.clone()), hash_task, reason.try_into().unwrap())
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::DisputeOpened(caller, hash_task).into());
+	}
+
+	vote_on_dispute {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let x in 1 .. 2000;
+		let hash_task = setup_rejected_task::<T>(&caller, &volunteer, x.into());
+		let mut arbitrators = setup_arbitrators::<T>();
+		let reason = vec![0u8; <T as ConfigTask>::MaxFeedbackLen::get() as usize];
+		let _ = PalletTask::<T>::open_dispute(RawOrigin::Signed(caller).into(), hash_task, reason.try_into().unwrap());
+
+		// The last vote resolves the dispute
+		let last = arbitrators.pop().unwrap();
+		for arbitrator in arbitrators {
+			let _ = PalletTask::<T>::vote_on_dispute(RawOrigin::Signed(arbitrator).into(), hash_task, Percent::from_percent(50));
+		}
+
+	}: vote_on_dispute(RawOrigin::Signed(last), hash_task, Percent::from_percent(50))
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert!(PalletTask::<T>::disputes(hash_task).is_none());
+		assert!(PalletTask::<T>::tasks(hash_task).is_none());
+	}
+
+	resolve_dispute {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let hash_task = setup_rejected_task::<T>(&caller, &volunteer, 2000);
+		let mut arbitrators = setup_arbitrators::<T>();
+		let reason = vec![0u8; <T as ConfigTask>::MaxFeedbackLen::get() as usize];
+		let _ = PalletTask::<T>::open_dispute(RawOrigin::Signed(caller).into(), hash_task, reason.try_into().unwrap());
+
+		// The voting period ends before the last arbitrator has voted
+		arbitrators.pop();
+		for arbitrator in arbitrators {
+			let _ = PalletTask::<T>::vote_on_dispute(RawOrigin::Signed(arbitrator).into(), hash_task, Percent::from_percent(50));
+		}
+
+	}: { let _ = PalletTask::<T>::resolve_dispute(&hash_task); }
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert!(PalletTask::<T>::disputes(hash_task).is_none());
+		assert!(PalletTask::<T>::tasks(hash_task).is_none());
+	}
+
+	auto_accept_task {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let hash_task = setup_task::<T>(&caller, 2000);
+		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(volunteer.clone()).into(), hash_task);
+		let _ = PalletTask::<T>::complete_task(RawOrigin::Signed(volunteer).into(), hash_task, deliverables::<T>());
+		let review_ends_at = frame_system::Pallet::<T>::block_number() + <T as ConfigTask>::ReviewPeriod::get();
+
+	}: { PalletTask::<T>::end_review(review_ends_at, &hash_task); }
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskAutoAccepted(hash_task).into());
+		assert!(PalletTask::<T>::receipts(hash_task).is_some());
+	}
+
+	prune_receipt {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let hash_task = setup_task::<T>(&caller, 2000);
+		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(caller.clone()).into(), hash_task);
+		let _ = PalletTask::<T>::complete_task(RawOrigin::Signed(caller.clone()).into(), hash_task, deliverables::<T>());
+		let _ = PalletTask::<T>::accept_task(RawOrigin::Signed(caller).into(), hash_task);
+
+	}: { PalletTask::<T>::prune_receipt(&hash_task); }
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert!(PalletTask::<T>::receipts(hash_task).is_none());
+	}
+
+	set_arbitrator_pool {
+		/* setup initial state */
+		let s in 1 .. <T as ConfigTask>::MaxArbitratorPool::get();
+		let arbitrators: Vec<T::AccountId> = (0 .. s).map(|i| account("arbitrator", i, SEED)).collect();
+
+	}: set_arbitrator_pool(RawOrigin::Root, arbitrators.try_into().unwrap())
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::ArbitratorPoolSet(s).into());
+	}
+
+	create_subtask {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		let title = vec![0u8; <T as ConfigTask>::MaxTitleLen::get() as usize];
+		let specification = vec![0u8; <T as ConfigTask>::MaxSpecificationLen::get() as usize];
+		let attachments = vec![0u8; <T as ConfigTask>::MaxAttachmentsLen::get() as usize];
+		let keywords = vec![0u8; <T as ConfigTask>::MaxKeywordsLen::get() as usize];
+
+	}: create_subtask(RawOrigin::Signed(caller.clone()), hash_task, title.try_into().unwrap(), specification.try_into().unwrap(),
+		Zero::zero(), TaskDeadline::Timestamp(x.into()), attachments.try_into().unwrap(), keywords.try_into().unwrap(), true)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_eq!(PalletTask::<T>::subtasks(hash_task).len(), 1);
+	}
+
+	reclaim_task {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(volunteer).into(), hash_task);
+		let delivery_deadline = PalletTask::<T>::tasks(hash_task).unwrap().delivery_deadline.unwrap();
+		frame_system::Pallet::<T>::set_block_number(delivery_deadline + 1u32.into());
+
+	}: reclaim_task(RawOrigin::Signed(caller.clone()), hash_task)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskReclaimed(caller, hash_task).into());
+	}
+
+	abandon_task {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(volunteer.clone()).into(), hash_task);
+
+	}: abandon_task(RawOrigin::Signed(volunteer.clone()), hash_task)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskAbandoned(volunteer, hash_task).into());
+	}
+
+	add_dependency {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		let _ = setup_task::<T>(&caller, (x + 1).into());
+		let hash_dependency = PalletTask::<T>::tasks_owned(&caller)[1];
+
+	}: add_dependency(RawOrigin::Signed(caller.clone()), hash_task, hash_dependency)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::DependencyAdded(caller, hash_task, hash_dependency).into());
+	}
+
+	remove_dependency {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let x in 1 .. 2000;
+		let hash_task = setup_task::<T>(&caller, x.into());
+		let _ = setup_task::<T>(&caller, (x + 1).into());
+		let hash_dependency = PalletTask::<T>::tasks_owned(&caller)[1];
+		let _ = PalletTask::<T>::add_dependency(RawOrigin::Signed(caller.clone()).into(), hash_task, hash_dependency);
+
+	}: remove_dependency(RawOrigin::Signed(caller.clone()), hash_task, hash_dependency)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::DependencyRemoved(caller, hash_task, hash_dependency).into());
+	}
+
+	create_asset_task {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = setup_asset::<T>(&caller);
+		let title = vec![0u8; <T as ConfigTask>::MaxTitleLen::get() as usize];
+		let specification = vec![0u8; <T as ConfigTask>::MaxSpecificationLen::get() as usize];
+		let attachments = vec![0u8; <T as ConfigTask>::MaxAttachmentsLen::get() as usize];
+		let keywords = vec![0u8; <T as ConfigTask>::MaxKeywordsLen::get() as usize];
+
+		// Create profile before creating a task
+		create_profile::<T>();
+
+	}: create_task_with_options(RawOrigin::Signed(caller.clone()), title.try_into().unwrap(), specification.try_into().unwrap(), 1_000_000u32.into(),
+		TaskDeadline::Timestamp(2000), attachments.try_into().unwrap(), keywords.try_into().unwrap(), None, Some([8u8; 5]), Some([8u8; 5]), true, Some(asset))
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		let task_id = PalletTask::<T>::tasks_owned(&caller)[0];
+		assert_eq!(PalletTask::<T>::escrowed_balance(&task_id), 1_000_000u32.into());
+		assert_last_event::<T>(Event::<T>::TaskCreated(caller, task_id).into());
+	}
+
+	accept_asset_task {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = whitelisted_caller();
+		let asset = setup_asset::<T>(&caller);
+		let hash_task = setup_asset_task::<T>(&caller, asset);
+		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(volunteer.clone()).into(), hash_task);
+		let _ = PalletTask::<T>::complete_task(RawOrigin::Signed(volunteer).into(), hash_task, deliverables::<T>());
+
+	}: accept_task(RawOrigin::Signed(caller.clone()), hash_task)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskAccepted(caller, hash_task).into());
+		assert_eq!(PalletTask::<T>::receipts(hash_task).unwrap().asset, Some(asset));
+	}
+
+	create_recurring_task {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let title = vec![0u8; <T as ConfigTask>::MaxTitleLen::get() as usize];
+		let specification = vec![0u8; <T as ConfigTask>::MaxSpecificationLen::get() as usize];
+		let attachments = vec![0u8; <T as ConfigTask>::MaxAttachmentsLen::get() as usize];
+		let keywords = vec![0u8; <T as ConfigTask>::MaxKeywordsLen::get() as usize];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller) / 2u32.into();
+
+		// Create profile before creating a task
+		create_profile::<T>();
+
+	}: create_recurring_task(RawOrigin::Signed(caller.clone()), title.try_into().unwrap(), specification.try_into().unwrap(), budget,
+		attachments.try_into().unwrap(), keywords.try_into().unwrap(), None, Some([8u8; 5]), Some([8u8; 5]), true, None,
+		<T as ConfigTask>::MinRecurrenceInterval::get(), 2)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_eq!(PalletTask::<T>::tasks_owned(&caller).len(), 1);
+	}
+
+	cancel_recurring_task {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let title = vec![0u8; <T as ConfigTask>::MaxTitleLen::get() as usize];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller) / 2u32.into();
+		create_profile::<T>();
+		let _ = PalletTask::<T>::create_recurring_task(
+			RawOrigin::Signed(caller.clone()).into(), title.try_into().unwrap(), Vec::new().try_into().unwrap(), budget,
+			Vec::new().try_into().unwrap(), Vec::new().try_into().unwrap(), None, Some([8u8; 5]), Some([8u8; 5]), true, None,
+			<T as ConfigTask>::MinRecurrenceInterval::get(), 2);
+		let recurring_id = pallet::RecurringTasks::<T>::iter_keys().next().unwrap();
+
+	}: cancel_recurring_task(RawOrigin::Signed(caller.clone()), recurring_id)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::RecurringTaskCancelled(caller, recurring_id).into());
+	}
+
+	spawn_occurrence {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let title = vec![0u8; <T as ConfigTask>::MaxTitleLen::get() as usize];
+		let specification = vec![0u8; <T as ConfigTask>::MaxSpecificationLen::get() as usize];
+		let attachments = vec![0u8; <T as ConfigTask>::MaxAttachmentsLen::get() as usize];
+		let keywords = vec![0u8; <T as ConfigTask>::MaxKeywordsLen::get() as usize];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller) / 4u32.into();
+		create_profile::<T>();
+		let _ = PalletTask::<T>::create_recurring_task(
+			RawOrigin::Signed(caller.clone()).into(), title.try_into().unwrap(), specification.try_into().unwrap(), budget,
+			attachments.try_into().unwrap(), keywords.try_into().unwrap(), None, Some([8u8; 5]), Some([8u8; 5]), true, None,
+			<T as ConfigTask>::MinRecurrenceInterval::get(), 3);
+		let recurring_id = pallet::RecurringTasks::<T>::iter_keys().next().unwrap();
+
+	}: { let _ = PalletTask::<T>::spawn_occurrence(&recurring_id); }
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_eq!(PalletTask::<T>::tasks_owned(&caller).len(), 2);
+		assert_eq!(PalletTask::<T>::recurring_tasks(recurring_id).unwrap().occurrences, 2);
+	}
+
+	create_template {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let title = vec![0u8; <T as ConfigTask>::MaxTitleLen::get() as usize];
+		let specification = vec![0u8; <T as ConfigTask>::MaxSpecificationLen::get() as usize];
+		let attachments = vec![0u8; <T as ConfigTask>::MaxAttachmentsLen::get() as usize];
+		let keywords = vec![0u8; <T as ConfigTask>::MaxKeywordsLen::get() as usize];
+		let organization = create_organisation::<T>();
+
+	}: create_template(RawOrigin::Signed(caller.clone()), title.try_into().unwrap(), specification.try_into().unwrap(),
+		attachments.try_into().unwrap(), keywords.try_into().unwrap(), Some(organization), true)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		let template_id = pallet::Templates::<T>::iter_keys().next().unwrap();
+		assert_last_event::<T>(Event::<T>::TemplateCreated(caller, template_id).into());
+	}
+
+	update_template {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let title = vec![1u8; <T as ConfigTask>::MaxTitleLen::get() as usize];
+		let specification = vec![1u8; <T as ConfigTask>::MaxSpecificationLen::get() as usize];
+		let attachments = vec![1u8; <T as ConfigTask>::MaxAttachmentsLen::get() as usize];
+		let keywords = vec![1u8; <T as ConfigTask>::MaxKeywordsLen::get() as usize];
+		let template_id = setup_template::<T>(&caller);
+
+	}: update_template(RawOrigin::Signed(caller.clone()), template_id, title.try_into().unwrap(), specification.try_into().unwrap(),
+		attachments.try_into().unwrap(), keywords.try_into().unwrap(), false)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TemplateUpdated(caller, template_id, 2).into());
+	}
+
+	remove_template {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let template_id = setup_template::<T>(&caller);
+
+	}: remove_template(RawOrigin::Signed(caller.clone()), template_id)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TemplateRemoved(caller, template_id).into());
+	}
+
+	create_task_from_template {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller) / 2u32.into();
+		let template_id = setup_template::<T>(&caller);
+
+		// Create profile before creating a task
+		create_profile::<T>();
+
+	}: create_task_from_template(RawOrigin::Signed(caller.clone()), template_id, budget, TaskDeadline::Timestamp(2000), Some([8u8; 5]), Some([8u8; 5]))
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		let task_id = PalletTask::<T>::tasks_owned(&caller)[0];
+		assert_eq!(PalletTask::<T>::tasks(&task_id).unwrap().template, Some((template_id, 1)));
+	}
+
+	create_organization_task {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let title = vec![0u8; <T as ConfigTask>::MaxTitleLen::get() as usize];
+		let specification = vec![0u8; <T as ConfigTask>::MaxSpecificationLen::get() as usize];
+		let attachments = vec![0u8; <T as ConfigTask>::MaxAttachmentsLen::get() as usize];
+		let keywords = vec![0u8; <T as ConfigTask>::MaxKeywordsLen::get() as usize];
+		let budget = <T as pallet::Config>::Currency::total_balance(&caller);
+		let organization = create_organisation::<T>();
+
+		// Fund the organization account, the budget is drawn from it
+		let org_account = PalletDao::<T>::account_id(&organization);
+		<T as ConfigTask>::Currency::make_free_balance_be(&org_account, budget + budget);
+
+		// Create profile before creating a task
+		create_profile::<T>();
+
+	}: create_organization_task(RawOrigin::Signed(caller.clone()), title.try_into().unwrap(), specification.try_into().unwrap(), budget, TaskDeadline::Timestamp(2000),
+		attachments.try_into().unwrap(), keywords.try_into().unwrap(), organization, Some([8u8; 5]), Some([8u8; 5]), true, None)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		let task_id = PalletTask::<T>::tasks_owned(&caller)[0];
+		assert_eq!(PalletTask::<T>::tasks(&task_id).unwrap().funder, org_account);
+	}
+
+	rate_task {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let hash_task = setup_task::<T>(&caller, 2000);
+		setup_application::<T>(&volunteer, hash_task);
+		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(volunteer.clone()).into(), hash_task);
+		let _ = PalletTask::<T>::complete_task(RawOrigin::Signed(volunteer.clone()).into(), hash_task, deliverables::<T>());
+		let _ = PalletTask::<T>::accept_task(RawOrigin::Signed(caller.clone()).into(), hash_task);
+		let review = vec![0u8; <T as ConfigTask>::MaxReviewLen::get() as usize];
+
+	}: rate_task(RawOrigin::Signed(volunteer.clone()), hash_task, caller.clone(), 5, review.try_into().unwrap())
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskRated(volunteer, caller, hash_task, 5).into());
+	}
+
+	expire_task {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let hash_task = setup_task::<T>(&caller, 2000);
+		let n = frame_system::Pallet::<T>::block_number();
+
+		// Expire the task by block, timestamp deadlines not reached yet are only moved to a later block
+		Tasks::<T>::mutate(&hash_task, |task| if let Some(task) = task { task.deadline_by_block = true; });
+
+	}: { PalletTask::<T>::expire_task(n, &hash_task); }
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_eq!(PalletTask::<T>::tasks(&hash_task).unwrap().status, TaskStatus::Expired);
+	}
+
+	expire_milestones {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let volunteer: T::AccountId = account("volunteer", 0, SEED);
+		let hash_task = setup_task::<T>(&caller, 2000);
+		for _ in 0 .. <T as ConfigTask>::MaxMilestonesPerTask::get() {
+			setup_milestone::<T>(&caller, hash_task, 2000);
+		}
+		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(volunteer).into(), hash_task);
+		let n = frame_system::Pallet::<T>::block_number();
+
+		// Let the deadline of every milestone pass
+		Milestones::<T>::mutate(&hash_task, |milestones| for milestone in milestones.iter_mut() { milestone.deadline = 0; });
+
+	}: { PalletTask::<T>::expire_milestones(n, &hash_task); }
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert!(PalletTask::<T>::milestones(&hash_task).iter().all(|milestone| milestone.status == MilestoneStatus::Expired));
+	}
+
+	remove_dead_task {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let hash_task = setup_task::<T>(&caller, 2000);
+		let n = frame_system::Pallet::<T>::block_number();
+		Tasks::<T>::mutate(&hash_task, |task| if let Some(task) = task { task.deadline_by_block = true; });
+		PalletTask::<T>::expire_task(n, &hash_task);
+
+	}: { PalletTask::<T>::remove_dead_task(&hash_task); }
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert!(PalletTask::<T>::tasks(&hash_task).is_none());
+	}
+
+	set_task_visibility {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let hash_task = setup_task::<T>(&caller, 2000);
+
+	}: set_task_visibility(RawOrigin::Signed(caller.clone()), hash_task, TaskVisibility::InviteOnly)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::TaskVisibilitySet(caller, hash_task, TaskVisibility::InviteOnly).into());
+	}
+
+	invite_volunteer {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let invitee: T::AccountId = account("invitee", 0, SEED);
+		let hash_task = setup_task::<T>(&caller, 2000);
+		for i in 1 .. <T as ConfigTask>::MaxInvitationsPerTask::get() {
+			let _ = PalletTask::<T>::invite_volunteer(RawOrigin::Signed(caller.clone()).into(), hash_task, account("invitee", i, SEED));
+		}
+
+	}: invite_volunteer(RawOrigin::Signed(caller.clone()), hash_task, invitee.clone())
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::VolunteerInvited(caller, hash_task, invitee).into());
+	}
+
+	revoke_invitation {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let invitee: T::AccountId = account("invitee", 0, SEED);
+		let hash_task = setup_task::<T>(&caller, 2000);
+		let _ = PalletTask::<T>::invite_volunteer(RawOrigin::Signed(caller.clone()).into(), hash_task, invitee.clone());
+
+	}: revoke_invitation(RawOrigin::Signed(caller.clone()), hash_task, invitee.clone())
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::InvitationRevoked(caller, hash_task, invitee).into());
+	}
+
+	decline_invitation {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let invitee: T::AccountId = account("invitee", 0, SEED);
+		let hash_task = setup_task::<T>(&caller, 2000);
+		let _ = PalletTask::<T>::invite_volunteer(RawOrigin::Signed(caller.clone()).into(), hash_task, invitee.clone());
+
+	}: decline_invitation(RawOrigin::Signed(invitee.clone()), hash_task)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::InvitationDeclined(invitee, hash_task).into());
+	}
+
+	set_vesting_schedule {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let hash_task = setup_task::<T>(&caller, 2000);
+		let schedule = VestingSchedule { cliff: One::one(), period: 10u32.into() };
+
+	}: set_vesting_schedule(RawOrigin::Signed(caller.clone()), hash_task, Some(schedule))
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::VestingScheduleSet(caller, hash_task).into());
+	}
+
+	claim_vested {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let hash_task = setup_task::<T>(&caller, 2000);
+		let schedule = VestingSchedule { cliff: Zero::zero(), period: 10u32.into() };
+		let _ = PalletTask::<T>::set_vesting_schedule(RawOrigin::Signed(caller.clone()).into(), hash_task, Some(schedule));
+		let _ = PalletTask::<T>::start_task(RawOrigin::Signed(caller.clone()).into(), hash_task);
+		let _ = PalletTask::<T>::complete_task(RawOrigin::Signed(caller.clone()).into(), hash_task, deliverables::<T>());
+		let _ = PalletTask::<T>::accept_task(RawOrigin::Signed(caller.clone()).into(), hash_task);
+
+		// Claim halfway through the vesting period, so the payout is still stored afterwards
+		frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 5u32.into());
+
+	}: claim_vested(RawOrigin::Signed(caller.clone()), hash_task)
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert!(PalletTask::<T>::vesting_payouts(hash_task).is_some());
+	}
+
+	set_volunteer_bond {
+		/* setup initial state */
+		let caller: T::AccountId = whitelisted_caller();
+		let hash_task = setup_task::<T>(&caller, 2000);
+		let bond = VolunteerBond::Percent(Percent::from_percent(10));
+
+	}: set_volunteer_bond(RawOrigin::Signed(caller.clone()), hash_task, Some(bond))
+		/* the code to be benchmarked */
+
+	verify {
+		/* verifying final state */
+		assert_last_event::<T>(Event::<T>::VolunteerBondSet(caller, hash_task).into());
+	}
 }
 
 impl_benchmark_test_suite!(PalletTask, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -36,11 +36,116 @@
 //! Anybody can become an Initiator or Volunteer. In other words,
 //! one doesn't need permission to become an Initiator or Volunteer.
 //! 
-//! Budget funds reserved using ReservableCurrency.
-//! Funds are unreserved and sent to volunteer when a task is completed or removed.
+//! Budget funds are moved into an escrow account derived from the PalletId and the task_id.
+//! Escrowed funds are sent to the volunteer when a task is accepted, and refunded
+//! to the initiator when a task is removed, either by the initiator or after expiration.
+//! A budget is paid in the native currency, unless the task names an asset to pay it in.
+//! Asset budgets are escrowed and paid out the same way, in the asset the task was created with.
+//!
+//! Tasks of an organization can also be funded from the account of the organization, by its treasurers,
+//! or by its members while it has no treasurers. Refunds of such tasks go back to the organization account.
+//!
+//! When a task or a milestone is accepted, the `PlatformFee` share of the payout is taken and handed to
+//! `PlatformFeeDestination`, which the runtime wires to the treasury. Organization tasks also give the
+//! `OrganizationFee` share of the payout to the organization account. The volunteers are paid what is left.
+//! The platform fee of asset payouts is taken in the asset and transferred to the `PlatformFeeAccount` instead.
+//!
+//! Tasks are public unless their initiator restricts who can volunteer. Invite-only tasks can only be started
+//! by the accounts the initiator invited, who can start them without applying. Organization-only tasks can only
+//! be started by the members of the task organization. Invitees can decline an invitation, and the initiator
+//! can revoke it, as long as the task has not been started.
+//!
+//! A task can be split into volunteer slots, each with a share of the budget. Such team tasks
+//! start once every slot has been joined, each volunteer completes their slot independently,
+//! and every volunteer is paid their share when the task is accepted.
+//!
+//! The payout of a task can vest instead of being paid at once. Its initiator sets a vesting schedule before
+//! the task is started: a cliff, in blocks after acceptance, followed by a linear release over a period of blocks.
+//! On acceptance the payout stays in the escrow account of the task, and every volunteer claims their share
+//! as it vests. Milestone payouts are never vested. The initiator can't take back what has not vested yet,
+//! except through a dispute on the vesting payout: every volunteer is then paid what had vested when the dispute
+//! was opened and the share of the rest awarded by the arbitrators, the remainder is refunded to the funder.
+//!
+//! A task can require volunteers to reserve a bond in the native currency when they start it, either a fixed
+//! amount or a percentage of the budget, of the share of their slot for team tasks. The bond is returned
+//! when the task is accepted or removed. Volunteers who abandon the task, or do not deliver it before the
+//! initiator reclaims it, lose their bond to the initiator, and so do volunteers awarded less than half of
+//! the escrow by a dispute.
+//!
+//! Once a task has been rejected, either party can open a dispute. Arbitrators are drawn
+//! randomly from the members of the task organization, or from the arbitrator pool for tasks
+//! without an organization. Each arbitrator votes the percentage of the escrowed budget that goes
+//! to the volunteers; the rest is refunded to the initiator. The dispute deposit is returned
+//! when the verdict favours the opener, and slashed otherwise.
+//!
+//! The budget of a created task can be split into subtasks. Subtasks are funded from the escrow
+//! of their parent, and their budget flows back to the parent when they are removed before the
+//! parent has been started. Removing a parent removes its subtasks that have not been started yet;
+//! subtasks that are already in progress are detached and continue as standalone tasks. At most
+//! `MaxTasksWalked` subtasks are visited when a parent is removed, larger trees are removed from the
+//! bottom up. Dead tasks are removed without their subtasks, which are detached and expire on their own.
+//!
+//! A task can depend on other tasks, and can only be started once every task it depends on has been
+//! accepted. Dependencies that would form a cycle are rejected, as are dependencies whose check would
+//! visit more than `MaxTasksWalked` tasks. Removing a task drops its dependencies.
+//!
+//! Once started, volunteers have until the delivery deadline to complete a task. The delivery deadline
+//! is the task deadline, but never less than the delivery period after the task has been started.
+//! Past the delivery deadline the initiator can reclaim the task, and volunteers can abandon a task at
+//! any time. Either way the volunteers who leave lose reputation, and the task returns to Created.
+//!
+//! Completed tasks are accepted automatically once the review period has passed without the initiator
+//! accepting or rejecting them, with the same payout as when the initiator accepts the task.
+//!
+//! Recurring tasks spawn a fresh task every interval, until the maximum number of occurrences has been
+//! spawned or the initiator cancels them. Every occurrence is funded by the initiator when it is spawned,
+//! and is due when the next one is spawned. A recurring task stops once the initiator can't fund it.
+//!
+//! Task templates hold the title, specification, attachments, keywords and openness shared by many tasks.
+//! A template is owned by an account, or by an organization whose members can all use and manage it.
+//! Tasks created from a template only set their budget, deadline and location, belong to the organization
+//! of the template, and record the template and the version of the template they were created from.
+//!
+//! Task deadlines are given either as a unix timestamp in milliseconds, or as an absolute block number.
+//! Both are stored, the one not given being estimated from `MillisecondsPerBlock`. Block deadlines expire
+//! on their block. Timestamp deadlines are re-anchored to the block timestamp when their expiry
+//! is processed, and are moved to a later block if the deadline has not been reached yet.
 //!
 //! Tasks with expired deadline are automatically removed from storage.
 //!
+//! Milestones are submitted for the first time before their deadline. A milestone whose deadline passes
+//! before it was submitted expires, while its task has not been completed, and its budget is refunded to
+//! the funder out of the task budget. Milestones expire with the tasks, within `MaxExpiryWeight`.
+//!
+//! Expiry is processed as a queue, oldest block first, within `MaxExpiryWeight` at the start of every block
+//! and within the weight left at the end of it. The tasks that do not fit are carried over to the next block,
+//! so a popular deadline never exceeds the block weight. The disputes whose voting period ends on a block,
+//! the completed tasks whose review period ends on it, the occurrences of recurring tasks due on it and the
+//! receipts that are pruned on it are processed the same way, within `MaxScheduledWeight`. A dispute that can't
+//! be resolved is retried once another voting period has passed, and a completed task that can't be accepted
+//! once another review period has passed. A recurring task stops when its initiator can't fund an occurrence,
+//! an occurrence that can't be spawned for another reason is retried after another interval.
+//!
+//! Task locations are NAD `(x, y)` coordinates. Tasks are indexed by the grid cell of their location,
+//! made of the first two characters of both coordinates, from creation until they expire or are removed.
+//! Locations that are not valid NAD coordinates are kept, but not indexed. The created tasks can be
+//! queried within a bounding box, or within a radius around the location of a profile.
+//!
+//! Other pallets react to the lifecycle of tasks through `LifecycleHooks`, a tuple of implementors of
+//! [`traits::TaskLifecycleHooks`], called when a task is created, started, completed, accepted, rejected,
+//! abandoned, expired, removed or rated. The runtime keeps the reputation, the completed tasks and the ratings
+//! of profiles this way. Profiles are looked up through `Profiles`, an implementor of [`traits::Profiles`].
+//!
+//! Storage is versioned, see [`migrations`] for the migrations from older versions.
+//!
+//! Accepted tasks are removed from storage as well, but leave a receipt behind. Receipts are indexed
+//! by task, by account and by organization. The account and organization indexes keep the most recent
+//! receipts only, and receipts are pruned once they are older than the receipt longevity.
+//!
+//! Within the rating period after a task has been accepted, its initiator and its volunteers can rate
+//! each other from 1 to 5 stars, with a short review. Ratings are indexed by the account rated, which
+//! keeps the most recent ratings only, and are aggregated into the profile of the account rated.
+//!
 //! ## Interface
 //!
 //! ### Public Functions
@@ -50,12 +155,23 @@
 //!         - title: BoundedVec,
 //!         - specification: BoundedVec,
 //!         - budget: BalanceOf<T>,
-//!         - deadline: u64
+//!         - deadline: u64, a unix timestamp in milliseconds
 //!         - attachments: BoundedVec,
 //!         - keywords: BoundedVec
 //!         - organization: Option<OrganizationIdOf<T>>
 //!			- x: Option<[u8; 5]>: NAD system x coordiante 
 //!			- y: Option<[u8; 5]>: NAD system y coordiante 
+//!     The task is open to any volunteer and its budget is paid in the native currency.
+//!
+//! - `create_task_with_options` - Function used to create a new task with a block deadline, an application flow or an asset budget.
+//!     Inputs: as in `create_task`, and
+//!         - deadline: TaskDeadline, a unix timestamp in milliseconds or an absolute block number
+//!			- open: bool: whether volunteers can start the task without applying first
+//!			- asset: Option<T::AssetId>: the asset the budget is paid in, None for the native currency
+//!
+//! - `create_organization_task` - Function used to create a new task funded from the account of an organization.
+//!     Inputs: as in `create_task_with_options`, but the organization is required.
+//!     Only accounts that can spend from the organization account can create it.
 //!
 //! - `update_task` - Function used to update already existing task.
 //!     Inputs:
@@ -63,7 +179,7 @@
 //!         - title: Vec<u8>,
 //!         - specification: Vec<u8>,
 //!         - budget: BalanceOf<T>,
-//!         - deadline: u64,
+//!         - deadline: u64, a unix timestamp in milliseconds
 //!         - attachments, BoundedVec
 //!         - keywords: BoundedVec,
 //!         - organization: Option<OrganizationIdOf<T>>
@@ -72,6 +188,11 @@
 //!
 //!     Only the creator of the task has the update rights.
 //!
+//! - `update_task_with_options` - Function used to update already existing task, with a block deadline or a change to its application flow.
+//!     Inputs: as in `update_task`, and
+//!         - deadline: TaskDeadline, a unix timestamp in milliseconds or an absolute block number
+//!			- open: bool: whether volunteers can start the task without applying first
+//!
 //! - `remove_task` - Function used to remove an already existing task.
 //!     Inputs:
 //!         - task_id: T::Hash,
@@ -79,10 +200,14 @@
 //! - `start_task` - Function used to start already existing task.
 //!     Inputs:
 //!         - task_id: T::Hash,
+//!     Only open tasks can be started directly. Other tasks are started by selecting an application.
+//!     Fails with `CannotReserveBond` when the volunteer can't reserve the bond the task requires.
 //!
 //! - `complete_task` - Function used to complete a task.
 //!     Inputs:
 //!         - task_id: T::Hash,
+//!         - deliverables: BoundedVec<Deliverable>, content hashes or IPFS CIDs of the work, each with an optional description
+//!     Every submission is kept with the task, across rejections, and becomes evidence of a dispute.
 //!
 //! - `accept_task` - Function used to accept completed task.
 //!     Inputs:
@@ -96,6 +221,188 @@
 //! 
 //! - 'revive_expired_task' - Used to set a new deadline for a task with status Expired and update its status to Created.  
 //!		Used if a task has expired and the user wants to revive it to edit.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - new_deadline: TaskDeadline
+//! 
+//! - `add_milestone` - Function used by the initiator to split a created task into milestones.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - title: BoundedVec,
+//!     - budget: BalanceOf<T>, share of the task budget paid when the milestone is accepted
+//!     - deadline: u64, the milestone expires and its budget is refunded if it is not submitted before it
+//!
+//! - `submit_milestone` - Function used by the volunteer to submit a milestone for review.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - milestone: MilestoneIndex
+//!
+//! - `accept_milestone` - Function used by the initiator to accept a submitted milestone.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - milestone: MilestoneIndex
+//!     The milestone budget is paid from escrow to the volunteer. The remaining budget
+//!     is paid when the task itself is accepted.
+//!
+//! - `reject_milestone` - Function used by the initiator to reject a submitted milestone.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - milestone: MilestoneIndex
+//!     - feedback : BoundedVec
+//! 
+//! - `apply_for_task` - Function used by a volunteer to apply for a created task.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - cover_note: BoundedVec
+//!     - price: Option<BalanceOf<T>>, counter-offer for the task budget
+//!
+//! - `withdraw_application` - Function used by a volunteer to withdraw an application.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!
+//! - `shortlist_application` - Function used by the initiator to shortlist an application.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - applicant: T::AccountId
+//!
+//! - `select_application` - Function used by the initiator to pick the volunteer of a task.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - applicant: T::AccountId
+//!     The task is started with the applicant as volunteer and all other applications are declined.
+//!     If the applicant made a counter-offer, the task budget is changed to the offered price.
+//!
+//! - `set_volunteer_slots` - Function used by the initiator to turn a created task into a team task.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - shares: BoundedVec<BalanceOf<T>>, one share of the task budget per volunteer slot
+//!     The shares must add up to the task budget. Empty shares turn the task back into a single volunteer task.
+//!
+//! - `open_dispute` - Function used by the initiator or a volunteer to dispute a rejected task.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - reason: BoundedVec
+//!     The dispute deposit is reserved from the opener and arbitrators are drawn for the dispute.
+//!     The initiator of an accepted task can also dispute its payout while it vests.
+//!
+//! - `vote_on_dispute` - Function used by an arbitrator to vote on a dispute.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - volunteer_share: Percent, share of the escrowed budget that goes to the volunteers
+//!     The dispute is resolved once every arbitrator has voted, or when the voting period ends.
+//!
+//! - `set_arbitrator_pool` - Root function used to configure the arbitrators of tasks without an organization.
+//!     Inputs:
+//!     - arbitrators: BoundedVec<T::AccountId>
+//!
+//! - `create_subtask` - Function used by the initiator to split part of a created task's budget into a subtask.
+//!     Inputs:
+//!     - parent_id: T::Hash,
+//!     - title: BoundedVec,
+//!     - specification: BoundedVec,
+//!     - budget: BalanceOf<T>, moved from the escrow of the parent into the escrow of the subtask
+//!     - deadline: TaskDeadline
+//!     - attachments: BoundedVec,
+//!     - keywords: BoundedVec
+//!     - open: bool
+//!     The subtask belongs to the organization and location of its parent.
+//!
+//! - `reclaim_task` - Function used by the initiator to take back a task whose delivery deadline has passed.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     Volunteers who have not completed their part leave the task and lose their bond. The task returns to Created.
+//!
+//! - `abandon_task` - Function used by a volunteer to leave an in-progress task.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     The volunteer loses their bond. The task returns to Created until a new volunteer takes it.
+//!
+//! - `add_dependency` - Function used by the initiator to block a created task until another task is accepted.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - depends_on: T::Hash
+//!
+//! - `remove_dependency` - Function used by the initiator to unblock a task.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - depends_on: T::Hash
+//!
+//! - `create_recurring_task` - Function used to define a task that is spawned again at every interval.
+//!     Inputs:
+//!     - title, specification, budget, attachments, keywords, organization, x, y, open, asset: as in `create_task_with_options`
+//!     - interval: T::BlockNumber, blocks between two occurrences
+//!     - max_occurrences: u32, the number of tasks spawned at most
+//!     The first occurrence is spawned right away. The budget is drawn from the initiator for every occurrence.
+//!
+//! - `cancel_recurring_task` - Function used by the initiator to stop spawning a recurring task.
+//!     Inputs:
+//!     - recurring_id: T::Hash,
+//!     Tasks that have already been spawned are kept.
+//!
+//! - `create_template` - Function used to create a task template.
+//!     Inputs:
+//!     - title, specification, attachments, keywords, open: as in `create_task_with_options`
+//!     - organization: Option<OrganizationIdOf<T>>, the organization owning the template, if any
+//!
+//! - `update_template` - Function used to change a task template, increasing its version.
+//!     Inputs:
+//!     - template_id: T::Hash,
+//!     - title, specification, attachments, keywords, open: as in `create_task_with_options`
+//!
+//! - `remove_template` - Function used to remove a task template.
+//!     Inputs:
+//!     - template_id: T::Hash,
+//!     Tasks created from the template are kept.
+//!
+//! - `create_task_from_template` - Function used to create a task from a template.
+//!     Inputs:
+//!     - template_id: T::Hash,
+//!     - budget: BalanceOf<T>,
+//!     - deadline: TaskDeadline
+//!     - x: Option<[u8; 5]>: NAD system x coordiante
+//!     - y: Option<[u8; 5]>: NAD system y coordiante
+//!
+//! - `rate_task` - Function used by the initiator or a volunteer of an accepted task to rate the other party.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - reviewee: T::AccountId, the initiator when rated by a volunteer, a volunteer otherwise
+//!     - rating: u8, from 1 to 5 stars
+//!     - review: BoundedVec
+//!     Every account can rate the same party of a task once, until the rating period has passed.
+//!
+//! - `set_task_visibility` - Function used by the initiator of a created task to set who can volunteer for it.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - visibility: TaskVisibility, Public, InviteOnly or OrganizationOnly
+//!     Only tasks of an organization can be made organization-only.
+//!
+//! - `invite_volunteer` - Function used by the initiator of a created task to invite an account to volunteer for it.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - invitee: T::AccountId
+//!
+//! - `revoke_invitation` - Function used by the initiator of a created task to revoke an invitation.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - invitee: T::AccountId
+//!
+//! - `decline_invitation` - Function used by an invitee to decline the invitation to a created task.
+//!     Inputs:
+//!     - task_id: T::Hash
+//!
+//! - `set_vesting_schedule` - Function used by the initiator of a created task to vest its payout.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - schedule: Option<VestingSchedule>, the cliff and the period in blocks, None to pay out at once
+//!
+//! - `claim_vested` - Function used by a volunteer of an accepted task to claim what has vested of their payout.
+//!     Inputs:
+//!     - task_id: T::Hash
+//!
+//! - `set_volunteer_bond` - Function used by the initiator of a created task to require a bond from its volunteers.
+//!     Inputs:
+//!     - task_id: T::Hash,
+//!     - bond: Option<VolunteerBond>, a fixed amount or a percentage of the budget, None to require no bond
 //! 
 //! Storage Items:
 //! 	Tasks: Stores Task related information
@@ -103,6 +410,60 @@
 //! 	TasksOwned: Keeps track of how many tasks are owned per account
 //! 	ExpiringTasksPerBlock: A list of task_id that expire on a given block;
 //!		DyingTasksPerBlock: A list of task_id that will die (is removed from storage) on a given block;
+//!		NextExpiringBlock: The oldest block whose expiring tasks have not all been processed yet;
+//!		NextDyingBlock: The oldest block whose dying tasks have not all been removed yet;
+//!		Milestones: The milestones of a task, in the order they were added;
+//!		ExpiringMilestonesPerBlock: A list of task_id with milestones that expire on a given block;
+//!		NextMilestoneExpiryBlock: The oldest block whose expiring milestones have not all been processed yet;
+//!		Applications: The applications of volunteers for a task, in the order they were submitted;
+//!		Disputes: The open dispute of a task;
+//!		DisputesEndingPerBlock: A list of task_id whose dispute voting period ends on a given block;
+//!		NextDisputeBlock: The oldest block whose ending disputes have not all been processed yet;
+//!		ArbitratorPool: The arbitrators drawn for disputes on tasks without an organization;
+//!		Receipts: The receipt of an accepted task;
+//!		ReceiptsByAccount: The most recent receipts an account was initiator or volunteer of;
+//!		ReceiptsByOrganization: The most recent receipts of an organization;
+//!		PrunableReceiptsPerBlock: A list of task_id whose receipt is pruned on a given block;
+//!		NextPruningBlock: The oldest block whose prunable receipts have not all been pruned yet;
+//!		ReviewsEndingPerBlock: A list of task_id whose review period ends on a given block;
+//!		NextReviewBlock: The oldest block whose ending reviews have not all been processed yet;
+//!		Subtasks: The direct subtasks of a task;
+//!		Dependencies: The tasks that have to be accepted before a task can be started;
+//!		Dependents: The tasks that are waiting for a task to be accepted;
+//!		RecurringTasks: The definitions of recurring tasks;
+//!		RecurringTasksPerBlock: A list of recurring_id whose next occurrence is spawned on a given block;
+//!		NextRecurrenceBlock: The oldest block whose recurring tasks have not all been spawned yet;
+//!		Templates: The task templates of accounts and organizations;
+//!		Ratings: The ratings of accepted tasks, by reviewer and reviewee;
+//!		RatingsByAccount: The most recent ratings an account received;
+//!		TasksByCell: The tasks located in a grid cell;
+//!		TasksByStatus: The tasks with a given status;
+//!		TasksByOrganization: The tasks of an organization;
+//!		Visibility: Who can volunteer for a task, public when not set;
+//!		Invitations: The accounts invited to volunteer for a task;
+//!		Submissions: The deliverables submitted for a task, on every completion;
+//!		VestingSchedules: The vesting schedule of the payout of a task, paid out at once when not set;
+//!		VestingPayouts: The payout of an accepted task while it vests, with what every volunteer has claimed;
+//!		Bonds: The bonds reserved by the volunteers of a task, until it is accepted or they leave it;
+//!		Escrows: The funds escrowed for a task, whatever else its escrow account receives;
+//!
+//! Read API:
+//!		escrowed_balance: The budget currently held in the escrow account of a task, or its payout while it vests;
+//!		applications: The applications for a task, with their cover note, counter-offer and status;
+//!		participants: Every volunteer working on a task;
+//!		slots_budget: The part of the task budget that has been split into volunteer slots;
+//!		disputes: The open dispute of a task, with its arbitrators and votes;
+//!		claimable: The part of a vesting payout a volunteer can claim;
+//!		receipts: The receipt of an accepted task;
+//!		account_receipts: The receipts of the tasks an account was initiator or volunteer of;
+//!		organization_receipts: The receipts of the tasks of an organization;
+//!		account_ratings: The ratings an account received, with their review;
+//!		subtree: The descendants of a task, breadth first, up to `MaxTasksWalked` of them;
+//!		is_blocked: Whether a task is still waiting for one of its dependencies to be accepted;
+//!		grid_cell: The grid cell of a location;
+//!		tasks_by_status, tasks_by_owner, tasks_by_organization, tasks_by_keyword, tasks_expiring_before,
+//!		tasks_in_area, tasks_near:
+//!			Paginated task queries, exposed to clients through the TaskApi runtime API and RPC;
 //!
 //! ## Related Modules
 //!
@@ -121,18 +482,18 @@ mod tests;
 mod benchmarking;
 pub mod weights;
 pub mod traits;
+pub mod migrations;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::{dispatch::DispatchResult, pallet_prelude::*, traits::UnixTime, PalletId};
+	use frame_support::{dispatch::DispatchResult, pallet_prelude::*, traits::{UnixTime, Randomness, OnUnbalanced}, PalletId};
 	use frame_system::pallet_prelude::*;
 	use frame_support::{
-		sp_runtime::traits::{Hash, SaturatedConversion, AccountIdConversion},
-		traits::{Currency, ReservableCurrency, tokens::ExistenceRequirement, ConstU32},
+		sp_runtime::{traits::{Hash, SaturatedConversion, AccountIdConversion, Saturating, CheckedSub, Zero, One}, Percent, Perbill, Permill},
+		traits::{Currency, ReservableCurrency, BalanceStatus, WithdrawReasons, tokens::{ExistenceRequirement, fungibles}, ConstU32},
 		transactional};
 	use scale_info::TypeInfo;
-	use sp_std::vec::Vec;
-	use core::time::Duration;
+	use sp_std::{vec::Vec, collections::btree_set::BTreeSet};
 	use crate::{
 		weights::WeightInfo,
 		TaskStatus::Created,
@@ -146,10 +507,38 @@ pub mod pallet {
 	// Use AccountId from frame_system
 	type AccountOf<T> = <T as frame_system::Config>::AccountId;
 	type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 	type OrganizationIdOf<T> = <T as frame_system::Config>::Hash;
 
 	pub type MaximumTasksPerBlock = ConstU32<10_000>;
 	pub type NadLocation = ([u8; 5], [u8; 5]);
+	/// The column and the row of a grid cell.
+	pub type GridCell = (u16, u16);
+	pub type MilestoneIndex = u32;
+
+	/// The characters of NAD coordinates, in increasing order of value.
+	pub const NAD_CHARACTERS: &[u8; 30] = b"0123456789BCDFGHJKLMNPQRSTVWXZ";
+
+	/// The number of grid cells along each axis, one per value of the first two characters of a coordinate.
+	pub const GRID_SIZE: u16 = 900;
+
+	/// The maximum radius, in grid cells, of a query around a location.
+	pub const MAX_SEARCH_RADIUS: u16 = 25;
+
+	/// The maximum amount of tasks returned by a single task query.
+	pub const MAX_TASKS_PER_PAGE: u32 = 100;
+
+	/// The maximum amount of tasks a single task query scans for matches, when it is not served by an index.
+	pub const MAX_TASKS_SCANNED: u32 = 1_000;
+
+	/// The maximum amount of blocks a single query of expiring tasks walks, a day of 6 second blocks.
+	pub const MAX_EXPIRY_BLOCKS_WALKED: u32 = 14_400;
+
+	/// The current storage version.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
+	/// A page of tasks, together with the cursor to query the next page with. The cursor is None on the last page.
+	pub type TaskPage<T> = (Vec<Task<T>>, Option<<T as frame_system::Config>::Hash>);
 
 	// Struct for holding Task information.
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -174,6 +563,250 @@ pub mod pallet {
 		pub deadline_block: Option<<T as frame_system::Config>::BlockNumber>,
 		pub task_id: T::Hash,
 		pub location: Option<NadLocation>,
+		/// Whether volunteers can start the task directly, without applying first.
+		pub open: bool,
+		/// Volunteer slots of a team task. Empty for tasks with a single volunteer.
+		pub slots: BoundedVec<VolunteerSlot<T>, T::MaxVolunteerSlots>,
+		/// The task whose budget this subtask was split from.
+		pub parent: Option<T::Hash>,
+		/// The block until which volunteers have to complete a started task.
+		pub delivery_deadline: Option<<T as frame_system::Config>::BlockNumber>,
+		/// The asset the budget is paid in. None for the native currency.
+		pub asset: Option<T::AssetId>,
+		/// The template, and the version of the template, the task was created from.
+		pub template: Option<(T::Hash, u32)>,
+		/// Whether the deadline was given as a block number. Otherwise `deadline_block` is re-anchored to the block timestamp.
+		pub deadline_by_block: bool,
+		/// The account the budget is drawn from and refunded to. The organization account for organization funded tasks.
+		pub funder: AccountOf<T>,
+		/// The bond volunteers reserve to start the task. None when no bond is required.
+		pub bond: Option<VolunteerBond<BalanceOf<T>>>,
+	}
+
+	// Struct for holding a volunteer slot of a team task.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct VolunteerSlot<T: Config> {
+		/// The share of the task budget paid to the volunteer of this slot.
+		pub share: BalanceOf<T>,
+		pub volunteer: Option<AccountOf<T>>,
+		pub completed: bool,
+	}
+
+	// Struct for holding Milestone information.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Milestone<T: Config> {
+		pub title: BoundedVec<u8, T::MaxTitleLen>,
+		/// The share of the task budget paid out when the milestone is accepted.
+		pub budget: BalanceOf<T>,
+		pub deadline: u64,
+		pub status: MilestoneStatus,
+		pub feedback: Option<BoundedVec<u8, T::MaxFeedbackLen>>,
+		pub updated_at: <T as frame_system::Config>::BlockNumber,
+	}
+
+	// Struct for holding the application of a volunteer for a task.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Application<T: Config> {
+		pub applicant: AccountOf<T>,
+		pub cover_note: BoundedVec<u8, T::MaxCoverNoteLen>,
+		/// Counter-offer for the task budget, if the applicant asks for a different price.
+		pub price: Option<BalanceOf<T>>,
+		pub status: ApplicationStatus,
+		pub created_at: <T as frame_system::Config>::BlockNumber,
+	}
+
+	// Set ApplicationStatus enum.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum ApplicationStatus {
+		Pending,
+		Shortlisted,
+		Selected,
+		Declined,
+	}
+
+	// Struct for holding a reference to delivered work.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Deliverable<T: Config> {
+		/// Content hash or IPFS CID of the delivered work.
+		pub reference: BoundedVec<u8, T::MaxDeliverableReferenceLen>,
+		pub description: Option<BoundedVec<u8, T::MaxDeliverableDescriptionLen>>,
+	}
+
+	// Struct for holding the deliverables submitted when completing a task.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Submission<T: Config> {
+		pub volunteer: AccountOf<T>,
+		pub deliverables: BoundedVec<Deliverable<T>, T::MaxDeliverablesPerSubmission>,
+		pub submitted_at: <T as frame_system::Config>::BlockNumber,
+	}
+
+	pub type SubmissionsOf<T> = BoundedVec<Submission<T>, <T as Config>::MaxSubmissionsPerTask>;
+
+	// Struct for holding the vesting schedule of the payout of a task.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct VestingSchedule<BlockNumber> {
+		/// The number of blocks after acceptance before anything vests.
+		pub cliff: BlockNumber,
+		/// The number of blocks after the cliff over which the payout vests linearly.
+		pub period: BlockNumber,
+	}
+
+	// Struct for holding the share of a vesting payout owed to a volunteer.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct VestingShare<T: Config> {
+		pub volunteer: AccountOf<T>,
+		pub amount: BalanceOf<T>,
+		/// The part of the amount the volunteer has already claimed.
+		pub claimed: BalanceOf<T>,
+	}
+
+	// Struct for holding the payout of an accepted task while it vests in the escrow account of the task.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct VestingPayout<T: Config> {
+		pub initiator: AccountOf<T>,
+		/// The account refunded the unvested amount cancelled by a dispute.
+		pub funder: AccountOf<T>,
+		pub organization: Option<OrganizationIdOf<T>>,
+		/// The asset the payout is made in. None for the native currency.
+		pub asset: Option<T::AssetId>,
+		/// The block the cliff ends on, nothing has vested before it.
+		pub starts_at: <T as frame_system::Config>::BlockNumber,
+		/// The block the payout has completely vested on.
+		pub ends_at: <T as frame_system::Config>::BlockNumber,
+		pub shares: BoundedVec<VestingShare<T>, T::MaxVolunteerSlots>,
+	}
+
+	// Struct for holding Dispute information.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Dispute<T: Config> {
+		pub opened_by: AccountOf<T>,
+		pub reason: BoundedVec<u8, T::MaxFeedbackLen>,
+		/// The submissions of the task when the dispute was opened.
+		pub evidence: SubmissionsOf<T>,
+		/// The deposit reserved from the account that opened the dispute.
+		pub deposit: BalanceOf<T>,
+		pub arbitrators: BoundedVec<AccountOf<T>, T::ArbitratorsPerDispute>,
+		/// The share of the escrowed budget each arbitrator awards to the volunteers.
+		pub votes: BoundedVec<(AccountOf<T>, Percent), T::ArbitratorsPerDispute>,
+		/// The status of the task before the dispute was opened, restored if nobody votes.
+		pub previous_status: TaskStatus,
+		pub opened_at: <T as frame_system::Config>::BlockNumber,
+		pub voting_ends_at: <T as frame_system::Config>::BlockNumber,
+	}
+
+	// Struct for holding the receipt of an accepted task, kept after the task is removed.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct TaskReceipt<T: Config> {
+		pub task_id: T::Hash,
+		pub initiator: AccountOf<T>,
+		pub volunteers: BoundedVec<AccountOf<T>, T::MaxVolunteerSlots>,
+		/// The total amount paid to the volunteers, milestone payouts included, net of the fees.
+		pub amount: BalanceOf<T>,
+		/// The asset the amount was paid in. None for the native currency.
+		pub asset: Option<T::AssetId>,
+		pub organization: Option<OrganizationIdOf<T>>,
+		pub accepted_at: <T as frame_system::Config>::BlockNumber,
+		pub title_hash: T::Hash,
+	}
+
+	// Struct for holding the rating and review one party of an accepted task gave the other.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Rating<T: Config> {
+		pub task_id: T::Hash,
+		pub reviewer: AccountOf<T>,
+		pub reviewee: AccountOf<T>,
+		/// From 1 to 5 stars.
+		pub rating: u8,
+		pub review: BoundedVec<u8, T::MaxReviewLen>,
+		pub rated_at: <T as frame_system::Config>::BlockNumber,
+	}
+
+	// Struct for holding a recurring task definition. A task is spawned from it at every interval.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct RecurringTask<T: Config> {
+		pub title: BoundedVec<u8, T::MaxTitleLen>,
+		pub specification: BoundedVec<u8, T::MaxSpecificationLen>,
+		pub initiator: AccountOf<T>,
+		/// The budget drawn from the initiator for every occurrence.
+		pub budget: BalanceOf<T>,
+		pub asset: Option<T::AssetId>,
+		pub attachments: BoundedVec<u8, T::MaxAttachmentsLen>,
+		pub keywords: BoundedVec<u8, T::MaxKeywordsLen>,
+		pub organization: Option<OrganizationIdOf<T>>,
+		pub location: Option<NadLocation>,
+		pub open: bool,
+		/// The number of blocks between two occurrences.
+		pub interval: <T as frame_system::Config>::BlockNumber,
+		pub max_occurrences: u32,
+		/// The number of tasks spawned so far.
+		pub occurrences: u32,
+		/// The block the next occurrence is spawned on.
+		pub next_occurrence: <T as frame_system::Config>::BlockNumber,
+	}
+
+	// Struct for holding a task template. Tasks created from it only set their budget, deadline and location.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct TaskTemplate<T: Config> {
+		pub owner: TemplateOwner<AccountOf<T>, OrganizationIdOf<T>>,
+		pub title: BoundedVec<u8, T::MaxTitleLen>,
+		pub specification: BoundedVec<u8, T::MaxSpecificationLen>,
+		pub attachments: BoundedVec<u8, T::MaxAttachmentsLen>,
+		pub keywords: BoundedVec<u8, T::MaxKeywordsLen>,
+		pub open: bool,
+		/// Increased every time the template is updated.
+		pub version: u32,
+		pub updated_at: <T as frame_system::Config>::BlockNumber,
+	}
+
+	// Set TaskDeadline enum.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum TaskDeadline<BlockNumber> {
+		/// Unix time in milliseconds.
+		Timestamp(u64),
+		/// Absolute block number.
+		Block(BlockNumber),
+	}
+
+	// Set VolunteerBond enum.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum VolunteerBond<Balance> {
+		/// A fixed amount of the native currency.
+		Amount(Balance),
+		/// A percentage of the budget of the task, or of the share of the slot for team tasks.
+		Percent(Percent),
+	}
+
+	// Set TemplateOwner enum.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum TemplateOwner<AccountId, OrganizationId> {
+		Account(AccountId),
+		/// Shared by every member of the organization.
+		Organization(OrganizationId),
+	}
+
+	// Set MilestoneStatus enum.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum MilestoneStatus {
+		Created,
+		Submitted,
+		Accepted,
+		Rejected,
+		Expired,
 	}
 
 	// Set TaskStatus enum.
@@ -186,19 +819,52 @@ pub mod pallet {
 		Completed,
 		Accepted,
 		Expired,
+		Disputed,
   	}
 
+	/// Who can volunteer for a task.
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum TaskVisibility {
+		/// Any account with a profile.
+		Public,
+		/// Only the accounts invited by the initiator.
+		InviteOnly,
+		/// Only the members of the task organization.
+		OrganizationOnly,
+	}
+
+	impl Default for TaskVisibility {
+		fn default() -> Self {
+			TaskVisibility::Public
+		}
+	}
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config + pallet_profile::Config {
+	pub trait Config: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
 		/// Currency type that is linked with AccountID
 		type Currency: ReservableCurrency<Self::AccountId>;
 
-		/// Organization type used to verify organization existence
-		type Organization: traits::Organization<Self::Hash>;
+		/// Identifier of the assets task budgets can be paid in.
+		type AssetId: Member + Parameter + Copy + Default + MaxEncodedLen;
+
+		/// Assets task budgets can be paid in besides the native currency.
+		/// Escrow accounts hold no native balance, so only sufficient assets can be escrowed.
+		type Assets: fungibles::Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = BalanceOf<Self>>
+			+ fungibles::Transfer<Self::AccountId>;
+
+		/// Organization type used to verify organization existence and draw arbitrators from its members
+		type Organization: traits::Organization<Self::Hash, Self::AccountId>;
+
+		/// Profiles of the accounts, which have to exist to create or apply for tasks, and locate tasks nearby
+		type Profiles: traits::Profiles<Self::AccountId>;
+
+		/// Callbacks on the lifecycle of tasks. A tuple of every implementor, called in order.
+		type LifecycleHooks: traits::TaskLifecycleHooks<Self::AccountId, Self::Hash>;
 
 		/// Time provider type
 		type Time: UnixTime;
@@ -208,6 +874,33 @@ pub mod pallet {
 		/// Grace period after a task has expired before it is removed from storage.
 		type TaskLongevityAfterExpiration: Get<<Self as frame_system::Config>::BlockNumber>;
 
+		/// The maximum weight spent on expiring and removing tasks, and expiring milestones, at the start of a block.
+		/// The tasks that do not fit are processed with the weight left at the end of the block, or in later blocks.
+		#[pallet::constant]
+		type MaxExpiryWeight: Get<Weight>;
+
+		/// The maximum weight spent at the start of a block on the disputes, reviews, recurring tasks and receipts
+		/// scheduled up to that block.
+		/// What does not fit is processed with the weight left at the end of the block, or in later blocks.
+		#[pallet::constant]
+		type MaxScheduledWeight: Get<Weight>;
+
+		/// The minimum number of blocks volunteers have to complete a task once it has been started.
+		#[pallet::constant]
+		type DeliveryPeriod: Get<<Self as frame_system::Config>::BlockNumber>;
+
+		/// The number of blocks the initiator has to review a completed task before it is accepted automatically.
+		#[pallet::constant]
+		type ReviewPeriod: Get<<Self as frame_system::Config>::BlockNumber>;
+
+		/// The number of blocks after a task is accepted during which its parties can rate each other.
+		#[pallet::constant]
+		type RatingPeriod: Get<<Self as frame_system::Config>::BlockNumber>;
+
+		/// The minimum number of blocks between two occurrences of a recurring task.
+		#[pallet::constant]
+		type MinRecurrenceInterval: Get<<Self as frame_system::Config>::BlockNumber>;
+
 		/// The maximum amount of tasks a single account can own.
 		#[pallet::constant]
 		type MaxTasksOwned: Get<u32>;
@@ -227,33 +920,156 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxKeywordsLen: Get<u32> + MaxEncodedLen + TypeInfo;
 
-		/// WeightInfo provider.
-		type WeightInfo: WeightInfo;
+		/// The maximum amount of milestones a single task can be split into.
+		#[pallet::constant]
+		type MaxMilestonesPerTask: Get<u32> + MaxEncodedLen + TypeInfo;
 
+		/// The maximum amount of applications a single task can receive.
 		#[pallet::constant]
-		type PalletId: Get<PalletId>;
-	}
+		type MaxApplicationsPerTask: Get<u32> + MaxEncodedLen + TypeInfo;
 
-	#[pallet::pallet]
-	#[pallet::generate_store(pub(super) trait Store)]
-	pub struct Pallet<T>(_);
+		#[pallet::constant]
+		type MaxCoverNoteLen: Get<u32> + MaxEncodedLen + TypeInfo;
 
-	//TODO!!!!!!! BOUNDED VEC SIZE
-	#[pallet::storage]
-	/// Tasks: The Tasks that end on a given block number [key: Block Number, value: Vec<TaskId>]
-	pub(super) type ExpiringTasksPerBlock<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<T::Hash, MaximumTasksPerBlock>, ValueQuery>;
+		/// The maximum amount of volunteers that can work on a single team task.
+		#[pallet::constant]
+		type MaxVolunteerSlots: Get<u32> + MaxEncodedLen + TypeInfo;
 
-	#[pallet::storage]
-	/// Tasks that will be removed from storage on a given block. [key: Block Number, value: Vec<TaskId>]
-	pub(super) type DyingTasksPerBlock<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<T::Hash, MaximumTasksPerBlock>, ValueQuery>;
+		/// Randomness source used to draw arbitrators for disputes.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
 
-	#[pallet::storage]
-	#[pallet::getter(fn task_count)]
-	/// TaskCount: Get total number of Tasks in the system
-	pub(super) type TaskCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+		/// The amount reserved from the account that opens a dispute.
+		#[pallet::constant]
+		type DisputeDeposit: Get<BalanceOf<Self>>;
 
-	#[pallet::storage]
-	#[pallet::getter(fn tasks)]
+		/// The number of blocks arbitrators have to vote on a dispute.
+		#[pallet::constant]
+		type DisputeVotingPeriod: Get<Self::BlockNumber>;
+
+		/// Handler for the dispute deposits that are slashed.
+		type DisputeSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The share of every payout taken as a platform fee when a task or milestone is accepted.
+		#[pallet::constant]
+		type PlatformFee: Get<Permill>;
+
+		/// The share of every payout of an organization task that goes to the organization account. Zero disables the cut.
+		#[pallet::constant]
+		type OrganizationFee: Get<Permill>;
+
+		/// Handler for the platform fees taken from payouts.
+		type PlatformFeeDestination: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The account the platform fees taken from asset payouts are transferred to.
+		type PlatformFeeAccount: Get<Self::AccountId>;
+
+		/// The number of arbitrators drawn for a single dispute.
+		#[pallet::constant]
+		type ArbitratorsPerDispute: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// The maximum amount of arbitrators in the arbitrator pool.
+		#[pallet::constant]
+		type MaxArbitratorPool: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// The number of blocks receipts of accepted tasks are kept before being pruned.
+		#[pallet::constant]
+		type ReceiptLongevity: Get<Self::BlockNumber>;
+
+		/// The maximum amount of receipts indexed per account. The oldest receipts are dropped first.
+		#[pallet::constant]
+		type MaxReceiptsPerAccount: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// The maximum amount of receipts indexed per organization. The oldest receipts are dropped first.
+		#[pallet::constant]
+		type MaxReceiptsPerOrganization: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// The maximum length of the review left with a rating.
+		#[pallet::constant]
+		type MaxReviewLen: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// The maximum amount of ratings indexed per account. The oldest ratings are dropped first.
+		#[pallet::constant]
+		type MaxRatingsPerAccount: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// The maximum amount of tasks located in a single grid cell.
+		#[pallet::constant]
+		type MaxTasksPerCell: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// The maximum amount of direct subtasks a single task can be split into.
+		#[pallet::constant]
+		type MaxSubtasksPerTask: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// The maximum nesting depth of subtasks.
+		#[pallet::constant]
+		type MaxSubtaskDepth: Get<u32>;
+
+		/// The maximum amount of dependencies of a single task, and of tasks depending on a single task.
+		#[pallet::constant]
+		type MaxDependenciesPerTask: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// The maximum amount of tasks visited when walking the subtasks or the dependencies of a task,
+		/// by removing a task with its subtasks or by checking a new dependency for cycles.
+		#[pallet::constant]
+		type MaxTasksWalked: Get<u32>;
+
+		/// The maximum amount of accounts invited to volunteer for a single task.
+		#[pallet::constant]
+		type MaxInvitationsPerTask: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// The maximum length of the content hash or IPFS CID of a deliverable.
+		#[pallet::constant]
+		type MaxDeliverableReferenceLen: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// The maximum length of the description of a deliverable.
+		#[pallet::constant]
+		type MaxDeliverableDescriptionLen: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// The maximum amount of deliverables submitted when completing a task.
+		#[pallet::constant]
+		type MaxDeliverablesPerSubmission: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// The maximum amount of submissions kept per task, the oldest submission is dropped beyond it.
+		#[pallet::constant]
+		type MaxSubmissionsPerTask: Get<u32> + MaxEncodedLen + TypeInfo;
+
+		/// WeightInfo provider.
+		type WeightInfo: WeightInfo;
+
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	//TODO!!!!!!! BOUNDED VEC SIZE
+	#[pallet::storage]
+	/// Tasks: The Tasks that end on a given block number [key: Block Number, value: Vec<TaskId>]
+	pub(super) type ExpiringTasksPerBlock<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<T::Hash, MaximumTasksPerBlock>, ValueQuery>;
+
+	#[pallet::storage]
+	/// Tasks that will be removed from storage on a given block. [key: Block Number, value: Vec<TaskId>]
+	pub(super) type DyingTasksPerBlock<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<T::Hash, MaximumTasksPerBlock>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_expiring_block)]
+	/// The oldest block whose expiring tasks have not all been processed yet, the current block when unset.
+	pub(super) type NextExpiringBlock<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_dying_block)]
+	/// The oldest block whose dying tasks have not all been removed yet, the current block when unset.
+	pub(super) type NextDyingBlock<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn task_count)]
+	/// TaskCount: Get total number of Tasks in the system
+	pub(super) type TaskCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tasks)]
 	/// Tasks: Store Tasks in a  Storage Map where [key: hash, value: Task]
 	pub(super) type Tasks<T: Config> = StorageMap<_, Twox64Concat, T::Hash, Task<T>>;
 
@@ -262,6 +1078,169 @@ pub mod pallet {
 	/// Keeps track of which Accounts own which Tasks.
 	pub(super) type TasksOwned<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<T::Hash, T::MaxTasksOwned>, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn milestones)]
+	/// Milestones of a task in the order they were added. [key: task_id, value: BoundedVec<Milestone>]
+	pub(super) type Milestones<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<Milestone<T>, T::MaxMilestonesPerTask>, ValueQuery>;
+
+	#[pallet::storage]
+	/// Tasks with milestones whose deadline is expected on a given block. [key: Block Number, value: Vec<TaskId>]
+	pub(super) type ExpiringMilestonesPerBlock<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<T::Hash, MaximumTasksPerBlock>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_milestone_expiry_block)]
+	/// The oldest block whose expiring milestones have not all been processed yet, the current block when unset.
+	pub(super) type NextMilestoneExpiryBlock<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn applications)]
+	/// Applications for a task in the order they were submitted. [key: task_id, value: BoundedVec<Application>]
+	pub(super) type Applications<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<Application<T>, T::MaxApplicationsPerTask>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn disputes)]
+	/// Open disputes. [key: task_id, value: Dispute]
+	pub(super) type Disputes<T: Config> = StorageMap<_, Twox64Concat, T::Hash, Dispute<T>>;
+
+	#[pallet::storage]
+	/// Disputes whose voting period ends on a given block. [key: Block Number, value: Vec<TaskId>]
+	pub(super) type DisputesEndingPerBlock<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<T::Hash, MaximumTasksPerBlock>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_dispute_block)]
+	/// The oldest block whose ending disputes have not all been processed yet, the current block when unset.
+	pub(super) type NextDisputeBlock<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn arbitrator_pool)]
+	/// Arbitrators drawn for disputes on tasks without an organization.
+	pub(super) type ArbitratorPool<T: Config> = StorageValue<_, BoundedVec<T::AccountId, T::MaxArbitratorPool>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn receipts)]
+	/// Receipts of accepted tasks. [key: task_id, value: TaskReceipt]
+	pub(super) type Receipts<T: Config> = StorageMap<_, Twox64Concat, T::Hash, TaskReceipt<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn receipts_by_account)]
+	/// The most recent receipts an account was initiator or volunteer of. [key: AccountId, value: Vec<TaskId>]
+	pub(super) type ReceiptsByAccount<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<T::Hash, T::MaxReceiptsPerAccount>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn receipts_by_organization)]
+	/// The most recent receipts of an organization. [key: OrganizationId, value: Vec<TaskId>]
+	pub(super) type ReceiptsByOrganization<T: Config> = StorageMap<_, Twox64Concat, OrganizationIdOf<T>, BoundedVec<T::Hash, T::MaxReceiptsPerOrganization>, ValueQuery>;
+
+	#[pallet::storage]
+	/// Receipts that will be pruned from storage on a given block. [key: Block Number, value: Vec<TaskId>]
+	pub(super) type PrunableReceiptsPerBlock<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<T::Hash, MaximumTasksPerBlock>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_pruning_block)]
+	/// The oldest block whose prunable receipts have not all been pruned yet, the current block when unset.
+	pub(super) type NextPruningBlock<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	/// Completed tasks whose review period ends on a given block. [key: Block Number, value: Vec<TaskId>]
+	pub(super) type ReviewsEndingPerBlock<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<T::Hash, MaximumTasksPerBlock>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_review_block)]
+	/// The oldest block whose ending reviews have not all been processed yet, the current block when unset.
+	pub(super) type NextReviewBlock<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn subtasks)]
+	/// Direct subtasks of a task in the order they were created. [key: task_id, value: Vec<TaskId>]
+	pub(super) type Subtasks<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<T::Hash, T::MaxSubtasksPerTask>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn dependencies)]
+	/// Tasks that have to be accepted before a task can be started. [key: task_id, value: Vec<TaskId>]
+	pub(super) type Dependencies<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<T::Hash, T::MaxDependenciesPerTask>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn dependents)]
+	/// Tasks that are waiting for a task to be accepted. [key: task_id, value: Vec<TaskId>]
+	pub(super) type Dependents<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<T::Hash, T::MaxDependenciesPerTask>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn recurring_tasks)]
+	/// Definitions of recurring tasks. [key: recurring_id, value: RecurringTask]
+	pub(super) type RecurringTasks<T: Config> = StorageMap<_, Twox64Concat, T::Hash, RecurringTask<T>>;
+
+	#[pallet::storage]
+	/// Recurring tasks whose next occurrence is spawned on a given block. [key: Block Number, value: Vec<RecurringId>]
+	pub(super) type RecurringTasksPerBlock<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<T::Hash, MaximumTasksPerBlock>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_recurrence_block)]
+	/// The oldest block whose recurring tasks have not all been spawned yet, the current block when unset.
+	pub(super) type NextRecurrenceBlock<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn templates)]
+	/// Task templates of accounts and organizations. [key: template_id, value: TaskTemplate]
+	pub(super) type Templates<T: Config> = StorageMap<_, Twox64Concat, T::Hash, TaskTemplate<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn ratings)]
+	/// Ratings of accepted tasks. [key: (task_id, (reviewer, reviewee)), value: Rating]
+	pub(super) type Ratings<T: Config> = StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, (T::AccountId, T::AccountId), Rating<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn ratings_by_account)]
+	/// The most recent ratings an account received. [key: AccountId, value: Vec<(TaskId, reviewer)>]
+	pub(super) type RatingsByAccount<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<(T::Hash, T::AccountId), T::MaxRatingsPerAccount>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn tasks_by_cell)]
+	/// Tasks located in a grid cell, until they expire or are removed. [key: GridCell, value: Vec<TaskId>]
+	pub(super) type TasksByCell<T: Config> = StorageMap<_, Twox64Concat, GridCell, BoundedVec<T::Hash, T::MaxTasksPerCell>, ValueQuery>;
+
+	#[pallet::storage]
+	/// The tasks with a given status, until they are removed. [key: (TaskStatus, TaskId), value: ()]
+	pub(super) type TasksByStatus<T: Config> = StorageDoubleMap<_, Twox64Concat, TaskStatus, Twox64Concat, T::Hash, (), OptionQuery>;
+
+	#[pallet::storage]
+	/// The tasks of an organization, until they are removed. [key: (OrganizationId, TaskId), value: ()]
+	pub(super) type TasksByOrganization<T: Config> = StorageDoubleMap<_, Twox64Concat, OrganizationIdOf<T>, Twox64Concat, T::Hash, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn visibility)]
+	/// Who can volunteer for a task, public when not set. [key: TaskId, value: TaskVisibility]
+	pub(super) type Visibility<T: Config> = StorageMap<_, Twox64Concat, T::Hash, TaskVisibility, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn invitations)]
+	/// The accounts invited to volunteer for a task. [key: TaskId, value: Vec<AccountId>]
+	pub(super) type Invitations<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BoundedVec<T::AccountId, T::MaxInvitationsPerTask>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn submissions)]
+	/// The deliverables submitted for a task, oldest first. [key: TaskId, value: Vec<Submission>]
+	pub(super) type Submissions<T: Config> = StorageMap<_, Twox64Concat, T::Hash, SubmissionsOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_schedules)]
+	/// The vesting schedule of the payout of a task, paid out at once when not set. [key: TaskId, value: VestingSchedule]
+	pub(super) type VestingSchedules<T: Config> = StorageMap<_, Twox64Concat, T::Hash, VestingSchedule<T::BlockNumber>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_payouts)]
+	/// The payout of an accepted task while it vests. [key: TaskId, value: VestingPayout]
+	pub(super) type VestingPayouts<T: Config> = StorageMap<_, Twox64Concat, T::Hash, VestingPayout<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bonds)]
+	/// The bonds reserved by the volunteers of a task. [key: (TaskId, AccountId), value: Balance]
+	pub(super) type Bonds<T: Config> = StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn escrows)]
+	/// The funds escrowed for a task, or for its payout while it vests. [key: TaskId, value: Balance]
+	pub(super) type Escrows<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -288,6 +1267,135 @@ pub mod pallet {
 
 		/// Task revivied by owner [AccountID, task_id]
 		TaskRevived(T::AccountId, T::Hash),
+
+		/// Milestone added to a task by owner [AccountID, task_id, milestone]
+		MilestoneAdded(T::AccountId, T::Hash, MilestoneIndex),
+
+		/// Milestone submitted for review by volunteer [AccountID, task_id, milestone]
+		MilestoneSubmitted(T::AccountId, T::Hash, MilestoneIndex),
+
+		/// Milestone accepted and paid out by owner [AccountID, task_id, milestone, amount]
+		MilestoneAccepted(T::AccountId, T::Hash, MilestoneIndex, BalanceOf<T>),
+
+		/// Milestone rejected by owner [AccountID, task_id, milestone]
+		MilestoneRejected(T::AccountId, T::Hash, MilestoneIndex),
+
+		/// Milestone not submitted before its deadline, its budget refunded to the funder [task_id, milestone, amount]
+		MilestoneExpired(T::Hash, MilestoneIndex, BalanceOf<T>),
+
+		/// Expired milestones whose budget could not be refunded, it stays in the task budget [task_id, error]
+		MilestoneRefundFailed(T::Hash, DispatchError),
+
+		/// Fees taken from a payout on acceptance [task_id, platform_fee, organization_cut, payout]
+		PayoutFeesTaken(T::Hash, BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
+
+		/// Volunteer applied for a task [AccountID, task_id]
+		ApplicationSubmitted(T::AccountId, T::Hash),
+
+		/// Volunteer withdrew the application for a task [AccountID, task_id]
+		ApplicationWithdrawn(T::AccountId, T::Hash),
+
+		/// Application shortlisted by owner [AccountID, task_id, applicant]
+		ApplicationShortlisted(T::AccountId, T::Hash, T::AccountId),
+
+		/// Application selected by owner, the applicant becomes the volunteer [AccountID, task_id, applicant]
+		ApplicationSelected(T::AccountId, T::Hash, T::AccountId),
+
+		/// Volunteer slots of a task set by owner [AccountID, task_id, slots]
+		VolunteerSlotsSet(T::AccountId, T::Hash, u32),
+
+		/// Dispute opened on a rejected task [AccountID, task_id]
+		DisputeOpened(T::AccountId, T::Hash),
+
+		/// Arbitrator voted on a dispute [AccountID, task_id, volunteer_share]
+		DisputeVoted(T::AccountId, T::Hash, Percent),
+
+		/// Dispute resolved and escrow split by the verdict [task_id, volunteer_share, paid to volunteers, refunded to initiator]
+		DisputeResolved(T::Hash, Percent, BalanceOf<T>, BalanceOf<T>),
+
+		/// Dispute dismissed because no arbitrator voted [task_id]
+		DisputeDismissed(T::Hash),
+
+		/// Dispute that could not be resolved at the end of its voting period, retried after another one [task_id, error]
+		DisputeResolutionFailed(T::Hash, DispatchError),
+
+		/// Arbitrator pool set by root [arbitrators]
+		ArbitratorPoolSet(u32),
+
+		/// Subtask split from the budget of a task [AccountID, parent_id, task_id]
+		SubtaskCreated(T::AccountId, T::Hash, T::Hash),
+
+		/// Task blocked until another task is accepted [AccountID, task_id, depends_on]
+		DependencyAdded(T::AccountId, T::Hash, T::Hash),
+
+		/// Dependency of a task removed by owner [AccountID, task_id, depends_on]
+		DependencyRemoved(T::AccountId, T::Hash, T::Hash),
+
+		/// Task past its delivery deadline reclaimed by owner [AccountID, task_id]
+		TaskReclaimed(T::AccountId, T::Hash),
+
+		/// Task abandoned by volunteer [AccountID, task_id]
+		TaskAbandoned(T::AccountId, T::Hash),
+
+		/// Completed task accepted after its review period has passed [task_id]
+		TaskAutoAccepted(T::Hash),
+
+		/// Completed task that could not be accepted after its review period, retried after another one [task_id, error]
+		TaskAutoAcceptFailed(T::Hash, DispatchError),
+
+		/// Recurring task defined [AccountID, recurring_id]
+		RecurringTaskCreated(T::AccountId, T::Hash),
+
+		/// Task spawned from a recurring task [recurring_id, task_id]
+		RecurringTaskSpawned(T::Hash, T::Hash),
+
+		/// Recurring task stopped because its next occurrence could not be funded [recurring_id]
+		RecurringTaskStopped(T::Hash),
+
+		/// Occurrence of a recurring task that could not be spawned, retried after another interval [recurring_id, error]
+		RecurringTaskSpawnFailed(T::Hash, DispatchError),
+
+		/// Recurring task cancelled by owner [AccountID, recurring_id]
+		RecurringTaskCancelled(T::AccountId, T::Hash),
+
+		/// Task template created [AccountID, template_id]
+		TemplateCreated(T::AccountId, T::Hash),
+
+		/// Task template updated to a new version [AccountID, template_id, version]
+		TemplateUpdated(T::AccountId, T::Hash, u32),
+
+		/// Task template removed [AccountID, template_id]
+		TemplateRemoved(T::AccountId, T::Hash),
+
+		/// Party of an accepted task rated [reviewer, reviewee, task_id, rating]
+		TaskRated(T::AccountId, T::AccountId, T::Hash, u8),
+
+		/// Visibility of a task set by owner [AccountID, task_id, visibility]
+		TaskVisibilitySet(T::AccountId, T::Hash, TaskVisibility),
+
+		/// Account invited to volunteer for a task by owner [AccountID, task_id, invitee]
+		VolunteerInvited(T::AccountId, T::Hash, T::AccountId),
+
+		/// Invitation to volunteer for a task revoked by owner [AccountID, task_id, invitee]
+		InvitationRevoked(T::AccountId, T::Hash, T::AccountId),
+
+		/// Invitation to volunteer for a task declined by invitee [AccountID, task_id]
+		InvitationDeclined(T::AccountId, T::Hash),
+
+		/// Vesting schedule of the payout of a task set by owner [AccountID, task_id]
+		VestingScheduleSet(T::AccountId, T::Hash),
+
+		/// Payout of an accepted task kept in escrow while it vests [task_id, starts_at, ends_at]
+		PayoutVesting(T::Hash, T::BlockNumber, T::BlockNumber),
+
+		/// Vested part of a payout claimed by volunteer [AccountID, task_id, amount]
+		VestedClaimed(T::AccountId, T::Hash, BalanceOf<T>),
+
+		/// Bond volunteers reserve to start a task set by owner [AccountID, task_id]
+		VolunteerBondSet(T::AccountId, T::Hash),
+
+		/// Bond of a volunteer who abandoned a task or lost its dispute slashed to the initiator [AccountID, task_id, amount]
+		BondSlashed(T::AccountId, T::Hash, BalanceOf<T>),
 	}
 
 	// Errors inform users that something went wrong.
@@ -319,12 +1427,146 @@ pub mod pallet {
 		NoProfile,
 		/// Provided deadline value can not be accepted.
 		IncorrectDeadlineTimestamp,
+		/// Provided deadline block has already passed.
+		IncorrectDeadlineBlock,
 		/// Only Task creator can update the task.
 		OnlyInitiatorUpdatesTask,
 		/// The provided organization identifier does not exist.
 		InvalidOrganization,
 		/// Expiry or Dying Tasks limit reached.
 		ExpiringTaskLimitReached,
+		/// The given milestone doesn't exist.
+		MilestoneNotExist,
+		/// Reached maximum number of milestones for this task.
+		TooManyMilestones,
+		/// The milestone budgets exceed the budget of the task.
+		MilestoneBudgetExceeded,
+		/// The deadline of the milestone has passed before it was submitted.
+		MilestoneDeadlinePassed,
+		/// Only milestones that are created or rejected can be submitted.
+		NoPermissionToSubmitMilestone,
+		/// Only submitted milestones can be accepted or rejected.
+		OnlySubmittedMilestonesAreReviewed,
+		/// This task can only be started by selecting an application.
+		TaskRequiresApplication,
+		/// Only created tasks accept applications, and the initiator can't apply.
+		NoPermissionToApply,
+		/// This account has already applied for the task.
+		AlreadyApplied,
+		/// The given application doesn't exist.
+		ApplicationNotExist,
+		/// Reached maximum number of applications for this task.
+		TooManyApplications,
+		/// The application has already been selected or declined.
+		ApplicationClosed,
+		/// The volunteer slot shares must add up to the task budget.
+		SlotSharesMismatch,
+		/// This account has already joined the team task.
+		AlreadyJoined,
+		/// Volunteer slots can't be changed after volunteers have joined.
+		NoPermissionToChangeSlots,
+		/// Team tasks can't be split into milestones.
+		MilestonesNotSupportedForTeamTasks,
+		/// The task is under dispute.
+		TaskDisputed,
+		/// Only the initiator or a volunteer of a rejected task can open a dispute.
+		NoPermissionToDispute,
+		/// There is no one eligible to arbitrate the dispute.
+		NotEnoughArbitrators,
+		/// The given task has no open dispute.
+		DisputeNotExist,
+		/// Only arbitrators drawn for the dispute can vote.
+		NotAnArbitrator,
+		/// This arbitrator has already voted.
+		AlreadyVoted,
+		/// Disputes limit reached for the block the voting period ends on.
+		DisputeLimitReached,
+		/// Receipts limit reached for the block they are pruned on.
+		ReceiptLimitReached,
+		/// The subtask budget exceeds the part of the parent budget not split into milestones.
+		SubtaskBudgetExceeded,
+		/// Reached maximum number of subtasks for this task.
+		TooManySubtasks,
+		/// Reached maximum nesting depth of subtasks.
+		SubtaskTooDeep,
+		/// Reached maximum number of dependencies for one of the tasks.
+		TooManyDependencies,
+		/// The dependency would make the task depend on itself.
+		DependencyCycle,
+		/// Walking the subtasks or dependencies of the task visits more tasks than allowed.
+		WalkLimitReached,
+		/// The task already depends on the given task.
+		AlreadyDependsOn,
+		/// The task doesn't depend on the given task.
+		DependencyNotExist,
+		/// The task can't be started before every task it depends on has been accepted.
+		TaskBlocked,
+		/// Only the initiator of an in-progress task can reclaim it.
+		NoPermissionToReclaim,
+		/// The delivery deadline of the task has not passed yet.
+		DeliveryDeadlineNotReached,
+		/// Only volunteers who have not completed their part of an in-progress task can abandon it.
+		NoPermissionToAbandon,
+		/// Reviews limit reached for the block the review period ends on.
+		ReviewLimitReached,
+		/// The given recurring task doesn't exist.
+		RecurringTaskNotExist,
+		/// The same recurring task has already been defined in this block.
+		RecurringTaskExists,
+		/// The interval between two occurrences is shorter than the minimum recurrence interval.
+		RecurrenceIntervalTooShort,
+		/// A recurring task has to be spawned at least once.
+		NoOccurrences,
+		/// Recurring tasks limit reached for the block the next occurrence is spawned on.
+		RecurrenceLimitReached,
+		/// The given template doesn't exist.
+		TemplateNotExist,
+		/// The same template has already been created in this block.
+		TemplateExists,
+		/// Only the owner of a template, or the members of the organization owning it, can use and manage it.
+		NoPermissionForTemplate,
+		/// Only accounts that can spend from the organization account can fund tasks from it.
+		NoPermissionToFund,
+		/// The given task has no receipt, it has not been accepted or its receipt has been pruned.
+		ReceiptNotExist,
+		/// The rating period of this task has passed.
+		RatingPeriodEnded,
+		/// Only the initiator and the volunteers of a task can rate each other.
+		NoPermissionToRate,
+		/// Ratings are given from 1 to 5 stars.
+		InvalidRating,
+		/// This party of the task has already been rated by this account.
+		AlreadyRated,
+		/// Tasks limit reached for the grid cell of the task location.
+		CellLimitReached,
+		/// Only invited accounts can volunteer for an invite-only task.
+		NotInvited,
+		/// This account has already been invited to volunteer for the task.
+		AlreadyInvited,
+		/// Reached maximum number of invitations for this task.
+		TooManyInvitations,
+		/// Only members of the task organization can volunteer for an organization-only task.
+		NotOrganizationMember,
+		/// Only tasks of an organization can be made organization-only.
+		TaskHasNoOrganization,
+		/// Only the initiator of a created task can change who volunteers for it, and can't invite themselves.
+		NoPermissionToInvite,
+		/// The payout of the given task is not vesting.
+		VestingNotExist,
+		/// The budget, a share or a milestone of it, or what is left of it, is below the minimum balance of an escrow account.
+		BudgetBelowMinimum,
+		/// Not enough funds are escrowed for the task to pay this amount.
+		InsufficientEscrow,
+		/// Only the volunteers of a vesting payout can claim it.
+		NoPermissionToClaim,
+		/// Nothing has vested since the last claim.
+		NothingVested,
+		/// The payout is split between more volunteers than there are volunteer slots.
+		TooManyVolunteers,
+		/// The volunteer can't reserve the bond the task requires.
+		CannotReserveBond,
+		/// The deadline of the task has passed, although it has not been expired yet.
+		TaskDeadlinePassed,
 	}
 	
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -332,12 +1574,23 @@ pub mod pallet {
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Function call that creates tasks.  [origin, title, specification, budget, deadline, attachments, keywords, organization]
+		/// Function call that creates tasks.  [origin, title, specification, budget, deadline, attachments, keywords, organization, x, y]
+		/// The task is open to any volunteer, its budget is paid in the native currency and its deadline is a timestamp.
 		#[pallet::weight(<T as Config>::WeightInfo::create_task(0,0))]
 		pub fn create_task(origin: OriginFor<T>, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>, budget: BalanceOf<T>,
 			deadline: u64, attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, organization: Option<OrganizationIdOf<T>>,
 			x: Option<[u8; 5]>, y: Option<[u8; 5]>
 		) -> DispatchResultWithPostInfo {
+			Self::create_task_with_options(origin, title, specification, budget, TaskDeadline::Timestamp(deadline), attachments, keywords, organization, x, y, true, None)
+		}
+
+		/// Function call that creates tasks.  [origin, title, specification, budget, deadline, attachments, keywords, organization, x, y, open, asset]
+		#[transactional]
+		#[pallet::weight(if asset.is_some() { <T as Config>::WeightInfo::create_asset_task() } else { <T as Config>::WeightInfo::create_task(0,0) })]
+		pub fn create_task_with_options(origin: OriginFor<T>, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>, budget: BalanceOf<T>,
+			deadline: TaskDeadline<T::BlockNumber>, attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, organization: Option<OrganizationIdOf<T>>,
+			x: Option<[u8; 5]>, y: Option<[u8; 5]>, open: bool, asset: Option<T::AssetId>
+		) -> DispatchResultWithPostInfo {
 
 			// Check that the extrinsic was signed and get the signer.
 			let signer = ensure_signed(origin)?;
@@ -348,7 +1601,7 @@ pub mod pallet {
 			}
 
 			// Ensure has enough balance;
-			ensure!(<T as self::Config>::Currency::can_reserve(&signer, budget), Error::<T>::NotEnoughBalance);
+			ensure!(Self::free_balance(&signer, asset) >= budget, Error::<T>::NotEnoughBalance);
 
 			let mut location: Option<NadLocation> = None;
 			if x.is_some() && y.is_some() {
@@ -356,10 +1609,10 @@ pub mod pallet {
 			}
 
 			// Update storage.
-			let task_id = Self::new_task(&signer, title, specification, &budget, deadline, attachments, keywords, organization, location)?;
+			let task_id = Self::new_task(&signer, title, specification, &budget, deadline, attachments, keywords, organization, location, open, None, asset, None, &signer)?;
 
-			// Reserve currency of the task creator.
-			<T as self::Config>::Currency::reserve(&signer, budget).expect("can_reserve has been called; qed");
+			// Move the budget of the task creator into the task escrow account.
+			Self::fund_escrow(&signer, &task_id, asset, budget)?;
 
 			// Emit a Task Created Event.
 			Self::deposit_event(Event::TaskCreated(signer, task_id));
@@ -367,13 +1620,61 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		/// Function call that updates a created task.  [origin, task, title, specification, budget, deadline, attachments, keywords, organization]
-		//	todo: minimum change amount?
+		/// Function call that creates tasks funded from the account of an organization. [origin, title, specification, budget, deadline, attachments, keywords, organization, x, y, open, asset]
+		#[transactional]
+		#[pallet::weight(<T as Config>::WeightInfo::create_organization_task())]
+		pub fn create_organization_task(origin: OriginFor<T>, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>, budget: BalanceOf<T>,
+			deadline: TaskDeadline<T::BlockNumber>, attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, organization: OrganizationIdOf<T>,
+			x: Option<[u8; 5]>, y: Option<[u8; 5]>, open: bool, asset: Option<T::AssetId>
+		) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Verify the organization and that the signer can spend from its account
+			ensure!(T::Organization::exists(&organization), Error::<T>::InvalidOrganization);
+			ensure!(T::Organization::can_spend(&organization, &signer), Error::<T>::NoPermissionToFund);
+
+			// Ensure the organization has enough balance;
+			let funder = T::Organization::account(&organization);
+			ensure!(Self::free_balance(&funder, asset) >= budget, Error::<T>::NotEnoughBalance);
+
+			let mut location: Option<NadLocation> = None;
+			if x.is_some() && y.is_some() {
+				location = Some((x.unwrap(), y.unwrap()))
+			}
+
+			// Update storage.
+			let task_id = Self::new_task(&signer, title, specification, &budget, deadline, attachments, keywords, Some(organization), location, open, None, asset, None, &funder)?;
+
+			// Move the budget from the organization account into the task escrow account.
+			Self::fund_escrow(&funder, &task_id, asset, budget)?;
+
+			// Emit a Task Created Event.
+			Self::deposit_event(Event::TaskCreated(signer, task_id));
+
+			Ok(())
+		}
+
+		/// Function call that updates a created task.  [origin, task, title, specification, budget, deadline, attachments, keywords, organization, x, y]
+		/// The deadline is a timestamp, and whether the task is open to any volunteer is left unchanged.
 		#[pallet::weight(<T as Config>::WeightInfo::update_task(0,0))]
 		pub fn update_task(origin: OriginFor<T>, task_id: T::Hash, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>,
 			budget: BalanceOf<T>, deadline: u64, attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, organization: Option<OrganizationIdOf<T>>,
 			x: Option<[u8; 5]>, y: Option<[u8; 5]>
 		) -> DispatchResultWithPostInfo {
+			let open = Self::tasks(&task_id).map_or(true, |task| task.open);
+			Self::update_task_with_options(origin, task_id, title, specification, budget, TaskDeadline::Timestamp(deadline), attachments, keywords, organization, x, y, open)
+		}
+
+		/// Function call that updates a created task.  [origin, task, title, specification, budget, deadline, attachments, keywords, organization, x, y, open]
+		//	todo: minimum change amount?
+		#[transactional]
+		#[pallet::weight(<T as Config>::WeightInfo::update_task(0,0))]
+		pub fn update_task_with_options(origin: OriginFor<T>, task_id: T::Hash, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>,
+			budget: BalanceOf<T>, deadline: TaskDeadline<T::BlockNumber>, attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, organization: Option<OrganizationIdOf<T>>,
+			x: Option<[u8; 5]>, y: Option<[u8; 5]>, open: bool
+		) -> DispatchResultWithPostInfo {
 
 			// Check that the extrinsic was signed and get the signer.
 			let signer = ensure_signed(origin)?;
@@ -390,29 +1691,19 @@ pub mod pallet {
 			ensure!(Self::is_task_initiator(&task_id, &signer)?, <Error<T>>::OnlyInitiatorUpdatesTask);
 
 			// Ensure user has a profile before creating a task
-			ensure!(pallet_profile::Pallet::<T>::has_profile(&signer).unwrap(), <Error<T>>::NoProfile);
+			ensure!(T::Profiles::exists(&signer), <Error<T>>::NoProfile);
 
 			// Check if task is in created status. Tasks can be updated only before work has been started.
 			ensure!(TaskStatus::Created == old_task.status, <Error<T>>::NoPermissionToUpdate);
 
+			// Tasks funded by an organization stay with that organization.
+			ensure!(old_task.funder == old_task.initiator || organization == old_task.organization, <Error<T>>::NoPermissionToUpdate);
 
-			let deadline_duration = Duration::from_millis(old_task.deadline.saturated_into::<u64>());
-			ensure!(T::Time::now() < deadline_duration, Error::<T>::IncorrectDeadlineTimestamp);
 
-			if old_task.budget != budget {
-				// Check that sender can reserve.
-				// Reserve difference if the budget has increased.
-				if budget > old_task.budget {
-					let diff = budget - old_task.budget;
-					ensure!(<T as self::Config>::Currency::can_reserve(&signer, diff), Error::<T>::NotEnoughBalance);
-					<T as self::Config>::Currency::reserve(&signer, diff).expect("can_reserve has been called; qed");
+			ensure!(!Self::is_past_deadline(&old_task), Error::<T>::IncorrectDeadlineTimestamp);
 
-				// Unreserve difference if the budget has decreased.
-				} else {
-					let diff = old_task.budget - budget;
-					<T as self::Config>::Currency::unreserve(&signer, diff);
-				}
-			}
+			// Move the budget difference into or out of escrow.
+			Self::change_budget(&signer, &task_id, budget)?;
 
 			let mut location: Option<NadLocation> = None;
 			if x.is_some() && y.is_some() {
@@ -420,7 +1711,7 @@ pub mod pallet {
 			}
 
 			// Update storage after as we need to check if sender can reserve new amount.
-			Self::update_created_task(old_task, &task_id, title, specification, &budget, deadline, attachments, keywords, organization, location)?;
+			Self::update_created_task(old_task, &task_id, title, specification, &budget, deadline, attachments, keywords, organization, location, open)?;
 
 			// Emit a Task Updated Event.
 			Self::deposit_event(Event::TaskUpdated(signer, task_id));
@@ -429,19 +1720,22 @@ pub mod pallet {
 		}
 
 		/// Function that removes a task by task owner. [origin, task_id]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_task(0,0))]
-		pub fn remove_task(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+		/// Every subtask visited is charged as the removal of a task.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_task(0,0).saturating_mul(T::MaxTasksWalked::get().saturating_add(1).into()))]
+		pub fn remove_task(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResultWithPostInfo {
 
 			// Check that the extrinsic was signed and get the signer.
 			let signer = ensure_signed(origin)?;
 
-			// Delete task from storage.
-			Self::delete_task(&signer, &task_id)?;
+			// Delete task and its subtasks from storage.
+			let mut walk_left = T::MaxTasksWalked::get();
+			Self::delete_task(&signer, &task_id, Some(&mut walk_left))?;
 
 			// Emit a Task Removed Event.
 			Self::deposit_event(Event::TaskRemoved(signer, task_id));
 
-			Ok(())
+			let walked = T::MaxTasksWalked::get().saturating_sub(walk_left);
+			Ok(Some(<T as Config>::WeightInfo::remove_task(0,0).saturating_mul(walked.saturating_add(1).into())).into())
 		}
 
 
@@ -452,6 +1746,10 @@ pub mod pallet {
 			// Check that the extrinsic was signed and get the signer.
 			let signer = ensure_signed(origin)?;
 
+			// Tasks that are not open are started by selecting an application, or by an invitee.
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			ensure!(task.open || Self::invitations(&task_id).contains(&signer), <Error<T>>::TaskRequiresApplication);
+
 			// Assign task and update storage.
 			Self::assign_task(&signer, &task_id)?;
 
@@ -461,9 +1759,9 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Function that completes a task [origin, task_id]
+		/// Function that completes a task, with references to the delivered work [origin, task_id, deliverables]
 		#[pallet::weight(<T as Config>::WeightInfo::complete_task(0,0))]
-		pub fn complete_task(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+		pub fn complete_task(origin: OriginFor<T>, task_id: T::Hash, deliverables: BoundedVec<Deliverable<T>, T::MaxDeliverablesPerSubmission>) -> DispatchResult {
 
 			// Check that the extrinsic was signed and get the signer.
 			let signer = ensure_signed(origin)?;
@@ -471,6 +1769,9 @@ pub mod pallet {
 			// Complete task and update storage.
 			Self::mark_finished(&signer, &task_id)?;
 
+			// Keep the deliverables with the earlier submissions of the task.
+			Self::record_submission(&signer, &task_id, deliverables);
+
 			// Emit a Task Completed Event.
 			Self::deposit_event(Event::TaskCompleted(signer, task_id));
 
@@ -479,27 +1780,23 @@ pub mod pallet {
 
 		/// Function to accept a completed task. [origin, task_id]
 		#[transactional]
-		#[pallet::weight(<T as Config>::WeightInfo::accept_task(0,0))]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_task(0,0).max(<T as Config>::WeightInfo::accept_asset_task()))]
 		pub fn accept_task(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
 
 			// Check that the extrinsic was signed and get the signer.
 			let signer = ensure_signed(origin)?;
 
 			// Check if task exists
-			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
 
 			// Ensure owner
 			ensure!(task.current_owner == signer, Error::<T>::OnlyInitiatorAcceptsTask);
 
-			// Transfer reserved funds of task amount to volunteer.
-			<T as self::Config>::Currency::unreserve(&signer, task.budget);
-			<T as self::Config>::Currency::transfer(&signer, &task.volunteer, task.budget, ExistenceRequirement::AllowDeath)?;
-
-			// Accept task and update storage.
-			Self::accept_completed_task(&signer, &mut task, &task_id)?;
+			// Disputed tasks are settled by the arbitrators
+			ensure!(task.status != TaskStatus::Disputed, Error::<T>::TaskDisputed);
 
-			// Add task to completed tasks list of volunteer's profile.
-			pallet_profile::Pallet::<T>::add_task_to_completed_tasks(&task.volunteer, task_id)?;
+			// Pay out the task and update storage.
+			Self::settle_accepted_task(&signer, &task_id, task)?;
 
 			// Emit a Task Removed Event.
 			Self::deposit_event(Event::TaskAccepted(signer, task_id));
@@ -526,7 +1823,7 @@ pub mod pallet {
 		/// Function to revive an expired task. [origin, task_id, new_deadline]
 		/// Something the user does to allow editing of the task as well as keep the task in storage.
 		#[pallet::weight(<T as Config>::WeightInfo::revive_task(0,0))]
-		pub fn revive_expired_task(origin: OriginFor<T>, task_id: T::Hash, new_deadline: u64) -> DispatchResult {
+		pub fn revive_expired_task(origin: OriginFor<T>, task_id: T::Hash, new_deadline: TaskDeadline<T::BlockNumber>) -> DispatchResult {
 
 			// Check that the extrinsic was signed and get the signer;
 			let signer = ensure_signed(origin)?;
@@ -540,29 +1837,24 @@ pub mod pallet {
 			// Ensure task status is expired;
 			ensure!(task.status == TaskStatus::Expired, Error::<T>::NoPermissionToRevive);
 
-			let deadline_duration = Duration::from_millis(new_deadline);
-			ensure!(T::Time::now() < deadline_duration, Error::<T>::IncorrectDeadlineTimestamp);
+			// Index the location again, expired tasks are not indexed;
+			Self::index_location(&task_id, &task.location)?;
 
-			// Get the deadlines for editing;
-			let old_deadline_block = task.deadline_block;
-			let new_deadline_block = Self::get_deadline_block(new_deadline);
+			// Get the new deadlines, and the block the task expired on;
+			let expired_at = task.updated_at;
+			let (new_deadline_timestamp, new_deadline_block) = Self::resolve_deadline(&new_deadline)?;
 			
 			// Update the task struct;
-			task.status = TaskStatus::Created;
-			task.deadline = new_deadline;
+			Self::set_status(&mut task, TaskStatus::Created);
+			task.deadline = new_deadline_timestamp;
 			task.deadline_block = Some(new_deadline_block);
+			task.deadline_by_block = matches!(new_deadline, TaskDeadline::Block(_));
 			Tasks::<T>::insert(task_id, &task);
 
-			if let Some(deadline_block) = old_deadline_block {
-				// Remove from the dying tasks storage;
-				let dying_tasks: BoundedVec<T::Hash, MaximumTasksPerBlock> = DyingTasksPerBlock::<T>::take(deadline_block + T::TaskLongevityAfterExpiration::get())
-					.into_iter()
-					.filter(|&h| h != task_id)
-					.collect::<Vec<T::Hash>>()
-					.try_into()
-					.expect("reducing, will not be out of bounds; qed");
-				DyingTasksPerBlock::<T>::insert(deadline_block, dying_tasks);
-			}
+			// Remove from the dying tasks storage, tasks die a while after the block they expired on;
+			DyingTasksPerBlock::<T>::mutate(expired_at + T::TaskLongevityAfterExpiration::get(), |dying_tasks| {
+				dying_tasks.retain(|&h| h != task_id);
+			});
 			
 			// Handle the new deadline without the old deadline;
 			// Using none here because handle_new_task_deadline only deals with expiring tasks
@@ -574,321 +1866,2657 @@ pub mod pallet {
 
 			Ok(())
 		}
-	}
 
-	#[pallet::hooks]
-	impl<T:Config> Hooks<T::BlockNumber> for Pallet<T> {
-		fn on_initialize(n: T::BlockNumber) -> frame_support::weights::Weight {
-			// Remove tasks which have not been started, and have passed the deadline
-			let mut weight = 10_000;
+		/// Function to split a created task into milestones. [origin, task_id, title, budget, deadline]
+		#[pallet::weight(<T as Config>::WeightInfo::add_milestone())]
+		pub fn add_milestone(origin: OriginFor<T>, task_id: T::Hash, title: BoundedVec<u8, T::MaxTitleLen>, budget: BalanceOf<T>, deadline: u64) -> DispatchResult {
 
-			// Collect all dying and expiring tasks.
-			let old_expiring_tasks = ExpiringTasksPerBlock::<T>::take(n);
-			let old_dying_tasks = DyingTasksPerBlock::<T>::take(n);
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
 
-			// Set the new dying tasks as the old expiring tasks.
-			DyingTasksPerBlock::<T>::insert(n + T::TaskLongevityAfterExpiration::get(), &old_expiring_tasks);
+			// Add milestone and update storage.
+			let milestone = Self::new_milestone(&signer, &task_id, title, budget, deadline)?;
 
-			// Update the dying/expired tasks.
-			let _ = old_expiring_tasks.iter().map(|th| {
-				let maybe_task = Tasks::<T>::get(th);
-				if let Some(mut task)= maybe_task {
-					// Update the tasks status to expired;
-					task.status = TaskStatus::Expired;
-					task.updated_at = n;
+			// Emit a Milestone Added Event.
+			Self::deposit_event(Event::MilestoneAdded(signer, task_id, milestone));
 
-					Tasks::insert(th, task);
-					weight += 10_000;
-				}
-			}).collect::<Vec<_>>();
+			Ok(())
+		}
 
+		/// Function to submit a milestone for review. [origin, task_id, milestone]
+		#[pallet::weight(<T as Config>::WeightInfo::submit_milestone())]
+		pub fn submit_milestone(origin: OriginFor<T>, task_id: T::Hash, milestone: MilestoneIndex) -> DispatchResult {
 
-			// Remove all dead tasks from storage.
-			let _ = old_dying_tasks.iter().map(|th| {
-				let maybe_task = Tasks::<T>::get(th);
-				if let Some(task)= maybe_task {
-					//also remove from expiring if applicable
-					if let Ok(()) = Self::delete_task(&task.initiator, &th) {
-						weight += 10_000;
-					} 
-				}
-			}).collect::<Vec<_>>();
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
 
-			weight
+			// Submit milestone and update storage.
+			Self::mark_milestone_submitted(&signer, &task_id, milestone)?;
+
+			// Emit a Milestone Submitted Event.
+			Self::deposit_event(Event::MilestoneSubmitted(signer, task_id, milestone));
+
+			Ok(())
 		}
-	}
 
-	// *** Helper functions *** //
-	impl<T:Config> Pallet<T> {
+		/// Function to accept a submitted milestone and pay out its budget. [origin, task_id, milestone]
+		#[transactional]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_milestone())]
+		pub fn accept_milestone(origin: OriginFor<T>, task_id: T::Hash, milestone: MilestoneIndex) -> DispatchResult {
 
-		fn new_task(from_initiator: &T::AccountId, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>, budget: &BalanceOf<T>,
-			deadline: u64, attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, organization: Option<OrganizationIdOf<T>>,
-			location: Option<NadLocation>,
-			) -> Result<T::Hash, DispatchError> {
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
 
-			let time_of_creation = T::Time::now();
-			// Ensure user has a profile before creating a task
-			ensure!(pallet_profile::Pallet::<T>::has_profile(from_initiator).unwrap(), <Error<T>>::NoProfile);
-			let deadline_duration = Duration::from_millis(deadline.saturated_into::<u64>());
-			ensure!(time_of_creation < deadline_duration, Error::<T>::IncorrectDeadlineTimestamp);
-			
-			// Get the amount of block equal to the deadline duration.
-			let blocks_till_deadline: T::BlockNumber = ((((deadline_duration - time_of_creation).as_millis() / T::MillisecondsPerBlock::get() as u128)) as u32).into();
-			let deadline_block = blocks_till_deadline + <frame_system::Pallet<T>>::block_number();
+			// Accept milestone and update storage.
+			let (volunteer, amount) = Self::accept_submitted_milestone(&signer, &task_id, milestone)?;
+
+			// Transfer the milestone share of the escrowed funds to volunteer, minus the fees.
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			let payout = Self::take_payout_fees(&task_id, &task, amount)?;
+			Self::release_escrow(&task_id, &volunteer, task.asset, payout)?;
+
+			// Emit a Milestone Accepted Event.
+			Self::deposit_event(Event::MilestoneAccepted(signer, task_id, milestone, amount));
+
+			Ok(())
+		}
+
+		/// Function to reject a submitted milestone. [origin, task_id, milestone, feedback]
+		#[pallet::weight(<T as Config>::WeightInfo::reject_milestone())]
+		pub fn reject_milestone(origin: OriginFor<T>, task_id: T::Hash, milestone: MilestoneIndex, feedback: BoundedVec<u8, T::MaxFeedbackLen>) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Reject milestone and update storage.
+			Self::reject_submitted_milestone(&signer, &task_id, milestone, feedback)?;
+
+			// Emit a Milestone Rejected Event.
+			Self::deposit_event(Event::MilestoneRejected(signer, task_id, milestone));
+
+			Ok(())
+		}
+
+		/// Function to apply for a created task. [origin, task_id, cover_note, price]
+		#[pallet::weight(<T as Config>::WeightInfo::apply_for_task())]
+		pub fn apply_for_task(origin: OriginFor<T>, task_id: T::Hash, cover_note: BoundedVec<u8, T::MaxCoverNoteLen>, price: Option<BalanceOf<T>>) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Add application and update storage.
+			Self::new_application(&signer, &task_id, cover_note, price)?;
+
+			// Emit an Application Submitted Event.
+			Self::deposit_event(Event::ApplicationSubmitted(signer, task_id));
+
+			Ok(())
+		}
+
+		/// Function to withdraw an application that has not been selected or declined. [origin, task_id]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_application())]
+		pub fn withdraw_application(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Remove application and update storage.
+			Self::remove_application(&signer, &task_id)?;
+
+			// Emit an Application Withdrawn Event.
+			Self::deposit_event(Event::ApplicationWithdrawn(signer, task_id));
+
+			Ok(())
+		}
+
+		/// Function to shortlist an application. [origin, task_id, applicant]
+		#[pallet::weight(<T as Config>::WeightInfo::shortlist_application())]
+		pub fn shortlist_application(origin: OriginFor<T>, task_id: T::Hash, applicant: T::AccountId) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Shortlist application and update storage.
+			Self::mark_application_shortlisted(&signer, &task_id, &applicant)?;
+
+			// Emit an Application Shortlisted Event.
+			Self::deposit_event(Event::ApplicationShortlisted(signer, task_id, applicant));
+
+			Ok(())
+		}
+
+		/// Function to select an application and start the task with the applicant as volunteer. [origin, task_id, applicant]
+		#[transactional]
+		#[pallet::weight(<T as Config>::WeightInfo::select_application())]
+		pub fn select_application(origin: OriginFor<T>, task_id: T::Hash, applicant: T::AccountId) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Check if the owner is the one who created task
+			ensure!(Self::is_task_initiator(&task_id, &signer)?, <Error<T>>::OnlyInitiatorUpdatesTask);
+
+			let application = Self::applications(&task_id)
+				.into_iter()
+				.find(|a| a.applicant == applicant)
+				.ok_or(<Error<T>>::ApplicationNotExist)?;
+			ensure!(application.status == ApplicationStatus::Pending || application.status == ApplicationStatus::Shortlisted,
+				<Error<T>>::ApplicationClosed);
+
+			// Settle the counter-offer of the applicant (if provided)
+			if let Some(price) = application.price {
+				Self::change_budget(&signer, &task_id, price)?;
+			}
+
+			// Assign task to the applicant and update storage. Other applications are declined.
+			Self::assign_task(&applicant, &task_id)?;
+
+			// Emit an Application Selected and a Task Assigned Event.
+			Self::deposit_event(Event::ApplicationSelected(signer, task_id, applicant.clone()));
+			Self::deposit_event(Event::TaskAssigned(applicant, task_id));
+
+			Ok(())
+		}
+
+		/// Function to split a created task into volunteer slots, each with a share of the budget. [origin, task_id, shares]
+		#[pallet::weight(<T as Config>::WeightInfo::set_volunteer_slots(shares.len() as u32))]
+		pub fn set_volunteer_slots(origin: OriginFor<T>, task_id: T::Hash, shares: BoundedVec<BalanceOf<T>, T::MaxVolunteerSlots>) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			let slots = shares.len() as u32;
+
+			// Set volunteer slots and update storage.
+			Self::change_volunteer_slots(&signer, &task_id, shares)?;
+
+			// Emit a Volunteer Slots Set Event.
+			Self::deposit_event(Event::VolunteerSlotsSet(signer, task_id, slots));
+
+			Ok(())
+		}
+
+		/// Function to dispute a rejected task. [origin, task_id, reason]
+		#[transactional]
+		#[pallet::weight(<T as Config>::WeightInfo::open_dispute())]
+		pub fn open_dispute(origin: OriginFor<T>, task_id: T::Hash, reason: BoundedVec<u8, T::MaxFeedbackLen>) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Reserve the deposit, draw arbitrators and update storage.
+			Self::new_dispute(&signer, &task_id, reason)?;
+
+			// Emit a Dispute Opened Event.
+			Self::deposit_event(Event::DisputeOpened(signer, task_id));
+
+			Ok(())
+		}
+
+		/// Function to vote on a dispute as one of its arbitrators. [origin, task_id, volunteer_share]
+		#[pallet::weight(<T as Config>::WeightInfo::vote_on_dispute())]
+		pub fn vote_on_dispute(origin: OriginFor<T>, task_id: T::Hash, volunteer_share: Percent) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Add vote and update storage.
+			let voting_finished = Self::add_dispute_vote(&signer, &task_id, volunteer_share)?;
+
+			// Emit a Dispute Voted Event.
+			Self::deposit_event(Event::DisputeVoted(signer, task_id, volunteer_share));
+
+			// Resolve the dispute once every arbitrator has voted
+			if let Some(voting_ends_at) = voting_finished {
+				Self::remove_dispute_from_ending(&task_id, voting_ends_at);
+				Self::resolve_dispute(&task_id)?;
+			}
+
+			Ok(())
+		}
+
+		/// Function to set the arbitrators of tasks without an organization. [origin, arbitrators]
+		#[pallet::weight(<T as Config>::WeightInfo::set_arbitrator_pool(arbitrators.len() as u32))]
+		pub fn set_arbitrator_pool(origin: OriginFor<T>, arbitrators: BoundedVec<T::AccountId, T::MaxArbitratorPool>) -> DispatchResult {
+
+			// Only root can configure the arbitrator pool.
+			ensure_root(origin)?;
+
+			let count = arbitrators.len() as u32;
+			<ArbitratorPool<T>>::put(arbitrators);
+
+			// Emit an Arbitrator Pool Set Event.
+			Self::deposit_event(Event::ArbitratorPoolSet(count));
+
+			Ok(())
+		}
+
+		/// Function to split part of the budget of a created task into a subtask. [origin, parent_id, title, specification, budget, deadline, attachments, keywords, open]
+		#[transactional]
+		#[pallet::weight(<T as Config>::WeightInfo::create_subtask())]
+		pub fn create_subtask(origin: OriginFor<T>, parent_id: T::Hash, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>,
+			budget: BalanceOf<T>, deadline: TaskDeadline<T::BlockNumber>, attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, open: bool
+		) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Create the subtask and fund it from the parent escrow.
+			let task_id = Self::new_subtask(&signer, &parent_id, title, specification, budget, deadline, attachments, keywords, open)?;
+
+			// Emit a Subtask Created Event.
+			Self::deposit_event(Event::SubtaskCreated(signer, parent_id, task_id));
+
+			Ok(())
+		}
+
+		/// Function to take back a task whose delivery deadline has passed. [origin, task_id]
+		#[transactional]
+		#[pallet::weight(<T as Config>::WeightInfo::reclaim_task())]
+		pub fn reclaim_task(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Reclaim task and update storage.
+			Self::reclaim_stale_task(&signer, &task_id)?;
+
+			// Emit a Task Reclaimed Event.
+			Self::deposit_event(Event::TaskReclaimed(signer, task_id));
+
+			Ok(())
+		}
+
+		/// Function for a volunteer to leave an in-progress task. [origin, task_id]
+		#[transactional]
+		#[pallet::weight(<T as Config>::WeightInfo::abandon_task())]
+		pub fn abandon_task(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Abandon task and update storage.
+			Self::leave_task(&signer, &task_id)?;
+
+			// Emit a Task Abandoned Event.
+			Self::deposit_event(Event::TaskAbandoned(signer, task_id));
+
+			Ok(())
+		}
+
+		/// Function to block a created task until another task is accepted. [origin, task_id, depends_on]
+		#[pallet::weight(<T as Config>::WeightInfo::add_dependency().saturating_add(T::DbWeight::get().reads(T::MaxTasksWalked::get().into())))]
+		pub fn add_dependency(origin: OriginFor<T>, task_id: T::Hash, depends_on: T::Hash) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Add dependency and update storage.
+			Self::new_dependency(&signer, &task_id, &depends_on)?;
+
+			// Emit a Dependency Added Event.
+			Self::deposit_event(Event::DependencyAdded(signer, task_id, depends_on));
+
+			Ok(())
+		}
+
+		/// Function to remove a dependency of a task. [origin, task_id, depends_on]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_dependency())]
+		pub fn remove_dependency(origin: OriginFor<T>, task_id: T::Hash, depends_on: T::Hash) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Remove dependency and update storage.
+			Self::delete_dependency(&signer, &task_id, &depends_on)?;
+
+			// Emit a Dependency Removed Event.
+			Self::deposit_event(Event::DependencyRemoved(signer, task_id, depends_on));
+
+			Ok(())
+		}
+
+		/// Function call that defines a task spawned again at every interval. [origin, title, specification, budget, attachments, keywords, organization, x, y, open, asset, interval, max_occurrences]
+		#[transactional]
+		#[pallet::weight(<T as Config>::WeightInfo::create_recurring_task())]
+		pub fn create_recurring_task(origin: OriginFor<T>, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>, budget: BalanceOf<T>,
+			attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, organization: Option<OrganizationIdOf<T>>,
+			x: Option<[u8; 5]>, y: Option<[u8; 5]>, open: bool, asset: Option<T::AssetId>, interval: T::BlockNumber, max_occurrences: u32
+		) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Verify the organization (if provided)
+			if let Some(organization) = organization {
+				ensure!(T::Organization::exists(&organization), Error::<T>::InvalidOrganization);
+			}
+
+			// Update storage.
+			let recurring_id = Self::new_recurring_task(&signer, title, specification, budget, asset, attachments, keywords, organization, x.zip(y), open, interval, max_occurrences)?;
+
+			// Emit a Recurring Task Created Event.
+			Self::deposit_event(Event::RecurringTaskCreated(signer, recurring_id));
+
+			// Spawn the first occurrence right away.
+			let task_id = Self::spawn_occurrence(&recurring_id)?;
+			Self::deposit_event(Event::RecurringTaskSpawned(recurring_id, task_id));
+
+			Ok(())
+		}
+
+		/// Function to stop spawning a recurring task. [origin, recurring_id]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_recurring_task())]
+		pub fn cancel_recurring_task(origin: OriginFor<T>, recurring_id: T::Hash) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Remove recurring task from storage.
+			Self::delete_recurring_task(&signer, &recurring_id)?;
+
+			// Emit a Recurring Task Cancelled Event.
+			Self::deposit_event(Event::RecurringTaskCancelled(signer, recurring_id));
+
+			Ok(())
+		}
+
+		/// Function call that creates task templates. [origin, title, specification, attachments, keywords, organization, open]
+		#[pallet::weight(<T as Config>::WeightInfo::create_template())]
+		pub fn create_template(origin: OriginFor<T>, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>,
+			attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, organization: Option<OrganizationIdOf<T>>, open: bool
+		) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Update storage.
+			let template_id = Self::new_template(&signer, title, specification, attachments, keywords, organization, open)?;
+
+			// Emit a Template Created Event.
+			Self::deposit_event(Event::TemplateCreated(signer, template_id));
+
+			Ok(())
+		}
+
+		/// Function call that updates a template to a new version. [origin, template_id, title, specification, attachments, keywords, open]
+		#[pallet::weight(<T as Config>::WeightInfo::update_template())]
+		pub fn update_template(origin: OriginFor<T>, template_id: T::Hash, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>,
+			attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, open: bool
+		) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Update template and storage.
+			let version = Self::change_template(&signer, &template_id, title, specification, attachments, keywords, open)?;
+
+			// Emit a Template Updated Event.
+			Self::deposit_event(Event::TemplateUpdated(signer, template_id, version));
+
+			Ok(())
+		}
+
+		/// Function to remove a template. [origin, template_id]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_template())]
+		pub fn remove_template(origin: OriginFor<T>, template_id: T::Hash) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Remove template from storage.
+			Self::delete_template(&signer, &template_id)?;
+
+			// Emit a Template Removed Event.
+			Self::deposit_event(Event::TemplateRemoved(signer, template_id));
+
+			Ok(())
+		}
+
+		/// Function call that creates a task from a template. [origin, template_id, budget, deadline, x, y]
+		#[transactional]
+		#[pallet::weight(<T as Config>::WeightInfo::create_task_from_template())]
+		pub fn create_task_from_template(origin: OriginFor<T>, template_id: T::Hash, budget: BalanceOf<T>, deadline: TaskDeadline<T::BlockNumber>,
+			x: Option<[u8; 5]>, y: Option<[u8; 5]>
+		) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			let mut location: Option<NadLocation> = None;
+			if x.is_some() && y.is_some() {
+				location = Some((x.unwrap(), y.unwrap()))
+			}
+
+			// Create the task and move the budget of the task creator into the task escrow account.
+			let task_id = Self::new_task_from_template(&signer, &template_id, budget, deadline, location)?;
+
+			// Emit a Task Created Event.
+			Self::deposit_event(Event::TaskCreated(signer, task_id));
+
+			Ok(())
+		}
+
+		/// Function to rate the other party of an accepted task. [origin, task_id, reviewee, rating, review]
+		#[pallet::weight(<T as Config>::WeightInfo::rate_task())]
+		pub fn rate_task(origin: OriginFor<T>, task_id: T::Hash, reviewee: T::AccountId, rating: u8, review: BoundedVec<u8, T::MaxReviewLen>) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Store the rating and add it to the profile of the reviewee.
+			Self::new_rating(&signer, &task_id, &reviewee, rating, review)?;
+
+			// Emit a Task Rated Event.
+			Self::deposit_event(Event::TaskRated(signer, reviewee, task_id, rating));
+
+			Ok(())
+		}
+
+		/// Function to set who can volunteer for a created task. [origin, task_id, visibility]
+		#[pallet::weight(<T as Config>::WeightInfo::set_task_visibility())]
+		pub fn set_task_visibility(origin: OriginFor<T>, task_id: T::Hash, visibility: TaskVisibility) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Ensure the signer can restrict the task, and the task can be restricted this way.
+			let task = Self::created_task_of(&signer, &task_id)?;
+			if visibility == TaskVisibility::OrganizationOnly {
+				ensure!(task.organization.is_some(), <Error<T>>::TaskHasNoOrganization);
+			}
+			<Visibility<T>>::insert(task_id, visibility.clone());
+
+			// Emit a Task Visibility Set Event.
+			Self::deposit_event(Event::TaskVisibilitySet(signer, task_id, visibility));
+
+			Ok(())
+		}
+
+		/// Function to invite an account to volunteer for a created task. [origin, task_id, invitee]
+		#[pallet::weight(<T as Config>::WeightInfo::invite_volunteer())]
+		pub fn invite_volunteer(origin: OriginFor<T>, task_id: T::Hash, invitee: T::AccountId) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Ensure the signer can invite, and does not invite themselves.
+			Self::created_task_of(&signer, &task_id)?;
+			ensure!(invitee != signer, <Error<T>>::NoPermissionToInvite);
+
+			// Add the invitee to the invitations of the task.
+			<Invitations<T>>::try_mutate(&task_id, |invitations| -> Result<(), Error<T>> {
+				ensure!(!invitations.contains(&invitee), <Error<T>>::AlreadyInvited);
+				invitations.try_push(invitee.clone()).map_err(|_| <Error<T>>::TooManyInvitations)
+			})?;
+
+			// Emit a Volunteer Invited Event.
+			Self::deposit_event(Event::VolunteerInvited(signer, task_id, invitee));
+
+			Ok(())
+		}
+
+		/// Function to revoke the invitation of an account to volunteer for a created task. [origin, task_id, invitee]
+		#[pallet::weight(<T as Config>::WeightInfo::revoke_invitation())]
+		pub fn revoke_invitation(origin: OriginFor<T>, task_id: T::Hash, invitee: T::AccountId) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Ensure the signer can revoke invitations, and remove the invitation.
+			Self::created_task_of(&signer, &task_id)?;
+			Self::remove_invitation(&task_id, &invitee)?;
+
+			// Emit an Invitation Revoked Event.
+			Self::deposit_event(Event::InvitationRevoked(signer, task_id, invitee));
+
+			Ok(())
+		}
+
+		/// Function to decline the invitation to volunteer for a created task. [origin, task_id]
+		#[pallet::weight(<T as Config>::WeightInfo::decline_invitation())]
+		pub fn decline_invitation(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Ensure the task has not been started yet, and remove the invitation.
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			ensure!(task.status == TaskStatus::Created, <Error<T>>::NoPermissionToUpdate);
+			Self::remove_invitation(&task_id, &signer)?;
+
+			// Emit an Invitation Declined Event.
+			Self::deposit_event(Event::InvitationDeclined(signer, task_id));
+
+			Ok(())
+		}
+
+		/// Function to vest the payout of a created task, or to pay it out at once. [origin, task_id, schedule]
+		#[pallet::weight(<T as Config>::WeightInfo::set_vesting_schedule())]
+		pub fn set_vesting_schedule(origin: OriginFor<T>, task_id: T::Hash, schedule: Option<VestingSchedule<T::BlockNumber>>) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Ensure the signer is the initiator, and the task has not been started yet.
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			ensure!(task.initiator == signer, <Error<T>>::OnlyInitiatorUpdatesTask);
+			ensure!(task.status == TaskStatus::Created, <Error<T>>::NoPermissionToUpdate);
+			<VestingSchedules<T>>::set(task_id, schedule);
+
+			// Emit a Vesting Schedule Set Event.
+			Self::deposit_event(Event::VestingScheduleSet(signer, task_id));
+
+			Ok(())
+		}
+
+		/// Function to claim what has vested of the payout of an accepted task. [origin, task_id]
+		#[transactional]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_vested())]
+		pub fn claim_vested(origin: OriginFor<T>, task_id: T::Hash) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Pay out what has vested since the last claim and update storage.
+			let amount = Self::release_vested(&signer, &task_id)?;
+
+			// Emit a Vested Claimed Event.
+			Self::deposit_event(Event::VestedClaimed(signer, task_id, amount));
+
+			Ok(())
+		}
+
+		/// Function to require a bond from the volunteers of a created task, or to drop it. [origin, task_id, bond]
+		#[pallet::weight(<T as Config>::WeightInfo::set_volunteer_bond())]
+		pub fn set_volunteer_bond(origin: OriginFor<T>, task_id: T::Hash, bond: Option<VolunteerBond<BalanceOf<T>>>) -> DispatchResult {
+
+			// Check that the extrinsic was signed and get the signer.
+			let signer = ensure_signed(origin)?;
+
+			// Ensure the signer is the initiator, and the task has not been started yet.
+			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			ensure!(task.initiator == signer, <Error<T>>::OnlyInitiatorUpdatesTask);
+			ensure!(task.status == TaskStatus::Created, <Error<T>>::NoPermissionToUpdate);
+			task.bond = bond;
+			<Tasks<T>>::insert(task_id, task);
+
+			// Emit a Volunteer Bond Set Event.
+			Self::deposit_event(Event::VolunteerBondSet(signer, task_id));
+
+			Ok(())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T:Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(n: T::BlockNumber) -> frame_support::weights::Weight {
+			// Expire the tasks which have passed the deadline, and remove dead tasks, within the expiry weight.
+			let weight = Self::process_expiry(n, T::MaxExpiryWeight::get());
+
+			// Resolve the disputes whose voting period has ended, accept the completed tasks whose review period
+			// has ended, spawn the recurring tasks that are due, and prune the receipts that have outlived the
+			// receipt longevity, within the scheduled weight.
+			weight.saturating_add(Self::process_scheduled(n, T::MaxScheduledWeight::get()))
+		}
+
+		fn on_idle(n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			// Carry on with the expiry and the scheduled work that did not fit at the start of the block.
+			let weight = Self::process_expiry(n, remaining_weight);
+			weight.saturating_add(Self::process_scheduled(n, remaining_weight.saturating_sub(weight)))
+		}
+	}
+
+	// *** Helper functions *** //
+	impl<T:Config> Pallet<T> {
+
+		fn new_task(from_initiator: &T::AccountId, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>, budget: &BalanceOf<T>,
+			deadline: TaskDeadline<T::BlockNumber>, attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, organization: Option<OrganizationIdOf<T>>,
+			location: Option<NadLocation>, open: bool, parent: Option<T::Hash>, asset: Option<T::AssetId>, template: Option<(T::Hash, u32)>, funder: &T::AccountId,
+			) -> Result<T::Hash, DispatchError> {
+
+			// Ensure user has a profile before creating a task
+			ensure!(T::Profiles::exists(from_initiator), <Error<T>>::NoProfile);
+
+			// The escrow account of the task has to be able to hold its budget.
+			ensure!(*budget >= Self::minimum_balance(asset), <Error<T>>::BudgetBelowMinimum);
+
+			// Get both the timestamp and the block of the deadline.
+			let (deadline_timestamp, deadline_block) = Self::resolve_deadline(&deadline)?;
 
 			// Init Task Object
 			let mut task = Task::<T> {
 				title,
 				specification,
-				initiator: from_initiator.clone(),
-				volunteer: from_initiator.clone(),
-				status: Created,
-				budget: *budget,
-				current_owner: from_initiator.clone(),
-				deadline,
+				initiator: from_initiator.clone(),
+				volunteer: from_initiator.clone(),
+				status: Created,
+				budget: *budget,
+				current_owner: from_initiator.clone(),
+				deadline: deadline_timestamp,
+				attachments,
+				keywords,
+				feedback: None, // Only used when task is rejected
+				organization,
+				created_at: <frame_system::Pallet<T>>::block_number(),
+				updated_at: Default::default(),
+				completed_at: Default::default(),
+				deadline_block: Some(deadline_block),
+				task_id: T::Hashing::hash_of(&T::Time::now().as_nanos()),
+				location,
+				open,
+				slots: Default::default(),
+				parent,
+				delivery_deadline: None,
+				asset,
+				template,
+				deadline_by_block: matches!(deadline, TaskDeadline::Block(_)),
+				funder: funder.clone(),
+				bond: None,
+			};
+
+			// Create hash of task and set that as the task_id;
+			let task_id = T::Hashing::hash_of(&task);
+			task.task_id = task_id;
+
+			// Performs this operation first because as it may fail
+			<TasksOwned<T>>::try_mutate(&from_initiator, |tasks_vec| {
+				tasks_vec.try_push(task_id)
+			}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+
+			// Index the task by the grid cell of its location, its status and its organization
+			Self::index_location(&task_id, &task.location)?;
+			Self::index_task(&task);
+
+			// Insert task into Hashmap
+			<Tasks<T>>::insert(task_id, task);
+
+			// Increase task count
+			let new_count = Self::task_count().checked_add(1).ok_or(<Error<T>>::TaskCountOverflow)?;
+			<TaskCount<T>>::put(new_count);
+
+			// Handle the new deadline
+			let _ = Self::handle_new_task_deadline(&task_id, &None, &deadline_block)?;
+
+			T::LifecycleHooks::on_created(&task_id, from_initiator)?;
+
+			Ok(task_id)
+		}
+
+		// Task can be updated only after it has been created. Task that is already in progress can't be updated.
+		//  Private helper function.
+		fn update_created_task(old_task:Task<T>, task_id: &T::Hash, new_title: BoundedVec<u8, T::MaxTitleLen>, new_specification: BoundedVec<u8, T::MaxSpecificationLen>, new_budget: &BalanceOf<T>,
+			new_deadline: TaskDeadline<T::BlockNumber>, attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, organization: Option<OrganizationIdOf<T>>,
+			location: Option<NadLocation>, open: bool
+		) -> Result<(), DispatchError> {
+
+			let (deadline_timestamp, deadline_block) = Self::resolve_deadline(&new_deadline)?;
+
+			let new_task: Task<T> = Task::<T> {
+				title: new_title.clone(),
+				specification: new_specification.clone(),
+				budget: *new_budget,
+				attachments: attachments.clone(),
+				keywords: keywords.clone(),
+				organization: organization,
+				initiator: old_task.initiator.clone(),
+				volunteer: old_task.volunteer.clone(),
+				status: Created,
+				current_owner: old_task.current_owner.clone(),
+				deadline: deadline_timestamp,
+				feedback: None, // Only used when task is rejected
+				created_at: old_task.created_at,
+				updated_at: <frame_system::Pallet<T>>::block_number(),
+				completed_at: Default::default(),
+				deadline_block: Some(deadline_block),
+				task_id: old_task.task_id,
+				location,
+				open,
+				slots: old_task.slots.clone(),
+				parent: old_task.parent,
+				delivery_deadline: None,
+				asset: old_task.asset,
+				template: old_task.template,
+				deadline_by_block: matches!(new_deadline, TaskDeadline::Block(_)),
+				funder: old_task.funder.clone(),
+				bond: old_task.bond,
+			};
+
+			if old_task.location != new_task.location {
+				Self::unindex_location(task_id, &old_task.location);
+				Self::index_location(task_id, &new_task.location)?;
+			}
+
+			if old_task.organization != new_task.organization {
+				Self::unindex_task(&old_task);
+				Self::index_task(&new_task);
+			}
+
+			if old_task.deadline_block != new_task.deadline_block {
+				if let Some(d) = new_task.deadline_block {
+				// Make sure to handle the storage changes on deadline change;
+					Self::handle_new_task_deadline(task_id, &old_task.deadline_block, &d)?;
+				}
+			}
+
+			// Insert task into Hashmap
+			<Tasks<T>>::insert(task_id, new_task);
+
+			Ok(())
+		}
+
+		fn assign_task(volunteer: &T::AccountId, task_id: &T::Hash) -> Result<(), DispatchError> {
+
+			// Check if task exists
+			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Volunteer must be different than task Initiator
+			ensure!(!Self::is_task_initiator(task_id, volunteer)?, <Error<T>>::NoPermissionToStart);
+
+			// Ensure that only Created Task can be started
+			ensure!(TaskStatus::Created == task.status, <Error<T>>::NoPermissionToStart);
+
+			// Ensure the deadline has not passed, slow blocks expire timestamp deadlines after their block timestamp
+			ensure!(!Self::is_past_deadline(&task), <Error<T>>::TaskDeadlinePassed);
+
+			// Ensure every task this task depends on has been accepted
+			ensure!(!Self::is_blocked(task_id), <Error<T>>::TaskBlocked);
+
+			// Ensure the volunteer is allowed by the visibility of the task
+			Self::ensure_can_volunteer(volunteer, task_id, &task)?;
+
+			// Team tasks are joined slot by slot
+			if !task.slots.is_empty() {
+				return Self::join_team_task(volunteer, task_id, task);
+			}
+
+			// Reserve the bond the task requires from the volunteer
+			Self::reserve_bond(volunteer, task_id, Self::bond_amount(&task, task.budget))?;
+
+			// Remove task ownership from previous owner
+			let prev_owner = task.current_owner.clone();
+			<TasksOwned<T>>::try_mutate(&prev_owner, |owned| {
+				if let Some(index) = owned.iter().position(|&id| id == *task_id) {
+					owned.swap_remove(index);
+					return Ok(());
+				}
+				Err(())
+			}).map_err(|_| <Error<T>>::TaskNotExist)?;
+
+			// Change task properties and insert
+			task.current_owner = volunteer.clone();
+			task.volunteer = volunteer.clone();
+			Self::set_status(&mut task, TaskStatus::InProgress);
+			task.delivery_deadline = Some(Self::new_delivery_deadline(&task));
+
+			// Remove the task from expiring list;
+			if let Some(deadline) = task.deadline_block {
+				Self::remove_task_from_expiring(&task_id, deadline);
+			}
+
+			<Tasks<T>>::insert(task_id, task);
+
+			// Assign task to volunteer
+			<TasksOwned<T>>::try_mutate(volunteer, |vec| {
+				vec.try_push(*task_id)
+			}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+
+			// The task is taken, close its applications
+			Self::close_applications(task_id, volunteer, true);
+
+			T::LifecycleHooks::on_started(task_id, sp_std::slice::from_ref(volunteer))
+		}
+
+		// Assigns the volunteer to the first free slot of a team task. The task is started once every slot is taken.
+		fn join_team_task(volunteer: &T::AccountId, task_id: &T::Hash, mut task: Task<T>) -> Result<(), DispatchError> {
+
+			ensure!(!task.slots.iter().any(|slot| slot.volunteer.as_ref() == Some(volunteer)), <Error<T>>::AlreadyJoined);
+
+			let slot = task.slots.iter().position(|slot| slot.volunteer.is_none()).ok_or(<Error<T>>::NoPermissionToStart)?;
+
+			// Reserve the bond the task requires from the volunteer, in proportion to the share of the slot
+			Self::reserve_bond(volunteer, task_id, Self::bond_amount(&task, task.slots[slot].share))?;
+			task.slots[slot].volunteer = Some(volunteer.clone());
+
+			let task_taken = task.slots.iter().all(|slot| slot.volunteer.is_some());
+			if task_taken {
+				// Move task ownership from the initiator to every volunteer who still has work to do
+				Self::remove_task_from_owner(&task.initiator, task_id)?;
+				for volunteer in Self::unfinished_volunteers(&task) {
+					Self::add_task_to_owner(&volunteer, task_id)?;
+				}
+
+				// The volunteer of the first slot represents the team
+				task.volunteer = Self::participants(&task)[0].clone();
+				task.current_owner = task.volunteer.clone();
+				Self::set_status(&mut task, TaskStatus::InProgress);
+				task.delivery_deadline = Some(Self::new_delivery_deadline(&task));
+
+				// Remove the task from expiring list;
+				if let Some(deadline) = task.deadline_block {
+					Self::remove_task_from_expiring(&task_id, deadline);
+				}
+			}
+
+			let volunteers = Self::participants(&task);
+			<Tasks<T>>::insert(task_id, task);
+
+			Self::close_applications(task_id, volunteer, task_taken);
+
+			if task_taken {
+				T::LifecycleHooks::on_started(task_id, &volunteers)?;
+			}
+
+			Ok(())
+		}
+
+		fn mark_finished(to: &T::AccountId, task_id: &T::Hash) -> Result<(), DispatchError> {
+
+			// Check if task exists
+			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Check if task is in progress before closing
+			ensure!(TaskStatus::InProgress == task.status, <Error<T>>::NoPermissionToComplete);
+
+			// Every volunteer of a team task completes their slot independently
+			if !task.slots.is_empty() {
+				return Self::mark_slot_finished(to, task_id, task);
+			}
+
+			// Check if the volunteer is the one who finished task
+			ensure!(to == &task.volunteer, <Error<T>>::NoPermissionToComplete);
+
+			// Remove task ownership from current signer
+			<TasksOwned<T>>::try_mutate(&to, |owned| {
+				if let Some(index) = owned.iter().position(|&id| id == *task_id) {
+					owned.swap_remove(index);
+					return Ok(());
+				}
+				Err(())
+			}).map_err(|_| <Error<T>>::TaskNotExist)?;
+
+			// Set current owner to initiator
+			task.current_owner = task.initiator.clone();
+			Self::set_status(&mut task, TaskStatus::Completed);
+			task.completed_at = <frame_system::Pallet<T>>::block_number();
+			let task_initiator = task.initiator.clone();
+
+			// Accept the task automatically if the initiator doesn't review it in time
+			Self::schedule_review(task_id, task.completed_at)?;
+
+			// Insert into update task
+			<Tasks<T>>::insert(task_id, task);
+
+			// Assign task to new owner (original initiator)
+			<TasksOwned<T>>::try_mutate(task_initiator, |vec| {
+				vec.try_push(*task_id)
+			}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+
+			T::LifecycleHooks::on_completed(task_id, sp_std::slice::from_ref(to))
+		}
+
+		// Appends a submission to the history of the task, dropping the oldest submission when it is full.
+		fn record_submission(volunteer: &T::AccountId, task_id: &T::Hash, deliverables: BoundedVec<Deliverable<T>, T::MaxDeliverablesPerSubmission>) {
+			let submission = Submission::<T> {
+				volunteer: volunteer.clone(),
+				deliverables,
+				submitted_at: <frame_system::Pallet<T>>::block_number(),
+			};
+			<Submissions<T>>::mutate(task_id, |submissions| {
+				if submissions.len() as u32 >= T::MaxSubmissionsPerTask::get() {
+					submissions.remove(0);
+				}
+				let _ = submissions.try_push(submission);
+			});
+		}
+
+		// Marks the slot of the volunteer as completed. The task is completed once every slot is.
+		fn mark_slot_finished(to: &T::AccountId, task_id: &T::Hash, mut task: Task<T>) -> Result<(), DispatchError> {
+
+			let slot = task.slots.iter_mut().find(|slot| slot.volunteer.as_ref() == Some(to)).ok_or(<Error<T>>::NoPermissionToComplete)?;
+			ensure!(!slot.completed, <Error<T>>::NoPermissionToComplete);
+			slot.completed = true;
+
+			// Remove task ownership from current signer
+			Self::remove_task_from_owner(to, task_id)?;
+
+			let task_completed = task.slots.iter().all(|slot| slot.completed);
+			if task_completed {
+				// Set current owner to initiator
+				task.current_owner = task.initiator.clone();
+				Self::set_status(&mut task, TaskStatus::Completed);
+				task.completed_at = <frame_system::Pallet<T>>::block_number();
+
+				// Assign task to new owner (original initiator)
+				Self::add_task_to_owner(&task.initiator, task_id)?;
+
+				// Accept the task automatically if the initiator doesn't review it in time
+				Self::schedule_review(task_id, task.completed_at)?;
+			}
+
+			let volunteers = Self::participants(&task);
+			<Tasks<T>>::insert(task_id, task);
+
+			if task_completed {
+				T::LifecycleHooks::on_completed(task_id, &volunteers)?;
+			}
+
+			Ok(())
+		}
+
+		// Pays the escrow out to the volunteers, accepts the task and keeps a receipt of it.
+		#[transactional]
+		fn settle_accepted_task(task_initiator: &T::AccountId, task_id: &T::Hash, mut task: Task<T>) -> DispatchResult {
+
+			// Transfer escrowed funds of task amount to the volunteers, minus the fees, or keep them in escrow while they vest.
+			let payout = Self::take_payout_fees(task_id, &task, Self::escrowed_balance(task_id))?;
+
+			// Milestones accepted earlier have already been paid out of the budget.
+			let amount = payout.saturating_add(Self::paid_milestones_payout(&task));
+			match Self::vesting_schedules(task_id) {
+				Some(schedule) => Self::vest_payout(task_id, &task, payout, schedule)?,
+				None => Self::pay_volunteers(task_id, &task, payout)?,
+			}
+
+			// Return the bonds of the volunteers, who delivered the task.
+			Self::release_bonds(task_id);
+
+			// Accept task and update storage.
+			Self::accept_completed_task(task_initiator, &mut task, task_id)?;
+
+			// Let other pallets react, the runtime rewards the profiles of the initiator and the volunteers.
+			T::LifecycleHooks::on_accepted(task_id, &task.initiator, &Self::participants(&task))?;
+
+			// Keep a receipt of the accepted task.
+			Self::archive_task(&task, amount)
+		}
+
+		// Internal helper function, checks Must be called before calling this function.
+		fn accept_completed_task(task_initiator: &T::AccountId, task: &mut Task<T>, task_id: &T::Hash) -> Result<(), DispatchError> {
+
+			// The task has been reviewed
+			if TaskStatus::Completed == task.status {
+				Self::remove_task_from_review(task_id, task.completed_at + T::ReviewPeriod::get());
+			}
+
+			// Remove from ownership
+			<TasksOwned<T>>::try_mutate(&task_initiator, |owned| {
+				if let Some(index) = owned.iter().position(|&id| id == *task_id) {
+					owned.swap_remove(index);
+					return Ok(());
+				}
+				Err(())
+			}).map_err(|_| <Error<T>>::TaskNotExist)?;
+
+			// Unblock the dependents and detach the subtasks of the task
+			Self::unlink_task(task_id, task);
+			Self::unindex_location(task_id, &task.location);
+			Self::unindex_task(task);
+
+			// Update task state
+			task.status = TaskStatus::Accepted;
+			<Tasks<T>>::insert(task_id, task);
+
+			// remove task, its milestones and applications once accepted
+			<Tasks<T>>::remove(task_id);
+			<Milestones<T>>::remove(task_id);
+			<Applications<T>>::remove(task_id);
+			<Visibility<T>>::remove(task_id);
+			<Invitations<T>>::remove(task_id);
+			<Submissions<T>>::remove(task_id);
+			<VestingSchedules<T>>::remove(task_id);
+
+			// Reduce task count
+			let new_count = Self::task_count().saturating_sub(1);
+			<TaskCount<T>>::put(new_count);
+
+			Ok(())
+		}
+
+		// Task can be rejected by the creator, which places the task back into progress.
+		fn reject_completed_task(task_initiator: &T::AccountId, task_id: &T::Hash, feedback: BoundedVec<u8, T::MaxFeedbackLen>) -> Result<(), DispatchError> {
+
+			// Check if task exists
+			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Check if the owner is the one who created task
+			ensure!(Self::is_task_initiator(task_id, task_initiator)?, <Error<T>>::OnlyInitiatorAcceptsTask);
+
+			// Check if task is Completed before rejecting it
+			ensure!(TaskStatus::Completed == task.status, <Error<T>>::OnlyCompletedTaskAreRejected);
+
+			// The task has been reviewed
+			Self::remove_task_from_review(task_id, task.completed_at + T::ReviewPeriod::get());
+
+			// Remove from ownership of initiator
+			<TasksOwned<T>>::try_mutate(&task_initiator, |owned| {
+				if let Some(index) = owned.iter().position(|&id| id == *task_id) {
+					owned.swap_remove(index);
+					return Ok(());
+				}
+				Err(())
+			}).map_err(|_| <Error<T>>::TaskNotExist)?;
+
+			// Set current owner back to volunteer, who gets a new delivery period for the rework
+			task.current_owner = task.volunteer.clone();
+			Self::set_status(&mut task, TaskStatus::InProgress);
+			task.feedback = Some(feedback);
+			task.delivery_deadline = Some(Self::new_delivery_deadline(&task));
+
+			// Every volunteer of a team task has to complete their slot again
+			for slot in task.slots.iter_mut() {
+				slot.completed = false;
+			}
+
+			// Assign task to new owners (original volunteers)
+			let volunteers = Self::participants(&task);
+			for participant in volunteers.iter() {
+				Self::add_task_to_owner(participant, task_id)?;
+			}
+
+			// Insert task
+			<Tasks<T>>::insert(task_id, task);
+
+			T::LifecycleHooks::on_rejected(task_id, &volunteers)
+		}
+
+		// The initiator takes back an in-progress task past its delivery deadline, from every volunteer who has not completed their part.
+		fn reclaim_stale_task(task_initiator: &T::AccountId, task_id: &T::Hash) -> Result<(), DispatchError> {
+
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			ensure!(task.initiator == *task_initiator && TaskStatus::InProgress == task.status, <Error<T>>::NoPermissionToReclaim);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(task.delivery_deadline.map_or(false, |deadline| deadline < now), <Error<T>>::DeliveryDeadlineNotReached);
+
+			let stale_volunteers = Self::unfinished_volunteers(&task);
+			Self::unassign_volunteers(task_id, task, &stale_volunteers)
+		}
+
+		// A volunteer leaves an in-progress task before completing their part.
+		fn leave_task(volunteer: &T::AccountId, task_id: &T::Hash) -> Result<(), DispatchError> {
+
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			ensure!(TaskStatus::InProgress == task.status, <Error<T>>::NoPermissionToAbandon);
+			ensure!(Self::unfinished_volunteers(&task).contains(volunteer), <Error<T>>::NoPermissionToAbandon);
+
+			Self::unassign_volunteers(task_id, task, sp_std::slice::from_ref(volunteer))
+		}
+
+		// Returns the volunteers of an in-progress task who have not completed their part yet.
+		fn unfinished_volunteers(task: &Task<T>) -> Vec<T::AccountId> {
+			if task.slots.is_empty() {
+				return sp_std::vec![task.volunteer.clone()];
+			}
+			task.slots.iter()
+				.filter(|slot| !slot.completed)
+				.filter_map(|slot| slot.volunteer.clone())
+				.collect()
+		}
+
+		// Removes the leaving volunteers from an in-progress task, who lose reputation for it, and puts the task back to Created.
+		// Volunteers of a team task who stay keep their slot, and the task restarts once every slot has been taken again.
+		fn unassign_volunteers(task_id: &T::Hash, mut task: Task<T>, leaving: &[T::AccountId]) -> Result<(), DispatchError> {
+			let now = <frame_system::Pallet<T>>::block_number();
+
+			// The task returns to the initiator until volunteers take it again
+			for volunteer in Self::unfinished_volunteers(&task) {
+				Self::remove_task_from_owner(&volunteer, task_id)?;
+			}
+			Self::add_task_to_owner(&task.initiator, task_id)?;
+
+			for volunteer in leaving {
+				// The bond of a volunteer who did not deliver goes to the initiator
+				Self::slash_bond(task_id, volunteer, &task.initiator);
+			}
+
+			for slot in task.slots.iter_mut() {
+				if slot.volunteer.as_ref().map_or(false, |volunteer| leaving.contains(volunteer)) {
+					slot.volunteer = None;
+					slot.completed = false;
+				}
+			}
+
+			// Submitted milestones have to be submitted again by the next volunteer
+			<Milestones<T>>::mutate(task_id, |milestones| {
+				for milestone in milestones.iter_mut().filter(|m| m.status == MilestoneStatus::Submitted) {
+					milestone.status = MilestoneStatus::Created;
+				}
+			});
+
+			task.volunteer = task.initiator.clone();
+			task.current_owner = task.initiator.clone();
+			Self::set_status(&mut task, TaskStatus::Created);
+			task.delivery_deadline = None;
+			task.updated_at = now;
+
+			// Put the task back on the expiry schedule, expiring right away if its deadline has already passed
+			let deadline = task.deadline_block.map_or(now + One::one(), |deadline| deadline.max(now + One::one()));
+			task.deadline_block = Some(deadline);
+			Self::handle_new_task_deadline(task_id, &None, &deadline)?;
+
+			<Tasks<T>>::insert(task_id, task);
+
+			T::LifecycleHooks::on_abandoned(task_id, leaving)
+		}
+
+		// Returns the delivery deadline of a task started now: its deadline, but no less than the delivery period.
+		fn new_delivery_deadline(task: &Task<T>) -> T::BlockNumber {
+			let delivery_period_end = <frame_system::Pallet<T>>::block_number() + T::DeliveryPeriod::get();
+			task.deadline_block.map_or(delivery_period_end, |deadline| deadline.max(delivery_period_end))
+		}
+
+		// Changes the budget of a task, moving the difference between the funder and the escrow account.
+		fn change_budget(task_initiator: &T::AccountId, task_id: &T::Hash, budget: BalanceOf<T>) -> Result<(), DispatchError> {
+
+			// Check if task exists
+			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// The budget must still cover all milestones of the task, and be held by its escrow account.
+			ensure!(budget >= Self::milestones_budget(task_id), Error::<T>::MilestoneBudgetExceeded);
+			ensure!(budget >= Self::minimum_balance(task.asset), Error::<T>::BudgetBelowMinimum);
+
+			// The volunteer slots of a team task must still add up to the budget.
+			ensure!(task.slots.is_empty() || Self::slots_budget(&task) == budget, Error::<T>::SlotSharesMismatch);
+
+			if task.budget != budget {
+				// Check that sender can pay.
+				// Move difference into escrow if the budget has increased.
+				if budget > task.budget {
+					let diff = budget - task.budget;
+					ensure!(Self::can_fund(task_initiator, &task), Error::<T>::NoPermissionToFund);
+					ensure!(Self::free_balance(&task.funder, task.asset) >= diff, Error::<T>::NotEnoughBalance);
+					Self::fund_escrow(&task.funder, task_id, task.asset, diff)?;
+
+				// Refund difference from escrow if the budget has decreased.
+				} else {
+					let diff = task.budget - budget;
+					Self::release_escrow(task_id, &task.funder, task.asset, diff)?;
+				}
+
+				task.budget = budget;
+				<Tasks<T>>::insert(task_id, task);
+			}
+
+			Ok(())
+		}
+
+		// Removes a task, together with its subtasks that have not been started yet while `walk_left` is given.
+		// Every subtask visited counts against `walk_left`, and the removal fails once none are left.
+		fn delete_task(task_initiator: &T::AccountId, task_id: &T::Hash, mut walk_left: Option<&mut u32>) -> Result<(), DispatchError> {
+
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Check if the owner is the one who created task
+			ensure!(Self::is_task_initiator(task_id, task_initiator)?, <Error<T>>::NoPermissionToRemove);
+
+			// Ensure that only Created Task can be deleted
+			ensure!(TaskStatus::Created == task.status || TaskStatus::Expired == task.status, <Error<T>>::NoPermissionToRemove);
+
+			// Remove the subtasks that have not been started yet, their budget flows back into this task
+			if let Some(walk_left) = walk_left.as_deref_mut() {
+				for subtask_id in Self::subtasks(task_id).iter() {
+					ensure!(*walk_left > 0, <Error<T>>::WalkLimitReached);
+					*walk_left -= 1;
+					if Self::tasks(subtask_id).map_or(false, |s| TaskStatus::Created == s.status || TaskStatus::Expired == s.status) {
+						Self::delete_task(task_initiator, subtask_id, Some(&mut *walk_left))?;
+					}
+				}
+			}
+
+			// remove task, its milestones and applications from storage
+			Self::unindex_location(task_id, &task.location);
+			Self::unindex_task(&task);
+			<Tasks<T>>::remove(task_id);
+			<Milestones<T>>::remove(task_id);
+			<Applications<T>>::remove(task_id);
+			<Visibility<T>>::remove(task_id);
+			<Invitations<T>>::remove(task_id);
+			<Submissions<T>>::remove(task_id);
+			<VestingSchedules<T>>::remove(task_id);
+			Self::release_bonds(task_id);
+
+			// Detach the remaining subtasks and drop the dependencies of the task
+			Self::unlink_task(task_id, &task);
+
+			// Refund escrowed budget to the parent if it has not been started yet, or to the task funder otherwise
+			match task.parent.and_then(|parent_id| Self::tasks(parent_id).map(|parent| (parent_id, parent))) {
+				Some((parent_id, mut parent)) if TaskStatus::Created == parent.status || TaskStatus::Expired == parent.status => {
+					let amount = Self::escrowed_balance(task_id);
+					Self::transfer_escrow(task_id, &parent_id, task.asset, amount)?;
+					parent.budget = parent.budget.saturating_add(amount);
+					<Tasks<T>>::insert(parent_id, parent);
+				},
+				_ => Self::release_escrow(task_id, &task.funder, task.asset, Self::escrowed_balance(task_id))?,
+			}
+
+			// Reduce task count
+			let new_count = Self::task_count().saturating_sub(1);
+			<TaskCount<T>>::put(new_count);
+
+			T::LifecycleHooks::on_removed(task_id)
+		}
+
+		// Creates a subtask that belongs to the organization and location of its parent, funded from the parent escrow.
+		fn new_subtask(task_initiator: &T::AccountId, parent_id: &T::Hash, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>,
+			budget: BalanceOf<T>, deadline: TaskDeadline<T::BlockNumber>, attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, open: bool
+		) -> Result<T::Hash, DispatchError> {
+
+			// Check if parent exists
+			let mut parent = Self::tasks(&parent_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Check if the owner is the one who created the parent
+			ensure!(parent.initiator == *task_initiator, <Error<T>>::OnlyInitiatorUpdatesTask);
+
+			// The budget can be split only before work on the parent has been started.
+			ensure!(TaskStatus::Created == parent.status, <Error<T>>::NoPermissionToUpdate);
+
+			// The volunteer slots of a team task must keep adding up to its budget.
+			ensure!(parent.slots.is_empty(), <Error<T>>::SlotSharesMismatch);
+
+			// Ensure the subtask fits within the part of the parent budget not split into milestones
+			ensure!(budget <= parent.budget.saturating_sub(Self::milestones_budget(parent_id)), <Error<T>>::SubtaskBudgetExceeded);
+
+			// What is left in the parent escrow has to stay above its minimum balance, unless the whole budget is split off.
+			let remaining = parent.budget.saturating_sub(budget);
+			ensure!(remaining.is_zero() || remaining >= Self::minimum_balance(parent.asset), <Error<T>>::BudgetBelowMinimum);
+
+			ensure!(Self::task_depth(parent_id) < T::MaxSubtaskDepth::get(), <Error<T>>::SubtaskTooDeep);
+
+			let task_id = Self::new_task(task_initiator, title, specification, &budget, deadline, attachments, keywords, parent.organization, parent.location, open, Some(*parent_id), parent.asset, None, &parent.funder)?;
+
+			<Subtasks<T>>::try_mutate(parent_id, |subtasks| {
+				subtasks.try_push(task_id)
+			}).map_err(|_| <Error<T>>::TooManySubtasks)?;
+
+			// Move the budget of the subtask out of the parent escrow.
+			Self::transfer_escrow(parent_id, &task_id, parent.asset, budget)?;
+			parent.budget = parent.budget.saturating_sub(budget);
+			<Tasks<T>>::insert(parent_id, parent);
+
+			Ok(task_id)
+		}
+
+		fn new_dependency(task_initiator: &T::AccountId, task_id: &T::Hash, depends_on: &T::Hash) -> Result<(), DispatchError> {
+
+			// Check if both tasks exist
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			ensure!(<Tasks<T>>::contains_key(depends_on), <Error<T>>::TaskNotExist);
+
+			// Check if the owner is the one who created task
+			ensure!(task.initiator == *task_initiator, <Error<T>>::OnlyInitiatorUpdatesTask);
+
+			// Dependencies can be added only before work has been started.
+			ensure!(TaskStatus::Created == task.status, <Error<T>>::NoPermissionToUpdate);
+
+			ensure!(!Self::dependencies(task_id).contains(depends_on), <Error<T>>::AlreadyDependsOn);
+
+			// Reject the dependency if the other task already depends on this task.
+			ensure!(!Self::reaches_dependency(depends_on, task_id)?, <Error<T>>::DependencyCycle);
+
+			<Dependencies<T>>::try_mutate(task_id, |dependencies| {
+				dependencies.try_push(*depends_on)
+			}).map_err(|_| <Error<T>>::TooManyDependencies)?;
+
+			<Dependents<T>>::try_mutate(depends_on, |dependents| {
+				dependents.try_push(*task_id)
+			}).map_err(|_| <Error<T>>::TooManyDependencies)?;
+
+			Ok(())
+		}
+
+		fn delete_dependency(task_initiator: &T::AccountId, task_id: &T::Hash, depends_on: &T::Hash) -> Result<(), DispatchError> {
+
+			// Check if the owner is the one who created task
+			ensure!(Self::is_task_initiator(task_id, task_initiator)?, <Error<T>>::OnlyInitiatorUpdatesTask);
+
+			ensure!(Self::dependencies(task_id).contains(depends_on), <Error<T>>::DependencyNotExist);
+
+			<Dependencies<T>>::mutate(task_id, |dependencies| dependencies.retain(|h| h != depends_on));
+			<Dependents<T>>::mutate(depends_on, |dependents| dependents.retain(|h| h != task_id));
+
+			Ok(())
+		}
+
+		// Removes a task from the subtasks of its parent, detaches its subtasks and drops all of its dependency edges.
+		fn unlink_task(task_id: &T::Hash, task: &Task<T>) {
+			if let Some(parent_id) = task.parent {
+				<Subtasks<T>>::mutate(parent_id, |subtasks| subtasks.retain(|h| h != task_id));
+			}
+			for subtask_id in <Subtasks<T>>::take(task_id).iter() {
+				<Tasks<T>>::mutate(subtask_id, |subtask| {
+					if let Some(subtask) = subtask {
+						subtask.parent = None;
+					}
+				});
+			}
+			for dependency in <Dependencies<T>>::take(task_id).iter() {
+				<Dependents<T>>::mutate(dependency, |dependents| dependents.retain(|h| h != task_id));
+			}
+			for dependent in <Dependents<T>>::take(task_id).iter() {
+				<Dependencies<T>>::mutate(dependent, |dependencies| dependencies.retain(|h| h != task_id));
+			}
+		}
+
+		// Returns true when the target can be reached from the given task by following dependencies.
+		// Fails when more than `MaxTasksWalked` tasks would have to be visited to know.
+		fn reaches_dependency(from: &T::Hash, target: &T::Hash) -> Result<bool, DispatchError> {
+			let mut visited = BTreeSet::new();
+			let mut stack = sp_std::vec![*from];
+			while let Some(task_id) = stack.pop() {
+				if task_id == *target {
+					return Ok(true);
+				}
+				if visited.insert(task_id) {
+					ensure!(visited.len() as u32 <= T::MaxTasksWalked::get(), <Error<T>>::WalkLimitReached);
+					stack.extend(Self::dependencies(task_id).into_iter());
+				}
+			}
+			Ok(false)
+		}
+
+		// Returns the number of ancestors of a task, up to `MaxSubtaskDepth`.
+		fn task_depth(task_id: &T::Hash) -> u32 {
+			let mut depth = 0;
+			let mut parent = Self::tasks(task_id).and_then(|task| task.parent);
+			while let Some(parent_id) = parent {
+				depth += 1;
+				if depth >= T::MaxSubtaskDepth::get() {
+					break;
+				}
+				parent = Self::tasks(parent_id).and_then(|task| task.parent);
+			}
+			depth
+		}
+
+		fn new_recurring_task(initiator: &T::AccountId, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>, budget: BalanceOf<T>,
+			asset: Option<T::AssetId>, attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, organization: Option<OrganizationIdOf<T>>,
+			location: Option<NadLocation>, open: bool, interval: T::BlockNumber, max_occurrences: u32
+		) -> Result<T::Hash, DispatchError> {
+
+			ensure!(interval >= T::MinRecurrenceInterval::get(), <Error<T>>::RecurrenceIntervalTooShort);
+			ensure!(max_occurrences > 0, <Error<T>>::NoOccurrences);
+
+			let recurring = RecurringTask::<T> {
+				title,
+				specification,
+				initiator: initiator.clone(),
+				budget,
+				asset,
 				attachments,
 				keywords,
-				feedback: None, // Only used when task is rejected
 				organization,
-				created_at: <frame_system::Pallet<T>>::block_number(),
-				updated_at: Default::default(),
-				completed_at: Default::default(),
-				deadline_block: Some(deadline_block),
-				task_id: T::Hashing::hash_of(&T::Time::now().as_nanos()),
 				location,
+				open,
+				interval,
+				max_occurrences,
+				occurrences: 0,
+				next_occurrence: <frame_system::Pallet<T>>::block_number(),
+			};
+
+			let recurring_id = T::Hashing::hash_of(&recurring);
+			ensure!(!<RecurringTasks<T>>::contains_key(recurring_id), <Error<T>>::RecurringTaskExists);
+			<RecurringTasks<T>>::insert(recurring_id, recurring);
+
+			Ok(recurring_id)
+		}
+
+		// Spawns the next occurrence of a recurring task, funded by its initiator, and schedules the one after it.
+		// The recurring task is removed once its last occurrence has been spawned.
+		#[transactional]
+		pub(crate) fn spawn_occurrence(recurring_id: &T::Hash) -> Result<T::Hash, DispatchError> {
+			let mut recurring = Self::recurring_tasks(recurring_id).ok_or(<Error<T>>::RecurringTaskNotExist)?;
+
+			// Ensure the initiator can fund the occurrence
+			ensure!(Self::free_balance(&recurring.initiator, recurring.asset) >= recurring.budget, Error::<T>::NotEnoughBalance);
+
+			// Every occurrence is due when the next one is spawned.
+			let deadline = TaskDeadline::Block(<frame_system::Pallet<T>>::block_number() + recurring.interval);
+
+			let task_id = Self::new_task(&recurring.initiator, recurring.title.clone(), recurring.specification.clone(), &recurring.budget, deadline,
+				recurring.attachments.clone(), recurring.keywords.clone(), recurring.organization, recurring.location, recurring.open, None, recurring.asset, None, &recurring.initiator)?;
+			Self::fund_escrow(&recurring.initiator, &task_id, recurring.asset, recurring.budget)?;
+
+			recurring.occurrences = recurring.occurrences.saturating_add(1);
+			if recurring.occurrences < recurring.max_occurrences {
+				recurring.next_occurrence = <frame_system::Pallet<T>>::block_number() + recurring.interval;
+				<RecurringTasksPerBlock<T>>::try_mutate(recurring.next_occurrence, |recurring_tasks| {
+					recurring_tasks.try_push(*recurring_id)
+				}).map_err(|_| <Error<T>>::RecurrenceLimitReached)?;
+				<RecurringTasks<T>>::insert(recurring_id, recurring);
+			} else {
+				<RecurringTasks<T>>::remove(recurring_id);
+			}
+
+			Ok(task_id)
+		}
+
+		fn delete_recurring_task(initiator: &T::AccountId, recurring_id: &T::Hash) -> Result<(), DispatchError> {
+
+			// Check if recurring task exists
+			let recurring = Self::recurring_tasks(recurring_id).ok_or(<Error<T>>::RecurringTaskNotExist)?;
+
+			// Check if the owner is the one who defined the recurring task
+			ensure!(recurring.initiator == *initiator, <Error<T>>::NoPermissionToRemove);
+
+			<RecurringTasks<T>>::remove(recurring_id);
+			<RecurringTasksPerBlock<T>>::mutate(recurring.next_occurrence, |recurring_tasks| {
+				recurring_tasks.retain(|&h| h != *recurring_id);
+			});
+
+			Ok(())
+		}
+
+		fn new_template(creator: &T::AccountId, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>,
+			attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, organization: Option<OrganizationIdOf<T>>, open: bool
+		) -> Result<T::Hash, DispatchError> {
+
+			// Templates of an organization are created by its members
+			let owner = match organization {
+				Some(organization) => {
+					ensure!(T::Organization::exists(&organization), Error::<T>::InvalidOrganization);
+					ensure!(T::Organization::members(&organization).contains(creator), Error::<T>::NoPermissionForTemplate);
+					TemplateOwner::Organization(organization)
+				},
+				None => TemplateOwner::Account(creator.clone()),
+			};
+
+			let template = TaskTemplate::<T> {
+				owner,
+				title,
+				specification,
+				attachments,
+				keywords,
+				open,
+				version: 1,
+				updated_at: <frame_system::Pallet<T>>::block_number(),
+			};
+
+			let template_id = T::Hashing::hash_of(&template);
+			ensure!(!<Templates<T>>::contains_key(template_id), <Error<T>>::TemplateExists);
+			<Templates<T>>::insert(template_id, template);
+
+			Ok(template_id)
+		}
+
+		fn change_template(editor: &T::AccountId, template_id: &T::Hash, title: BoundedVec<u8, T::MaxTitleLen>, specification: BoundedVec<u8, T::MaxSpecificationLen>,
+			attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, open: bool
+		) -> Result<u32, DispatchError> {
+
+			<Templates<T>>::try_mutate(template_id, |maybe_template| -> Result<u32, DispatchError> {
+				let template = maybe_template.as_mut().ok_or(<Error<T>>::TemplateNotExist)?;
+				ensure!(Self::can_use_template(editor, template), <Error<T>>::NoPermissionForTemplate);
+
+				template.title = title;
+				template.specification = specification;
+				template.attachments = attachments;
+				template.keywords = keywords;
+				template.open = open;
+				template.version = template.version.saturating_add(1);
+				template.updated_at = <frame_system::Pallet<T>>::block_number();
+
+				Ok(template.version)
+			})
+		}
+
+		fn delete_template(editor: &T::AccountId, template_id: &T::Hash) -> Result<(), DispatchError> {
+			let template = Self::templates(template_id).ok_or(<Error<T>>::TemplateNotExist)?;
+			ensure!(Self::can_use_template(editor, &template), <Error<T>>::NoPermissionForTemplate);
+
+			<Templates<T>>::remove(template_id);
+
+			Ok(())
+		}
+
+		// Creates a task from the current version of a template, funded by the initiator.
+		fn new_task_from_template(initiator: &T::AccountId, template_id: &T::Hash, budget: BalanceOf<T>, deadline: TaskDeadline<T::BlockNumber>,
+			location: Option<NadLocation>) -> Result<T::Hash, DispatchError> {
+
+			let template = Self::templates(template_id).ok_or(<Error<T>>::TemplateNotExist)?;
+			ensure!(Self::can_use_template(initiator, &template), <Error<T>>::NoPermissionForTemplate);
+
+			// Ensure has enough balance;
+			ensure!(Self::free_balance(initiator, None) >= budget, Error::<T>::NotEnoughBalance);
+
+			let organization = match template.owner {
+				TemplateOwner::Organization(organization) => Some(organization),
+				TemplateOwner::Account(_) => None,
 			};
 
-			// Create hash of task and set that as the task_id;
-			let task_id = T::Hashing::hash_of(&task);
-			task.task_id = task_id;
+			let task_id = Self::new_task(initiator, template.title, template.specification, &budget, deadline, template.attachments, template.keywords,
+				organization, location, template.open, None, None, Some((*template_id, template.version)), initiator)?;
+			Self::fund_escrow(initiator, &task_id, None, budget)?;
 
-			// Performs this operation first because as it may fail
-			<TasksOwned<T>>::try_mutate(&from_initiator, |tasks_vec| {
-				tasks_vec.try_push(task_id)
-			}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+			Ok(task_id)
+		}
 
-			// Insert task into Hashmap
-			<Tasks<T>>::insert(task_id, task);
+		// Templates are used and managed by their owner, or by the members of the organization owning them.
+		fn can_use_template(who: &T::AccountId, template: &TaskTemplate<T>) -> bool {
+			match &template.owner {
+				TemplateOwner::Account(owner) => owner == who,
+				TemplateOwner::Organization(organization) => T::Organization::members(organization).contains(who),
+			}
+		}
 
-			// Increase task count
-			let new_count = Self::task_count().checked_add(1).ok_or(<Error<T>>::TaskCountOverflow)?;
-			<TaskCount<T>>::put(new_count);
+		fn new_milestone(task_initiator: &T::AccountId, task_id: &T::Hash, title: BoundedVec<u8, T::MaxTitleLen>, budget: BalanceOf<T>,
+			deadline: u64) -> Result<MilestoneIndex, DispatchError> {
 
-			// Handle the new deadline
-			let _ = Self::handle_new_task_deadline(&task_id, &None, &deadline_block)?;
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
 
-			Ok(task_id)
+			// Check if the owner is the one who created task
+			ensure!(task.initiator == *task_initiator, <Error<T>>::OnlyInitiatorUpdatesTask);
+
+			// Milestones can be added only before work has been started.
+			ensure!(TaskStatus::Created == task.status, <Error<T>>::NoPermissionToUpdate);
+
+			// Milestone payouts go to a single volunteer.
+			ensure!(task.slots.is_empty(), <Error<T>>::MilestonesNotSupportedForTeamTasks);
+
+			// Milestones that are not submitted before their deadline expire
+			let (_, deadline_block) = Self::resolve_deadline(&TaskDeadline::Timestamp(deadline))?;
+
+			// Ensure the milestones together fit within the task budget
+			let milestones_budget = Self::milestones_budget(task_id).saturating_add(budget);
+			ensure!(milestones_budget <= task.budget, <Error<T>>::MilestoneBudgetExceeded);
+
+			// Paying out milestones must leave either nothing or at least the minimum balance in the escrow account.
+			let minimum = Self::minimum_balance(task.asset);
+			let remaining = task.budget - milestones_budget;
+			ensure!(budget >= minimum && (remaining.is_zero() || remaining >= minimum), <Error<T>>::BudgetBelowMinimum);
+
+			Self::schedule_milestone_expiry(task_id, deadline_block)?;
+			<Milestones<T>>::try_mutate(task_id, |milestones| -> Result<MilestoneIndex, DispatchError> {
+				let index = milestones.len() as MilestoneIndex;
+				milestones.try_push(Milestone::<T> {
+					title,
+					budget,
+					deadline,
+					status: MilestoneStatus::Created,
+					feedback: None, // Only used when milestone is rejected
+					updated_at: <frame_system::Pallet<T>>::block_number(),
+				}).map_err(|_| <Error<T>>::TooManyMilestones)?;
+
+				Ok(index)
+			})
 		}
 
-		// Task can be updated only after it has been created. Task that is already in progress can't be updated.
-		//  Private helper function.
-		fn update_created_task(old_task:Task<T>, task_id: &T::Hash, new_title: BoundedVec<u8, T::MaxTitleLen>, new_specification: BoundedVec<u8, T::MaxSpecificationLen>, new_budget: &BalanceOf<T>,
-			new_deadline: u64, attachments: BoundedVec<u8, T::MaxAttachmentsLen>, keywords: BoundedVec<u8, T::MaxKeywordsLen>, organization: Option<OrganizationIdOf<T>>,
-			location: Option<NadLocation>
-		) -> Result<(), DispatchError> {
-			
+		fn mark_milestone_submitted(volunteer: &T::AccountId, task_id: &T::Hash, index: MilestoneIndex) -> Result<(), DispatchError> {
 
-			let new_task: Task<T> = Task::<T> {
-				title: new_title.clone(),
-				specification: new_specification.clone(),
-				budget: *new_budget,
-				attachments: attachments.clone(),
-				keywords: keywords.clone(),
-				organization: organization,
-				initiator: old_task.initiator.clone(),
-				volunteer: old_task.volunteer.clone(),
-				status: Created,
-				current_owner: old_task.current_owner.clone(),
-				deadline: new_deadline,
-				feedback: None, // Only used when task is rejected
-				created_at: old_task.created_at,
-				updated_at: <frame_system::Pallet<T>>::block_number(),
-				completed_at: Default::default(),
-				deadline_block: Some(Self::get_deadline_block(new_deadline)), 
-				task_id: old_task.task_id,
-				location
-			};
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
 
-			if old_task.deadline != new_deadline {
-				if let Some(d) = new_task.deadline_block {
-				// Make sure to handle the storage changes on deadline change;
-					Self::handle_new_task_deadline(task_id, &old_task.deadline_block, &d)?;
+			// Only the volunteer of a task in progress can submit milestones
+			ensure!(TaskStatus::InProgress == task.status, <Error<T>>::NoPermissionToComplete);
+			ensure!(*volunteer == task.volunteer, <Error<T>>::NoPermissionToComplete);
+
+			<Milestones<T>>::try_mutate(task_id, |milestones| -> Result<(), DispatchError> {
+				let milestone = milestones.get_mut(index as usize).ok_or(<Error<T>>::MilestoneNotExist)?;
+
+				// Rejected milestones can be submitted again
+				ensure!(milestone.status == MilestoneStatus::Created || milestone.status == MilestoneStatus::Rejected,
+					<Error<T>>::NoPermissionToSubmitMilestone);
+
+				// Milestones are first submitted before their deadline
+				let now = T::Time::now().as_millis().saturated_into::<u64>();
+				ensure!(milestone.status == MilestoneStatus::Rejected || now < milestone.deadline, <Error<T>>::MilestoneDeadlinePassed);
+
+				milestone.status = MilestoneStatus::Submitted;
+				milestone.updated_at = <frame_system::Pallet<T>>::block_number();
+
+				Ok(())
+			})
+		}
+
+		// Marks a submitted milestone as accepted. Returns the volunteer and the amount to be paid out.
+		fn accept_submitted_milestone(task_initiator: &T::AccountId, task_id: &T::Hash, index: MilestoneIndex) -> Result<(T::AccountId, BalanceOf<T>), DispatchError> {
+
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Check if the owner is the one who created task
+			ensure!(task.initiator == *task_initiator, <Error<T>>::OnlyInitiatorAcceptsTask);
+
+			// Disputed tasks are settled by the arbitrators
+			ensure!(task.status != TaskStatus::Disputed, <Error<T>>::TaskDisputed);
+
+			<Milestones<T>>::try_mutate(task_id, |milestones| -> Result<(T::AccountId, BalanceOf<T>), DispatchError> {
+				let milestone = milestones.get_mut(index as usize).ok_or(<Error<T>>::MilestoneNotExist)?;
+				ensure!(milestone.status == MilestoneStatus::Submitted, <Error<T>>::OnlySubmittedMilestonesAreReviewed);
+
+				milestone.status = MilestoneStatus::Accepted;
+				milestone.updated_at = <frame_system::Pallet<T>>::block_number();
+
+				Ok((task.volunteer.clone(), milestone.budget))
+			})
+		}
+
+		fn reject_submitted_milestone(task_initiator: &T::AccountId, task_id: &T::Hash, index: MilestoneIndex,
+			feedback: BoundedVec<u8, T::MaxFeedbackLen>) -> Result<(), DispatchError> {
+
+			// Check if the owner is the one who created task
+			ensure!(Self::is_task_initiator(task_id, task_initiator)?, <Error<T>>::OnlyInitiatorAcceptsTask);
+
+			<Milestones<T>>::try_mutate(task_id, |milestones| -> Result<(), DispatchError> {
+				let milestone = milestones.get_mut(index as usize).ok_or(<Error<T>>::MilestoneNotExist)?;
+				ensure!(milestone.status == MilestoneStatus::Submitted, <Error<T>>::OnlySubmittedMilestonesAreReviewed);
+
+				milestone.status = MilestoneStatus::Rejected;
+				milestone.feedback = Some(feedback);
+				milestone.updated_at = <frame_system::Pallet<T>>::block_number();
+
+				Ok(())
+			})
+		}
+
+		fn new_application(applicant: &T::AccountId, task_id: &T::Hash, cover_note: BoundedVec<u8, T::MaxCoverNoteLen>,
+			price: Option<BalanceOf<T>>) -> Result<(), DispatchError> {
+
+			// Check if task exists
+			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Only created tasks can be applied for, and not by their initiator.
+			ensure!(TaskStatus::Created == task.status, <Error<T>>::NoPermissionToApply);
+			ensure!(task.initiator != *applicant, <Error<T>>::NoPermissionToApply);
+
+			// Ensure user has a profile before applying for a task
+			ensure!(T::Profiles::exists(applicant), <Error<T>>::NoProfile);
+
+			<Applications<T>>::try_mutate(task_id, |applications| -> Result<(), DispatchError> {
+				ensure!(!applications.iter().any(|a| a.applicant == *applicant), <Error<T>>::AlreadyApplied);
+
+				applications.try_push(Application::<T> {
+					applicant: applicant.clone(),
+					cover_note,
+					price,
+					status: ApplicationStatus::Pending,
+					created_at: <frame_system::Pallet<T>>::block_number(),
+				}).map_err(|_| <Error<T>>::TooManyApplications)?;
+
+				Ok(())
+			})
+		}
+
+		fn remove_application(applicant: &T::AccountId, task_id: &T::Hash) -> Result<(), DispatchError> {
+
+			<Applications<T>>::try_mutate(task_id, |applications| -> Result<(), DispatchError> {
+				let index = applications.iter().position(|a| a.applicant == *applicant).ok_or(<Error<T>>::ApplicationNotExist)?;
+
+				// Selected and declined applications are kept as a record
+				let status = &applications[index].status;
+				ensure!(*status == ApplicationStatus::Pending || *status == ApplicationStatus::Shortlisted, <Error<T>>::ApplicationClosed);
+
+				applications.remove(index);
+
+				Ok(())
+			})
+		}
+
+		fn mark_application_shortlisted(task_initiator: &T::AccountId, task_id: &T::Hash, applicant: &T::AccountId) -> Result<(), DispatchError> {
+
+			// Check if the owner is the one who created task
+			ensure!(Self::is_task_initiator(task_id, task_initiator)?, <Error<T>>::OnlyInitiatorUpdatesTask);
+
+			<Applications<T>>::try_mutate(task_id, |applications| -> Result<(), DispatchError> {
+				let application = applications.iter_mut().find(|a| a.applicant == *applicant).ok_or(<Error<T>>::ApplicationNotExist)?;
+				ensure!(application.status == ApplicationStatus::Pending || application.status == ApplicationStatus::Shortlisted,
+					<Error<T>>::ApplicationClosed);
+
+				application.status = ApplicationStatus::Shortlisted;
+
+				Ok(())
+			})
+		}
+
+		// Marks the application of the volunteer as selected. Once the task is taken, all other open applications are declined.
+		fn close_applications(task_id: &T::Hash, volunteer: &T::AccountId, task_taken: bool) {
+			<Applications<T>>::mutate(task_id, |applications| {
+				for application in applications.iter_mut() {
+					if application.applicant == *volunteer {
+						application.status = ApplicationStatus::Selected;
+					} else if task_taken && (application.status == ApplicationStatus::Pending || application.status == ApplicationStatus::Shortlisted) {
+						application.status = ApplicationStatus::Declined;
+					}
 				}
+			});
+		}
+
+		fn change_volunteer_slots(task_initiator: &T::AccountId, task_id: &T::Hash, shares: BoundedVec<BalanceOf<T>, T::MaxVolunteerSlots>) -> Result<(), DispatchError> {
+
+			// Check if task exists
+			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+
+			// Check if the owner is the one who created task
+			ensure!(task.initiator == *task_initiator, <Error<T>>::OnlyInitiatorUpdatesTask);
+
+			// Slots can be changed only before work has been started, and before anyone joined.
+			ensure!(TaskStatus::Created == task.status, <Error<T>>::NoPermissionToUpdate);
+			ensure!(task.slots.iter().all(|slot| slot.volunteer.is_none()), <Error<T>>::NoPermissionToChangeSlots);
+			ensure!(Self::milestones(task_id).is_empty(), <Error<T>>::MilestonesNotSupportedForTeamTasks);
+
+			// Ensure the shares add up to the task budget
+			if !shares.is_empty() {
+				let total = shares.iter().fold(Zero::zero(), |acc: BalanceOf<T>, share| acc.saturating_add(*share));
+				ensure!(total == task.budget, <Error<T>>::SlotSharesMismatch);
+				let minimum = Self::minimum_balance(task.asset);
+				ensure!(shares.iter().all(|share| *share >= minimum), <Error<T>>::BudgetBelowMinimum);
 			}
 
-			// Insert task into Hashmap
-			<Tasks<T>>::insert(task_id, new_task);
+			task.slots = shares.into_iter()
+				.map(|share| VolunteerSlot::<T> { share, volunteer: None, completed: false })
+				.collect::<Vec<_>>()
+				.try_into()
+				.expect("same length as the bounded shares; qed");
+			task.updated_at = <frame_system::Pallet<T>>::block_number();
+			<Tasks<T>>::insert(task_id, task);
 
 			Ok(())
 		}
 
-		fn assign_task(volunteer: &T::AccountId, task_id: &T::Hash) -> Result<(), DispatchError> {
+		// Pays an amount out of escrow to the volunteers of a task. Team volunteers are paid in proportion to their share.
+		fn pay_volunteers(task_id: &T::Hash, task: &Task<T>, amount: BalanceOf<T>) -> DispatchResult {
+			// Pay the smallest shares first so what is left in the escrow account stays above its minimum balance until the last payout.
+			for (volunteer, payout) in Self::volunteer_shares(task, amount) {
+				Self::release_escrow(task_id, &volunteer, task.asset, payout)?;
+			}
 
-			// Check if task exists
-			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			Ok(())
+		}
 
-			// Volunteer must be different than task Initiator
-			ensure!(!Self::is_task_initiator(task_id, volunteer)?, <Error<T>>::NoPermissionToStart);
+		// Splits an amount between the volunteers of a task by the share of their slot, smallest shares first.
+		// The last share takes whatever remains of the amount.
+		fn volunteer_shares(task: &Task<T>, amount: BalanceOf<T>) -> Vec<(T::AccountId, BalanceOf<T>)> {
+			if task.slots.is_empty() {
+				return sp_std::vec![(task.volunteer.clone(), amount)];
+			}
 
-			// Ensure that only Created Task can be started
-			ensure!(TaskStatus::Created == task.status, <Error<T>>::NoPermissionToStart);
+			let total = Self::slots_budget(task);
+			let mut slots = task.slots.clone().into_inner();
+			slots.sort_by(|a, b| a.share.cmp(&b.share));
+			let last = slots.len() - 1;
+			let mut paid: BalanceOf<T> = Zero::zero();
+			let mut shares = Vec::new();
+			for (index, slot) in slots.iter().enumerate() {
+				if let Some(volunteer) = &slot.volunteer {
+					let payout = if index == last { amount.saturating_sub(paid) } else { Perbill::from_rational(slot.share, total) * amount };
+					shares.push((volunteer.clone(), payout));
+					paid = paid.saturating_add(payout);
+				}
+			}
+			shares
+		}
 
-			// Remove task ownership from previous owner
-			let prev_owner = task.current_owner.clone();
-			<TasksOwned<T>>::try_mutate(&prev_owner, |owned| {
-				if let Some(index) = owned.iter().position(|&id| id == *task_id) {
-					owned.swap_remove(index);
-					return Ok(());
+		// Keeps the payout of an accepted task in its escrow account, for the volunteers to claim as it vests.
+		// The minimum balance of the escrow account is kept out of the shares, so that every claim keeps the account
+		// alive until the one settling the payout. Payouts that don't exceed the minimum balance are paid at once.
+		fn vest_payout(task_id: &T::Hash, task: &Task<T>, amount: BalanceOf<T>, schedule: VestingSchedule<T::BlockNumber>) -> DispatchResult {
+			let minimum = Self::minimum_balance(task.asset);
+			if amount <= minimum {
+				return Self::pay_volunteers(task_id, task, amount);
+			}
+
+			let starts_at = <frame_system::Pallet<T>>::block_number() + schedule.cliff;
+			let ends_at = starts_at + schedule.period;
+			let shares: Vec<VestingShare<T>> = Self::volunteer_shares(task, amount - minimum).into_iter()
+				.map(|(volunteer, amount)| VestingShare { volunteer, amount, claimed: Zero::zero() })
+				.collect();
+
+			<VestingPayouts<T>>::insert(task_id, VestingPayout::<T> {
+				initiator: task.initiator.clone(),
+				funder: task.funder.clone(),
+				organization: task.organization,
+				asset: task.asset,
+				starts_at,
+				ends_at,
+				shares: shares.try_into().map_err(|_| <Error<T>>::TooManyVolunteers)?,
+			});
+
+			Self::deposit_event(Event::PayoutVesting(*task_id, starts_at, ends_at));
+			Ok(())
+		}
+
+		// The part of an amount vesting from one block to another that has vested at a given block.
+		fn vested_amount(starts_at: T::BlockNumber, ends_at: T::BlockNumber, amount: BalanceOf<T>, at: T::BlockNumber) -> BalanceOf<T> {
+			if at >= ends_at {
+				return amount;
+			}
+			if at <= starts_at {
+				return Zero::zero();
+			}
+			Perbill::from_rational(at - starts_at, ends_at - starts_at) * amount
+		}
+
+		/// Returns the part of the vesting payout of a task that a volunteer can claim.
+		pub fn claimable(task_id: &T::Hash, volunteer: &T::AccountId) -> BalanceOf<T> {
+			let now = <frame_system::Pallet<T>>::block_number();
+			Self::vesting_payouts(task_id).and_then(|payout| {
+				payout.shares.iter().find(|share| share.volunteer == *volunteer).map(|share| {
+					let vested = Self::vested_amount(payout.starts_at, payout.ends_at, share.amount, now).saturating_sub(share.claimed);
+					// The claim settling the payout also takes the minimum balance kept out of the shares
+					if !vested.is_zero() && vested == Self::owed_amount(&payout) { Self::escrowed_balance(task_id) } else { vested }
+				})
+			}).unwrap_or_else(Zero::zero)
+		}
+
+		// The part of the shares of a vesting payout that has not been claimed yet.
+		fn owed_amount(payout: &VestingPayout<T>) -> BalanceOf<T> {
+			payout.shares.iter().fold(Zero::zero(), |acc: BalanceOf<T>, share| acc.saturating_add(share.amount.saturating_sub(share.claimed)))
+		}
+
+		// Pays a volunteer what has vested of their share since their last claim. The vesting payout is removed
+		// once every share has been claimed, the last claim taking what remains in escrow with it.
+		// Claims wait for the dispute of the payout to be settled.
+		fn release_vested(volunteer: &T::AccountId, task_id: &T::Hash) -> Result<BalanceOf<T>, DispatchError> {
+			ensure!(!<Disputes<T>>::contains_key(task_id), <Error<T>>::TaskDisputed);
+			let mut payout = Self::vesting_payouts(task_id).ok_or(<Error<T>>::VestingNotExist)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let (starts_at, ends_at) = (payout.starts_at, payout.ends_at);
+			let share = payout.shares.iter_mut().find(|share| share.volunteer == *volunteer).ok_or(<Error<T>>::NoPermissionToClaim)?;
+			let vested = Self::vested_amount(starts_at, ends_at, share.amount, now).saturating_sub(share.claimed);
+			ensure!(!vested.is_zero(), <Error<T>>::NothingVested);
+			share.claimed = share.claimed.saturating_add(vested);
+
+			// The claim settling the payout also takes the minimum balance kept out of the shares
+			let owed = Self::owed_amount(&payout);
+			let amount = if owed.is_zero() { Self::escrowed_balance(task_id) } else { vested };
+			Self::release_escrow(task_id, volunteer, payout.asset, amount)?;
+
+			if owed.is_zero() {
+				<VestingPayouts<T>>::remove(task_id);
+			} else {
+				<VestingPayouts<T>>::insert(task_id, payout);
+			}
+
+			Ok(amount)
+		}
+
+		// Returns the platform fee and the cut of the organization taken out of a payout of the task.
+		fn payout_fees(task: &Task<T>, amount: BalanceOf<T>) -> (BalanceOf<T>, BalanceOf<T>) {
+			let platform_fee = T::PlatformFee::get() * amount;
+			let organization_cut = if task.organization.is_some() { T::OrganizationFee::get() * amount } else { Zero::zero() };
+			(platform_fee, organization_cut)
+		}
+
+		// Takes the platform fee and the cut of the organization out of a payout, and returns what is left of it.
+		// Platform fees in the native currency are handled by their destination, asset fees go to the fee account.
+		fn take_payout_fees(task_id: &T::Hash, task: &Task<T>, amount: BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+			let (platform_fee, organization_cut) = Self::payout_fees(task, amount);
+			if platform_fee.is_zero() && organization_cut.is_zero() {
+				return Ok(amount);
+			}
+			let payout = amount.saturating_sub(platform_fee).saturating_sub(organization_cut);
+
+			if !platform_fee.is_zero() {
+				match task.asset {
+					Some(asset) => Self::release_escrow(task_id, &T::PlatformFeeAccount::get(), Some(asset), platform_fee)?,
+					None => Self::withdraw_escrow(task_id, platform_fee, |escrow, existence| {
+						let imbalance = <T as self::Config>::Currency::withdraw(escrow, platform_fee, WithdrawReasons::FEE, existence)?;
+						T::PlatformFeeDestination::on_unbalanced(imbalance);
+						Ok(())
+					})?,
 				}
-				Err(())
-			}).map_err(|_| <Error<T>>::TaskNotExist)?;
+			}
+			if let Some(organization) = task.organization.filter(|_| !organization_cut.is_zero()) {
+				Self::release_escrow(task_id, &T::Organization::account(&organization), task.asset, organization_cut)?;
+			}
 
-			// Change task properties and insert
-			task.current_owner = volunteer.clone();
-			task.volunteer = volunteer.clone();
-			task.status = TaskStatus::InProgress;
+			Self::deposit_event(Event::PayoutFeesTaken(*task_id, platform_fee, organization_cut, payout));
+			Ok(payout)
+		}
 
-			// Remove the task from expiring list;
-			if let Some(deadline) = task.deadline_block {
-				Self::remove_task_from_expiring(&task_id, deadline);
+		fn new_dispute(opener: &T::AccountId, task_id: &T::Hash, reason: BoundedVec<u8, T::MaxFeedbackLen>) -> Result<(), DispatchError> {
+
+			// Check if task exists, accepted tasks can still be disputed while their payout vests
+			let mut task = match Self::tasks(&task_id) {
+				Some(task) => task,
+				None if <VestingPayouts<T>>::contains_key(task_id) => return Self::new_vesting_dispute(opener, task_id, reason),
+				None => return Err(<Error<T>>::TaskNotExist.into()),
+			};
+
+			// Only tasks that have been rejected can be disputed, by one of their parties
+			ensure!(task.status != TaskStatus::Disputed, <Error<T>>::TaskDisputed);
+			ensure!(task.feedback.is_some(), <Error<T>>::NoPermissionToDispute);
+			ensure!(task.status == TaskStatus::InProgress || task.status == TaskStatus::Completed, <Error<T>>::NoPermissionToDispute);
+			let participants = Self::participants(&task);
+			ensure!(task.initiator == *opener || participants.contains(opener), <Error<T>>::NoPermissionToDispute);
+
+			let arbitrators = Self::draw_arbitrators(task_id, task.organization, &task.initiator, &participants)?;
+			Self::insert_dispute(opener, task_id, reason, Self::submissions(task_id), arbitrators, task.status.clone())?;
+
+			// A disputed task is not accepted at the end of its review period
+			if task.status == TaskStatus::Completed {
+				Self::remove_task_from_review(task_id, task.completed_at + T::ReviewPeriod::get());
 			}
 
+			Self::set_status(&mut task, TaskStatus::Disputed);
+			task.updated_at = <frame_system::Pallet<T>>::block_number();
 			<Tasks<T>>::insert(task_id, task);
 
-			// Assign task to volunteer
-			<TasksOwned<T>>::try_mutate(volunteer, |vec| {
-				vec.try_push(*task_id)
-			}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+			Ok(())
+		}
+
+		// Opens a dispute on the payout of an accepted task while it vests. Only the initiator can dispute it,
+		// as long as part of the payout has not vested yet.
+		fn new_vesting_dispute(opener: &T::AccountId, task_id: &T::Hash, reason: BoundedVec<u8, T::MaxFeedbackLen>) -> Result<(), DispatchError> {
+			let payout = Self::vesting_payouts(task_id).ok_or(<Error<T>>::VestingNotExist)?;
+			ensure!(!<Disputes<T>>::contains_key(task_id), <Error<T>>::TaskDisputed);
+			ensure!(payout.initiator == *opener, <Error<T>>::NoPermissionToDispute);
+			ensure!(<frame_system::Pallet<T>>::block_number() < payout.ends_at, <Error<T>>::NoPermissionToDispute);
+
+			let volunteers: Vec<T::AccountId> = payout.shares.iter().map(|share| share.volunteer.clone()).collect();
+			let arbitrators = Self::draw_arbitrators(task_id, payout.organization, &payout.initiator, &volunteers)?;
+			Self::insert_dispute(opener, task_id, reason, Default::default(), arbitrators, TaskStatus::Accepted)
+		}
+
+		// Reserves the dispute deposit from the opener, and stores the dispute until its voting period ends.
+		fn insert_dispute(
+			opener: &T::AccountId,
+			task_id: &T::Hash,
+			reason: BoundedVec<u8, T::MaxFeedbackLen>,
+			evidence: SubmissionsOf<T>,
+			arbitrators: BoundedVec<T::AccountId, T::ArbitratorsPerDispute>,
+			previous_status: TaskStatus,
+		) -> Result<(), DispatchError> {
+
+			// Reserve the dispute deposit
+			let deposit = T::DisputeDeposit::get();
+			<T as self::Config>::Currency::reserve(opener, deposit).map_err(|_| <Error<T>>::NotEnoughBalance)?;
+
+			let voting_ends_at = <frame_system::Pallet<T>>::block_number() + T::DisputeVotingPeriod::get();
+			<DisputesEndingPerBlock<T>>::try_mutate(voting_ends_at, |disputes| {
+				disputes.try_push(*task_id)
+			}).map_err(|_| <Error<T>>::DisputeLimitReached)?;
+
+			<Disputes<T>>::insert(task_id, Dispute::<T> {
+				opened_by: opener.clone(),
+				reason,
+				evidence,
+				deposit,
+				arbitrators,
+				votes: Default::default(),
+				previous_status,
+				opened_at: <frame_system::Pallet<T>>::block_number(),
+				voting_ends_at,
+			});
 
 			Ok(())
 		}
 
-		fn mark_finished(to: &T::AccountId, task_id: &T::Hash) -> Result<(), DispatchError> {
+		// Draws arbitrators at random from the task organization members, or from the arbitrator pool.
+		// Parties of the task are never drawn.
+		fn draw_arbitrators(
+			task_id: &T::Hash,
+			organization: Option<OrganizationIdOf<T>>,
+			initiator: &T::AccountId,
+			participants: &[T::AccountId],
+		) -> Result<BoundedVec<T::AccountId, T::ArbitratorsPerDispute>, DispatchError> {
+			let mut candidates = match organization {
+				Some(organization) => T::Organization::members(&organization),
+				None => Self::arbitrator_pool().into_inner(),
+			};
+			candidates.retain(|candidate| candidate != initiator && !participants.contains(candidate));
+			ensure!(!candidates.is_empty(), <Error<T>>::NotEnoughArbitrators);
+
+			let mut arbitrators: BoundedVec<T::AccountId, T::ArbitratorsPerDispute> = Default::default();
+			let mut nonce = 0u32;
+			while !candidates.is_empty() && (arbitrators.len() as u32) < T::ArbitratorsPerDispute::get() {
+				let index = Self::generate_random_number(task_id, nonce) as usize % candidates.len();
+				let _ = arbitrators.try_push(candidates.swap_remove(index));
+				nonce += 1;
+			}
 
-			// Check if task exists
-			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			Ok(arbitrators)
+		}
+
+		// Generating randomness
+		fn generate_random_number(task_id: &T::Hash, nonce: u32) -> u32 {
+			let (random_seed, _) = T::Randomness::random(&(T::PalletId::get(), task_id, nonce).encode());
+			<u32>::decode(&mut random_seed.as_ref()).expect("secure hashes should always be bigger than u32; qed")
+		}
+
+		// Adds the vote of an arbitrator. Returns the end of the voting period once every arbitrator has voted.
+		fn add_dispute_vote(arbitrator: &T::AccountId, task_id: &T::Hash, volunteer_share: Percent) -> Result<Option<T::BlockNumber>, DispatchError> {
+			<Disputes<T>>::try_mutate(task_id, |maybe_dispute| -> Result<Option<T::BlockNumber>, DispatchError> {
+				let dispute = maybe_dispute.as_mut().ok_or(<Error<T>>::DisputeNotExist)?;
+				ensure!(dispute.arbitrators.contains(arbitrator), <Error<T>>::NotAnArbitrator);
+				ensure!(!dispute.votes.iter().any(|(voter, _)| voter == arbitrator), <Error<T>>::AlreadyVoted);
+
+				dispute.votes.try_push((arbitrator.clone(), volunteer_share)).map_err(|_| <Error<T>>::AlreadyVoted)?;
+
+				if dispute.votes.len() == dispute.arbitrators.len() {
+					return Ok(Some(dispute.voting_ends_at));
+				}
+				Ok(None)
+			})
+		}
+
+		// Settles a dispute. The escrow is split by the average share the arbitrators awarded to the volunteers,
+		// and the task is removed. Without votes the dispute is dismissed and the task returns to its previous status.
+		#[transactional]
+		pub(crate) fn resolve_dispute(task_id: &T::Hash) -> DispatchResult {
+			let dispute = <Disputes<T>>::take(task_id).ok_or(<Error<T>>::DisputeNotExist)?;
+			let mut task = match Self::tasks(&task_id) {
+				Some(task) => task,
+				None if <VestingPayouts<T>>::contains_key(task_id) => return Self::resolve_vesting_dispute(task_id, dispute),
+				None => return Err(<Error<T>>::TaskNotExist.into()),
+			};
+
+			if dispute.votes.is_empty() {
+				<T as self::Config>::Currency::unreserve(&dispute.opened_by, dispute.deposit);
+
+				Self::set_status(&mut task, dispute.previous_status);
+				task.updated_at = <frame_system::Pallet<T>>::block_number();
+
+				// A completed task is reviewed again from the dismissal
+				if task.status == TaskStatus::Completed {
+					task.completed_at = task.updated_at;
+					Self::schedule_review(task_id, task.completed_at)?;
+				}
+				<Tasks<T>>::insert(task_id, task);
+
+				Self::deposit_event(Event::DisputeDismissed(*task_id));
+				return Ok(());
+			}
+
+			let volunteer_share = Self::dispute_verdict(&dispute);
+
+			// Split the escrow by the verdict
+			let to_volunteers = volunteer_share * Self::escrowed_balance(task_id);
+			Self::pay_volunteers(task_id, &task, to_volunteers)?;
+			let to_initiator = Self::escrowed_balance(task_id);
+			Self::release_escrow(task_id, &task.funder, task.asset, to_initiator)?;
+
+			Self::settle_dispute_deposit(&dispute, &task.initiator, volunteer_share);
+
+			// Volunteers who lose the dispute lose their bond to the initiator, the others get it back
+			if Self::verdict_against_volunteers(volunteer_share) {
+				for participant in Self::participants(&task) {
+					Self::slash_bond(task_id, &participant, &task.initiator);
+				}
+			}
+			Self::release_bonds(task_id);
+
+			// Remove the settled task from storage
+			let _ = Self::remove_task_from_owner(&task.initiator, task_id);
+			for participant in Self::participants(&task) {
+				let _ = Self::remove_task_from_owner(&participant, task_id);
+			}
+			Self::unindex_location(task_id, &task.location);
+			Self::unindex_task(&task);
+			<Tasks<T>>::remove(task_id);
+			<Milestones<T>>::remove(task_id);
+			<Applications<T>>::remove(task_id);
+			<Visibility<T>>::remove(task_id);
+			<Invitations<T>>::remove(task_id);
+			<Submissions<T>>::remove(task_id);
+			<VestingSchedules<T>>::remove(task_id);
+			Self::unlink_task(task_id, &task);
+
+			// Reduce task count
+			let new_count = Self::task_count().saturating_sub(1);
+			<TaskCount<T>>::put(new_count);
+
+			T::LifecycleHooks::on_removed(task_id)?;
+
+			Self::deposit_event(Event::DisputeResolved(*task_id, volunteer_share, to_volunteers, to_initiator));
+
+			Ok(())
+		}
+
+		// Settles a dispute on a vesting payout. Every volunteer is paid what had vested when the dispute was opened,
+		// and the share of the unvested amount awarded by the verdict. The rest is refunded to the funder, and the
+		// payout stops vesting. Without votes the dispute is dismissed and the payout keeps vesting.
+		fn resolve_vesting_dispute(task_id: &T::Hash, dispute: Dispute<T>) -> DispatchResult {
+			let payout = Self::vesting_payouts(task_id).ok_or(<Error<T>>::VestingNotExist)?;
+
+			if dispute.votes.is_empty() {
+				<T as self::Config>::Currency::unreserve(&dispute.opened_by, dispute.deposit);
+				Self::deposit_event(Event::DisputeDismissed(*task_id));
+				return Ok(());
+			}
+
+			let volunteer_share = Self::dispute_verdict(&dispute);
+
+			// Pay the volunteers what had vested and their share of the rest, and refund what remains.
+			// The minimum balance kept out of the shares is split like the rest, and paid with the last share.
+			let minimum = Self::escrowed_balance(task_id).saturating_sub(Self::owed_amount(&payout));
+			let mut to_volunteers: BalanceOf<T> = Zero::zero();
+			for (index, share) in payout.shares.iter().enumerate() {
+				let vested = Self::vested_amount(payout.starts_at, payout.ends_at, share.amount, dispute.opened_at);
+				let mut awarded = vested.saturating_add(volunteer_share * share.amount.saturating_sub(vested)).saturating_sub(share.claimed);
+				if index + 1 == payout.shares.len() {
+					awarded = awarded.saturating_add(volunteer_share * minimum);
+				}
+				Self::release_escrow(task_id, &share.volunteer, payout.asset, awarded)?;
+				to_volunteers = to_volunteers.saturating_add(awarded);
+			}
+			let to_initiator = Self::escrowed_balance(task_id);
+			Self::release_escrow(task_id, &payout.funder, payout.asset, to_initiator)?;
+
+			Self::settle_dispute_deposit(&dispute, &payout.initiator, volunteer_share);
+			<VestingPayouts<T>>::remove(task_id);
+
+			Self::deposit_event(Event::DisputeResolved(*task_id, volunteer_share, to_volunteers, to_initiator));
+
+			Ok(())
+		}
+
+		// The verdict of a dispute is the average share the arbitrators awarded to the volunteers.
+		fn dispute_verdict(dispute: &Dispute<T>) -> Percent {
+			let total: u32 = dispute.votes.iter().map(|(_, share)| share.deconstruct() as u32).sum();
+			Percent::from_percent((total / dispute.votes.len() as u32) as u8)
+		}
+
+		// The verdict goes against the volunteers when they are awarded less than half of the escrow,
+		// and against the initiator when they are awarded more. An even split goes against neither side.
+		fn verdict_against_volunteers(volunteer_share: Percent) -> bool {
+			volunteer_share < Percent::from_percent(50)
+		}
+
+		fn verdict_against_initiator(volunteer_share: Percent) -> bool {
+			volunteer_share > Percent::from_percent(50)
+		}
+
+		// Slashes the deposit if the verdict goes against the opener, returns it otherwise.
+		fn settle_dispute_deposit(dispute: &Dispute<T>, initiator: &T::AccountId, volunteer_share: Percent) {
+			let opener_lost = if dispute.opened_by == *initiator {
+				Self::verdict_against_initiator(volunteer_share)
+			} else {
+				Self::verdict_against_volunteers(volunteer_share)
+			};
+			if opener_lost {
+				let (imbalance, _) = <T as self::Config>::Currency::slash_reserved(&dispute.opened_by, dispute.deposit);
+				T::DisputeSlash::on_unbalanced(imbalance);
+			} else {
+				<T as self::Config>::Currency::unreserve(&dispute.opened_by, dispute.deposit);
+			}
+		}
+
+		// Returns the bond a volunteer reserves to start a task, given the part of the budget they are paid.
+		fn bond_amount(task: &Task<T>, share: BalanceOf<T>) -> BalanceOf<T> {
+			match task.bond {
+				Some(VolunteerBond::Amount(amount)) => amount,
+				Some(VolunteerBond::Percent(percent)) => percent * share,
+				None => Zero::zero(),
+			}
+		}
+
+		// Reserves the bond of a volunteer starting a task.
+		fn reserve_bond(volunteer: &T::AccountId, task_id: &T::Hash, amount: BalanceOf<T>) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(());
+			}
+			<T as self::Config>::Currency::reserve(volunteer, amount).map_err(|_| <Error<T>>::CannotReserveBond)?;
+			<Bonds<T>>::insert(task_id, volunteer, amount);
+			Ok(())
+		}
+
+		// Returns the bonds of every volunteer of a task.
+		fn release_bonds(task_id: &T::Hash) {
+			for (volunteer, amount) in <Bonds<T>>::drain_prefix(task_id) {
+				<T as self::Config>::Currency::unreserve(&volunteer, amount);
+			}
+		}
+
+		// Moves the bond of a volunteer to the initiator of the task.
+		fn slash_bond(task_id: &T::Hash, volunteer: &T::AccountId, initiator: &T::AccountId) {
+			if let Some(amount) = <Bonds<T>>::take(task_id, volunteer) {
+				let slashed = <T as self::Config>::Currency::repatriate_reserved(volunteer, initiator, amount, BalanceStatus::Free)
+					.map_or(Zero::zero(), |unslashed| amount.saturating_sub(unslashed));
+				Self::deposit_event(Event::BondSlashed(volunteer.clone(), *task_id, slashed));
+			}
+		}
+
+		fn remove_dispute_from_ending(task_id: &T::Hash, voting_ends_at: T::BlockNumber) {
+			<DisputesEndingPerBlock<T>>::mutate(voting_ends_at, |disputes| {
+				disputes.retain(|&h| h != *task_id);
+			});
+		}
 
-			// Check if task is in progress before closing
-			ensure!(TaskStatus::InProgress == task.status, <Error<T>>::NoPermissionToComplete);
+		/// Returns every volunteer working on a task.
+		pub fn participants(task: &Task<T>) -> Vec<T::AccountId> {
+			if task.slots.is_empty() {
+				return sp_std::vec![task.volunteer.clone()];
+			}
+			task.slots.iter().filter_map(|slot| slot.volunteer.clone()).collect()
+		}
 
-			// Check if the volunteer is the one who finished task
-			ensure!(to == &task.volunteer, <Error<T>>::NoPermissionToComplete);
+		/// Returns the part of the task budget that has been split into volunteer slots.
+		pub fn slots_budget(task: &Task<T>) -> BalanceOf<T> {
+			task.slots.iter().fold(Zero::zero(), |acc: BalanceOf<T>, slot| acc.saturating_add(slot.share))
+		}
 
-			// Remove task ownership from current signer
-			<TasksOwned<T>>::try_mutate(&to, |owned| {
+		fn remove_task_from_owner(owner: &T::AccountId, task_id: &T::Hash) -> DispatchResult {
+			<TasksOwned<T>>::try_mutate(owner, |owned| {
 				if let Some(index) = owned.iter().position(|&id| id == *task_id) {
 					owned.swap_remove(index);
 					return Ok(());
 				}
 				Err(())
-			}).map_err(|_| <Error<T>>::TaskNotExist)?;
+			}).map_err(|_| <Error<T>>::TaskNotExist.into())
+		}
 
-			// Set current owner to initiator
-			task.current_owner = task.initiator.clone();
-			task.status = TaskStatus::Completed;
-			task.completed_at = <frame_system::Pallet<T>>::block_number();
-			let task_initiator = task.initiator.clone();
+		fn add_task_to_owner(owner: &T::AccountId, task_id: &T::Hash) -> DispatchResult {
+			<TasksOwned<T>>::try_mutate(owner, |vec| {
+				vec.try_push(*task_id)
+			}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned.into())
+		}
 
-			// Insert into update task
-			<Tasks<T>>::insert(task_id, task);
+		/// Returns the part of the task budget that has already been paid out for accepted milestones.
+		pub fn paid_milestones_budget(task_id: &T::Hash) -> BalanceOf<T> {
+			Self::milestones(task_id).iter()
+				.filter(|m| m.status == MilestoneStatus::Accepted)
+				.fold(Zero::zero(), |acc: BalanceOf<T>, m| acc.saturating_add(m.budget))
+		}
 
-			// Assign task to new owner (original initiator)
-			<TasksOwned<T>>::try_mutate(task_initiator, |vec| {
-				vec.try_push(*task_id)
-			}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+		// Returns what the volunteers have already been paid for the accepted milestones of a task, net of the fees.
+		fn paid_milestones_payout(task: &Task<T>) -> BalanceOf<T> {
+			Self::milestones(&task.task_id).iter()
+				.filter(|m| m.status == MilestoneStatus::Accepted)
+				.fold(Zero::zero(), |acc: BalanceOf<T>, m| {
+					let (platform_fee, organization_cut) = Self::payout_fees(task, m.budget);
+					acc.saturating_add(m.budget.saturating_sub(platform_fee).saturating_sub(organization_cut))
+				})
+		}
+
+		// Stores the receipt of an accepted task, indexes it and schedules it for pruning.
+		fn archive_task(task: &Task<T>, amount: BalanceOf<T>) -> Result<(), DispatchError> {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let volunteers = Self::participants(task);
+
+			<PrunableReceiptsPerBlock<T>>::try_mutate(now + T::ReceiptLongevity::get(), |receipts| {
+				receipts.try_push(task.task_id)
+			}).map_err(|_| <Error<T>>::ReceiptLimitReached)?;
+
+			// Index the receipt, dropping the oldest receipts of full indexes
+			let mut accounts = volunteers.clone();
+			if !accounts.contains(&task.initiator) {
+				accounts.push(task.initiator.clone());
+			}
+			for account in accounts.iter() {
+				<ReceiptsByAccount<T>>::mutate(account, |receipts| {
+					if receipts.len() as u32 >= T::MaxReceiptsPerAccount::get() {
+						receipts.remove(0);
+					}
+					let _ = receipts.try_push(task.task_id);
+				});
+			}
+			if let Some(organization) = task.organization {
+				<ReceiptsByOrganization<T>>::mutate(organization, |receipts| {
+					if receipts.len() as u32 >= T::MaxReceiptsPerOrganization::get() {
+						receipts.remove(0);
+					}
+					let _ = receipts.try_push(task.task_id);
+				});
+			}
+
+			<Receipts<T>>::insert(task.task_id, TaskReceipt::<T> {
+				task_id: task.task_id,
+				initiator: task.initiator.clone(),
+				volunteers: volunteers.try_into().unwrap_or_default(),
+				amount,
+				asset: task.asset,
+				organization: task.organization,
+				accepted_at: now,
+				title_hash: T::Hashing::hash(&task.title),
+			});
 
 			Ok(())
 		}
 
-		// Internal helper function, checks Must be called before calling this function.
-		fn accept_completed_task(task_initiator: &T::AccountId, task: &mut Task<T>, task_id: &T::Hash) -> Result<(), DispatchError> {
+		// Removes a receipt together with its index entries.
+		pub(crate) fn prune_receipt(task_id: &T::Hash) {
+			if let Some(receipt) = <Receipts<T>>::take(task_id) {
+				for account in receipt.volunteers.iter().chain(sp_std::iter::once(&receipt.initiator)) {
+					<ReceiptsByAccount<T>>::mutate(account, |receipts| receipts.retain(|h| h != task_id));
+				}
+				if let Some(organization) = receipt.organization {
+					<ReceiptsByOrganization<T>>::mutate(organization, |receipts| receipts.retain(|h| h != task_id));
+				}
+			}
+		}
 
-			// Remove from ownership
-			<TasksOwned<T>>::try_mutate(&task_initiator, |owned| {
-				if let Some(index) = owned.iter().position(|&id| id == *task_id) {
-					owned.swap_remove(index);
-					return Ok(());
+		// Stores the rating one party of an accepted task gave the other, and indexes it by the reviewee.
+		fn new_rating(reviewer: &T::AccountId, task_id: &T::Hash, reviewee: &T::AccountId, rating: u8,
+			review: BoundedVec<u8, T::MaxReviewLen>
+		) -> Result<(), DispatchError> {
+			let receipt = Self::receipts(task_id).ok_or(<Error<T>>::ReceiptNotExist)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(now <= receipt.accepted_at + T::RatingPeriod::get(), <Error<T>>::RatingPeriodEnded);
+
+			// The initiator rates the volunteers, and the volunteers rate the initiator
+			let rates_initiator = receipt.volunteers.contains(reviewer) && *reviewee == receipt.initiator;
+			let rates_volunteer = *reviewer == receipt.initiator && receipt.volunteers.contains(reviewee);
+			ensure!(reviewer != reviewee && (rates_initiator || rates_volunteer), <Error<T>>::NoPermissionToRate);
+			ensure!((1..=5).contains(&rating), <Error<T>>::InvalidRating);
+
+			let key = (reviewer.clone(), reviewee.clone());
+			ensure!(!<Ratings<T>>::contains_key(task_id, &key), <Error<T>>::AlreadyRated);
+
+			// Let other pallets react, the runtime aggregates the rating into the profile of the reviewee.
+			T::LifecycleHooks::on_rated(task_id, reviewer, reviewee, rating)?;
+
+			// Index the rating, dropping the oldest rating of a full index
+			<RatingsByAccount<T>>::mutate(reviewee, |ratings| {
+				if ratings.len() as u32 >= T::MaxRatingsPerAccount::get() {
+					let (old_task_id, old_reviewer) = ratings.remove(0);
+					<Ratings<T>>::remove(old_task_id, (old_reviewer, reviewee.clone()));
 				}
-				Err(())
-			}).map_err(|_| <Error<T>>::TaskNotExist)?;
+				let _ = ratings.try_push((*task_id, reviewer.clone()));
+			});
+
+			<Ratings<T>>::insert(task_id, key, Rating::<T> {
+				task_id: *task_id,
+				reviewer: reviewer.clone(),
+				reviewee: reviewee.clone(),
+				rating,
+				review,
+				rated_at: now,
+			});
 
-			// Update task state
-			task.status = TaskStatus::Accepted;
-			<Tasks<T>>::insert(task_id, task);
+			Ok(())
+		}
 
-			// Reward reputation points to profiles who created/completed a task
-			Self::handle_reputation(task_id)?;
+		/// Returns the ratings an account received, oldest first.
+		pub fn account_ratings(account: &T::AccountId) -> Vec<Rating<T>> {
+			Self::ratings_by_account(account).iter()
+				.filter_map(|(task_id, reviewer)| Self::ratings(task_id, (reviewer.clone(), account.clone())))
+				.collect()
+		}
 
-			// remove task once accepted
-			<Tasks<T>>::remove(task_id);
+		/// Returns the receipts of the tasks an account was initiator or volunteer of, oldest first.
+		pub fn account_receipts(account: &T::AccountId) -> Vec<TaskReceipt<T>> {
+			Self::receipts_by_account(account).iter().filter_map(|task_id| Self::receipts(task_id)).collect()
+		}
 
-			// Reduce task count
-			let new_count = Self::task_count().saturating_sub(1);
-			<TaskCount<T>>::put(new_count);
+		/// Returns the receipts of the tasks of an organization, oldest first.
+		pub fn organization_receipts(organization: &OrganizationIdOf<T>) -> Vec<TaskReceipt<T>> {
+			Self::receipts_by_organization(organization).iter().filter_map(|task_id| Self::receipts(task_id)).collect()
+		}
 
-			Ok(())
+		/// Returns the descendants of a task, breadth first, up to `MaxTasksWalked` of them.
+		pub fn subtree(task_id: &T::Hash) -> Vec<T::Hash> {
+			let max = T::MaxTasksWalked::get() as usize;
+			let mut subtree = Self::subtasks(task_id).into_inner();
+			let mut index = 0;
+			while index < subtree.len() && subtree.len() < max {
+				let subtasks = Self::subtasks(subtree[index]);
+				subtree.extend(subtasks.into_iter());
+				index += 1;
+			}
+			subtree.truncate(max);
+			subtree
 		}
 
-		// Task can be rejected by the creator, which places the task back into progress.
-		fn reject_completed_task(task_initiator: &T::AccountId, task_id: &T::Hash, feedback: BoundedVec<u8, T::MaxFeedbackLen>) -> Result<(), DispatchError> {
+		/// Returns a created task of the initiator, whose visibility and invitations the initiator can change.
+		fn created_task_of(initiator: &T::AccountId, task_id: &T::Hash) -> Result<Task<T>, DispatchError> {
+			let task = Self::tasks(task_id).ok_or(<Error<T>>::TaskNotExist)?;
+			ensure!(task.initiator == *initiator, <Error<T>>::OnlyInitiatorUpdatesTask);
+			ensure!(task.status == TaskStatus::Created, <Error<T>>::NoPermissionToInvite);
+			Ok(task)
+		}
 
-			// Check if task exists
-			let mut task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+		fn remove_invitation(task_id: &T::Hash, invitee: &T::AccountId) -> DispatchResult {
+			<Invitations<T>>::try_mutate(task_id, |invitations| {
+				let index = invitations.iter().position(|account| account == invitee).ok_or(<Error<T>>::NotInvited)?;
+				invitations.remove(index);
+				Ok(())
+			})
+		}
 
-			// Check if the owner is the one who created task
-			ensure!(Self::is_task_initiator(task_id, task_initiator)?, <Error<T>>::OnlyInitiatorAcceptsTask);
+		/// Ensures the visibility of a task allows the account to volunteer for it.
+		fn ensure_can_volunteer(volunteer: &T::AccountId, task_id: &T::Hash, task: &Task<T>) -> DispatchResult {
+			match Self::visibility(task_id) {
+				TaskVisibility::Public => Ok(()),
+				TaskVisibility::InviteOnly => {
+					ensure!(Self::invitations(task_id).contains(volunteer), <Error<T>>::NotInvited);
+					Ok(())
+				},
+				TaskVisibility::OrganizationOnly => {
+					let organization = task.organization.as_ref().ok_or(<Error<T>>::TaskHasNoOrganization)?;
+					ensure!(T::Organization::members(organization).contains(volunteer), <Error<T>>::NotOrganizationMember);
+					Ok(())
+				},
+			}
+		}
 
-			// Check if task is Completed before rejecting it
-			ensure!(TaskStatus::Completed == task.status, <Error<T>>::OnlyCompletedTaskAreRejected);
+		/// Returns true while a task is waiting for one of its dependencies to be accepted.
+		pub fn is_blocked(task_id: &T::Hash) -> bool {
+			!Self::dependencies(task_id).is_empty()
+		}
 
-			// Remove from ownership of initiator
-			<TasksOwned<T>>::try_mutate(&task_initiator, |owned| {
-				if let Some(index) = owned.iter().position(|&id| id == *task_id) {
-					owned.swap_remove(index);
-					return Ok(());
+		/// Returns the tasks with the given status, in the storage order of their index.
+		pub fn tasks_by_status(status: TaskStatus, cursor: Option<T::Hash>, limit: u32) -> TaskPage<T> {
+			let task_ids = match cursor {
+				Some(cursor) => <TasksByStatus<T>>::iter_key_prefix_from(&status, <TasksByStatus<T>>::hashed_key_for(&status, cursor)),
+				None => <TasksByStatus<T>>::iter_key_prefix(&status),
+			};
+			Self::paginate(task_ids.filter_map(Self::tasks), limit)
+		}
+
+		/// Returns the tasks currently owned by an account, in the order they were taken.
+		/// A cursor that is no longer owned by the account restarts the query from the first task.
+		pub fn tasks_by_owner(owner: &T::AccountId, cursor: Option<T::Hash>, limit: u32) -> TaskPage<T> {
+			let owned = Self::tasks_owned(owner);
+			let start = cursor.and_then(|cursor| owned.iter().position(|task_id| *task_id == cursor)).map_or(0, |index| index + 1);
+			Self::paginate(owned.into_iter().skip(start).filter_map(Self::tasks), limit)
+		}
+
+		/// Returns the tasks of an organization, in the storage order of their index.
+		pub fn tasks_by_organization(organization: OrganizationIdOf<T>, cursor: Option<T::Hash>, limit: u32) -> TaskPage<T> {
+			let task_ids = match cursor {
+				Some(cursor) => <TasksByOrganization<T>>::iter_key_prefix_from(organization, <TasksByOrganization<T>>::hashed_key_for(organization, cursor)),
+				None => <TasksByOrganization<T>>::iter_key_prefix(organization),
+			};
+			Self::paginate(task_ids.filter_map(Self::tasks), limit)
+		}
+
+		/// Returns the tasks whose keywords contain the given keyword, scanning at most `MAX_TASKS_SCANNED` tasks.
+		/// A scan that stops early returns the last task it scanned as the cursor, with the matches found so far.
+		pub fn tasks_by_keyword(keyword: &[u8], cursor: Option<T::Hash>, limit: u32) -> TaskPage<T> {
+			Self::paginate_scan(Self::iter_tasks_from(cursor).map(|task| {
+				let matches = !keyword.is_empty() && task.keywords.windows(keyword.len()).any(|window| window == keyword);
+				(task.task_id, Some(task).filter(|_| matches))
+			}), limit, false)
+		}
+
+		/// Returns the created tasks whose deadline falls before the given block, walking the blocks they expire on
+		/// upwards from the oldest block not processed yet, or from the block of the cursor. At most
+		/// `MAX_EXPIRY_BLOCKS_WALKED` blocks and `MAX_TASKS_SCANNED` tasks are walked, a walk that stops early returns
+		/// the last task it scanned as the cursor. A cursor that is no longer expiring restarts the query from the first task.
+		pub fn tasks_expiring_before(block: T::BlockNumber, cursor: Option<T::Hash>, limit: u32) -> TaskPage<T> {
+			// Resume with the tasks after the cursor on its expiring block
+			let resumed = cursor.and_then(|cursor| {
+				let deadline_block = Self::tasks(cursor)?.deadline_block?;
+				let index = ExpiringTasksPerBlock::<T>::get(deadline_block).iter().position(|task_id| *task_id == cursor)?;
+				Some((deadline_block, index + 1))
+			});
+			let (start, skipped) = resumed.unwrap_or_else(|| {
+				(Self::next_expiring_block().unwrap_or_else(<frame_system::Pallet<T>>::block_number), 0)
+			});
+			let end = block.min(start.saturating_add(MAX_EXPIRY_BLOCKS_WALKED.into()));
+
+			let expiring = (0..MAX_EXPIRY_BLOCKS_WALKED)
+				.map(|offset| start.saturating_add(offset.into()))
+				.take_while(|deadline_block| *deadline_block < end)
+				.flat_map(|deadline_block| {
+					ExpiringTasksPerBlock::<T>::get(deadline_block).into_iter().map(move |task_id| (deadline_block, task_id))
+				})
+				.skip(skipped);
+			// Tasks that were started or moved to another block since are left out
+			Self::paginate_scan(expiring.map(|(deadline_block, task_id)| {
+				(task_id, Self::tasks(task_id).filter(|task| task.status == TaskStatus::Created && task.deadline_block == Some(deadline_block)))
+			}), limit, end < block)
+		}
+
+		/// Returns the created tasks located within the box between two locations, borders included.
+		/// Both corners have to be valid NAD coordinates, the first one being the lower one on both axes.
+		pub fn tasks_in_area(min: NadLocation, max: NadLocation, cursor: Option<T::Hash>, limit: u32) -> TaskPage<T> {
+			let corners = (Self::nad_position(&min.0), Self::nad_position(&min.1), Self::nad_position(&max.0), Self::nad_position(&max.1));
+			let (min_x, min_y, max_x, max_y) = match corners {
+				(Some(min_x), Some(min_y), Some(max_x), Some(max_y)) => (min_x, min_y, max_x, max_y),
+				_ => return (Vec::new(), None),
+			};
+			let (min_cell, max_cell) = (Self::cell_of(min_x, min_y), Self::cell_of(max_x, max_y));
+			let in_box = |cell: &GridCell| min_cell.0 <= cell.0 && cell.0 <= max_cell.0 && min_cell.1 <= cell.1 && cell.1 <= max_cell.1;
+
+			// Large boxes are searched through the cells that hold tasks rather than cell by cell
+			let search_width = 2 * MAX_SEARCH_RADIUS as u32 + 1;
+			let box_cells = (max_cell.0 as u32 + 1).saturating_sub(min_cell.0 as u32) * (max_cell.1 as u32 + 1).saturating_sub(min_cell.1 as u32);
+			let cells: Vec<GridCell> = if box_cells <= search_width * search_width {
+				(min_cell.1..=max_cell.1).flat_map(|row| (min_cell.0..=max_cell.0).map(move |column| (column, row))).collect()
+			} else {
+				<TasksByCell<T>>::iter_keys().filter(|cell| in_box(cell)).collect()
+			};
+
+			Self::tasks_in_cells(cells, cursor, limit, |location| {
+				match (Self::nad_position(&location.0), Self::nad_position(&location.1)) {
+					(Some(x), Some(y)) => min_x <= x && x <= max_x && min_y <= y && y <= max_y,
+					_ => false,
 				}
-				Err(())
-			}).map_err(|_| <Error<T>>::TaskNotExist)?;
+			})
+		}
 
-			// Set current owner back to volunteer
-			task.current_owner = task.volunteer.clone();
-			task.status = TaskStatus::InProgress;
-			task.feedback = Some(feedback);
-			let task_volunteer = task.volunteer.clone();
+		/// Returns the created tasks located within a radius, in grid cells, around the location of a profile.
+		/// The radius is capped at `MAX_SEARCH_RADIUS`. Cells wrap around along the x axis.
+		pub fn tasks_near(account: &T::AccountId, radius: u16, cursor: Option<T::Hash>, limit: u32) -> TaskPage<T> {
+			let center = match T::Profiles::location(account).as_ref().and_then(Self::grid_cell) {
+				Some(center) => center,
+				None => return (Vec::new(), None),
+			};
 
-			// Insert task
-			<Tasks<T>>::insert(task_id, task);
+			let radius = radius.min(MAX_SEARCH_RADIUS) as i32;
+			let mut cells = Vec::new();
+			for dy in -radius..=radius {
+				let row = center.1 as i32 + dy;
+				if row < 0 || row >= GRID_SIZE as i32 {
+					continue;
+				}
+				for dx in -radius..=radius {
+					if dx * dx + dy * dy <= radius * radius {
+						let column = (center.0 as i32 + dx).rem_euclid(GRID_SIZE as i32);
+						cells.push((column as u16, row as u16));
+					}
+				}
+			}
 
-			// Assign task to new owner (original volunteer)
-			<TasksOwned<T>>::try_mutate(task_volunteer, |vec| {
-				vec.try_push(*task_id)
-			}).map_err(|_| <Error<T>>::ExceedMaxTasksOwned)?;
+			Self::tasks_in_cells(cells, cursor, limit, |_| true)
+		}
+
+		/// Returns the grid cell of a location, or None if it is not made of valid NAD coordinates.
+		pub fn grid_cell(location: &NadLocation) -> Option<GridCell> {
+			Some(Self::cell_of(Self::nad_position(&location.0)?, Self::nad_position(&location.1)?))
+		}
+
+		// Returns the position of a NAD coordinate along its axis, or None if it has an invalid character.
+		fn nad_position(coordinate: &[u8; 5]) -> Option<u32> {
+			coordinate.iter().try_fold(0u32, |position, character| {
+				let value = NAD_CHARACTERS.iter().position(|c| *c == character.to_ascii_uppercase())?;
+				Some(position * NAD_CHARACTERS.len() as u32 + value as u32)
+			})
+		}
+
+		// Returns the grid cell of two NAD positions, dropping the last three characters of both.
+		fn cell_of(x: u32, y: u32) -> GridCell {
+			let cell_span = (NAD_CHARACTERS.len() as u32).pow(3);
+			((x / cell_span) as u16, (y / cell_span) as u16)
+		}
 
+		// Adds a task to the grid cell of its location. Tasks without a valid location are not indexed.
+		pub(crate) fn index_location(task_id: &T::Hash, location: &Option<NadLocation>) -> Result<(), DispatchError> {
+			if let Some(cell) = location.as_ref().and_then(Self::grid_cell) {
+				<TasksByCell<T>>::try_mutate(cell, |tasks| {
+					tasks.try_push(*task_id)
+				}).map_err(|_| <Error<T>>::CellLimitReached)?;
+			}
 			Ok(())
 		}
 
-		fn delete_task(task_initiator: &T::AccountId, task_id: &T::Hash) -> Result<(), DispatchError> {
+		// Removes a task from the grid cell of its location.
+		fn unindex_location(task_id: &T::Hash, location: &Option<NadLocation>) {
+			if let Some(cell) = location.as_ref().and_then(Self::grid_cell) {
+				<TasksByCell<T>>::mutate(cell, |tasks| tasks.retain(|h| h != task_id));
+			}
+		}
 
-			// Check if task exists
-			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+		// Adds a task to the index of its status, and to the index of its organization if it has one.
+		fn index_task(task: &Task<T>) {
+			<TasksByStatus<T>>::insert(&task.status, task.task_id, ());
+			if let Some(organization) = task.organization {
+				<TasksByOrganization<T>>::insert(organization, task.task_id, ());
+			}
+		}
 
-			// Check if the owner is the one who created task
-			ensure!(Self::is_task_initiator(task_id, task_initiator)?, <Error<T>>::NoPermissionToRemove);
+		// Removes a task from the indexes of its status and organization.
+		fn unindex_task(task: &Task<T>) {
+			<TasksByStatus<T>>::remove(&task.status, task.task_id);
+			if let Some(organization) = task.organization {
+				<TasksByOrganization<T>>::remove(organization, task.task_id);
+			}
+		}
 
-			// Ensure that only Created Task can be deleted
-			ensure!(TaskStatus::Created == task.status || TaskStatus::Expired == task.status, <Error<T>>::NoPermissionToRemove);
+		// Moves a task to a new status, and to the index of that status.
+		pub(crate) fn set_status(task: &mut Task<T>, status: TaskStatus) {
+			<TasksByStatus<T>>::remove(&task.status, task.task_id);
+			<TasksByStatus<T>>::insert(&status, task.task_id, ());
+			task.status = status;
+		}
 
-			// remove task from storage
-			<Tasks<T>>::remove(task_id);
+		// Collects a page of the created tasks located in the given cells whose location passes the filter.
+		fn tasks_in_cells(cells: Vec<GridCell>, cursor: Option<T::Hash>, limit: u32, filter: impl Fn(&NadLocation) -> bool) -> TaskPage<T> {
+			let located: Vec<T::Hash> = cells.into_iter().flat_map(|cell| Self::tasks_by_cell(cell).into_inner()).collect();
+			let start = cursor.and_then(|cursor| located.iter().position(|task_id| *task_id == cursor)).map_or(0, |index| index + 1);
+			Self::paginate(located.into_iter().skip(start).filter_map(Self::tasks).filter(|task| {
+				task.status == TaskStatus::Created && task.location.as_ref().map_or(false, &filter)
+			}), limit)
+		}
 
-			// Unreserve balance amount from task creator
-			<T as self::Config>::Currency::unreserve(task_initiator, task.budget);
+		// Iterates over all tasks in storage order, starting after the cursor.
+		fn iter_tasks_from(cursor: Option<T::Hash>) -> impl Iterator<Item = Task<T>> {
+			let tasks = match cursor {
+				Some(cursor) => <Tasks<T>>::iter_from(<Tasks<T>>::hashed_key_for(cursor)),
+				None => <Tasks<T>>::iter(),
+			};
+			tasks.map(|(_, task)| task)
+		}
 
-			// Reduce task count
-			let new_count = Self::task_count().saturating_sub(1);
-			<TaskCount<T>>::put(new_count);
+		// Collects a page of tasks. The cursor is only returned when the page is full.
+		fn paginate(tasks: impl Iterator<Item = Task<T>>, limit: u32) -> TaskPage<T> {
+			let limit = limit.min(MAX_TASKS_PER_PAGE);
+			let page: Vec<Task<T>> = tasks.take(limit as usize).collect();
+			let cursor = if limit > 0 && page.len() as u32 == limit { page.last().map(|task| task.task_id) } else { None };
+			(page, cursor)
+		}
 
-			Ok(())
+		// Collects a page of the matching tasks out of the scanned ones, of which at most `MAX_TASKS_SCANNED` are scanned.
+		// The cursor is the last task scanned, which may not be on the page, when the page is full or the scan stopped
+		// early, either at the scan limit or because the scanned tasks are `truncated` before the end of the query.
+		fn paginate_scan(scanned: impl Iterator<Item = (T::Hash, Option<Task<T>>)>, limit: u32, truncated: bool) -> TaskPage<T> {
+			let limit = limit.min(MAX_TASKS_PER_PAGE);
+			let mut page = Vec::new();
+			let mut last_scanned = None;
+			for (count, (task_id, task)) in scanned.enumerate() {
+				if page.len() as u32 == limit || count as u32 == MAX_TASKS_SCANNED {
+					return (page, last_scanned);
+				}
+				last_scanned = Some(task_id);
+				page.extend(task);
+			}
+			(page, last_scanned.filter(|_| truncated))
+		}
+
+		/// Returns the part of the task budget that has been split into milestones.
+		pub fn milestones_budget(task_id: &T::Hash) -> BalanceOf<T> {
+			// Expired milestones have been refunded out of the task budget.
+			Self::milestones(task_id).iter()
+				.filter(|m| m.status != MilestoneStatus::Expired)
+				.fold(Zero::zero(), |acc: BalanceOf<T>, m| acc.saturating_add(m.budget))
 		}
 
 		// Function to check if the current signer is the task_initiator
@@ -900,26 +4528,85 @@ pub mod pallet {
 		}
 
 		// Function that generates escrow account based on TaskID
-		#[allow(dead_code)] // Used in test only
-		pub(crate) fn account_id(task_id: &T::Hash) -> T::AccountId {
+		pub fn account_id(task_id: &T::Hash) -> T::AccountId {
 			T::PalletId::get().into_sub_account_truncating(task_id)
 		}
 
-		// Handles reputation update for profiles
-		fn handle_reputation(task_id: &T::Hash) -> Result<(), DispatchError> {
+		/// Returns the budget currently held in the escrow account of a task.
+		/// Only what the pallet escrowed counts, funds sent to the escrow account by anyone else are ignored.
+		pub fn escrowed_balance(task_id: &T::Hash) -> BalanceOf<T> {
+			Self::escrows(task_id)
+		}
 
-			// Check if task exists
-			let task = Self::tasks(&task_id).ok_or(<Error<T>>::TaskNotExist)?;
+		// Returns the minimum balance an account can hold in the native currency or in an asset.
+		fn minimum_balance(asset: Option<T::AssetId>) -> BalanceOf<T> {
+			match asset {
+				Some(asset) => <T::Assets as fungibles::Inspect<T::AccountId>>::minimum_balance(asset),
+				None => <T as self::Config>::Currency::minimum_balance(),
+			}
+		}
 
-			// Ensure that reputation is added only when task is in status Accepted
-			if task.status == TaskStatus::Accepted {
-				pallet_profile::Pallet::<T>::add_reputation(&task.initiator)?;
-				pallet_profile::Pallet::<T>::add_reputation(&task.volunteer)?;
+		// Returns the free balance of an account in the native currency or in an asset.
+		fn free_balance(who: &T::AccountId, asset: Option<T::AssetId>) -> BalanceOf<T> {
+			match asset {
+				Some(asset) => <T::Assets as fungibles::Inspect<T::AccountId>>::balance(asset, who),
+				None => <T as self::Config>::Currency::free_balance(who),
 			}
+		}
+
+		// The funder of a task, or whoever can spend from the organization account funding it, can add to its budget.
+		fn can_fund(who: &T::AccountId, task: &Task<T>) -> bool {
+			task.funder == *who || task.organization.map_or(false, |organization|
+				T::Organization::account(&organization) == task.funder && T::Organization::can_spend(&organization, who))
+		}
+
+		// Moves funds from an account into the escrow account of a task.
+		fn fund_escrow(from: &T::AccountId, task_id: &T::Hash, asset: Option<T::AssetId>, amount: BalanceOf<T>) -> DispatchResult {
+			Self::transfer_funds(from, &Self::account_id(task_id), asset, amount, ExistenceRequirement::KeepAlive)?;
+			<Escrows<T>>::mutate(task_id, |escrowed| *escrowed = escrowed.saturating_add(amount));
+			Ok(())
+		}
+
+		// Pays funds out of the escrow account of a task.
+		fn release_escrow(task_id: &T::Hash, to: &T::AccountId, asset: Option<T::AssetId>, amount: BalanceOf<T>) -> DispatchResult {
+			Self::withdraw_escrow(task_id, amount, |escrow, existence| Self::transfer_funds(escrow, to, asset, amount, existence))
+		}
+
+		// Moves funds between the escrow accounts of two tasks.
+		fn transfer_escrow(from_task: &T::Hash, to_task: &T::Hash, asset: Option<T::AssetId>, amount: BalanceOf<T>) -> DispatchResult {
+			Self::release_escrow(from_task, &Self::account_id(to_task), asset, amount)?;
+			<Escrows<T>>::mutate(to_task, |escrowed| *escrowed = escrowed.saturating_add(amount));
+			Ok(())
+		}
 
+		// Takes an amount out of the funds escrowed for a task with `withdraw`. The escrow account is kept alive
+		// while part of the escrowed funds remains, and can only be reaped by paying out the last of them.
+		fn withdraw_escrow<F>(task_id: &T::Hash, amount: BalanceOf<T>, withdraw: F) -> DispatchResult
+			where F: FnOnce(&T::AccountId, ExistenceRequirement) -> DispatchResult
+		{
+			let remaining = Self::escrows(task_id).checked_sub(&amount).ok_or(<Error<T>>::InsufficientEscrow)?;
+			let existence = if remaining.is_zero() { ExistenceRequirement::AllowDeath } else { ExistenceRequirement::KeepAlive };
+			withdraw(&Self::account_id(task_id), existence)?;
+
+			if remaining.is_zero() {
+				<Escrows<T>>::remove(task_id);
+			} else {
+				<Escrows<T>>::insert(task_id, remaining);
+			}
 			Ok(())
 		}
 
+		// Moves funds of the native currency or of an asset between two accounts.
+		fn transfer_funds(from: &T::AccountId, to: &T::AccountId, asset: Option<T::AssetId>, amount: BalanceOf<T>, existence: ExistenceRequirement) -> DispatchResult {
+			match asset {
+				Some(asset) => {
+					let keep_alive = existence == ExistenceRequirement::KeepAlive;
+					<T::Assets as fungibles::Transfer<T::AccountId>>::transfer(asset, from, to, amount, keep_alive).map(|_| ())
+				},
+				None => <T as self::Config>::Currency::transfer(from, to, amount, existence),
+			}
+		}
+
 		/// Replaces the old task deadline with the new one to track expired tasks.
 		/// If you have no old_task_deadline e.g the state change Status == InProgress to Status == Created
 		/// Or for creating new tasks, then, old_task_deadline == None.  
@@ -938,6 +4625,18 @@ pub mod pallet {
 			Ok(())
 		}
 
+		fn schedule_review(task_id: &T::Hash, completed_at: T::BlockNumber) -> DispatchResult {
+			<ReviewsEndingPerBlock<T>>::try_mutate(completed_at + T::ReviewPeriod::get(), |reviews| {
+				reviews.try_push(*task_id)
+			}).map_err(|_| <Error<T>>::ReviewLimitReached.into())
+		}
+
+		fn remove_task_from_review(task_id: &T::Hash, review_ends_at: T::BlockNumber) {
+			<ReviewsEndingPerBlock<T>>::mutate(review_ends_at, |reviews| {
+				reviews.retain(|&h| h != *task_id);
+			});
+		}
+
 		fn remove_task_from_expiring(task_id: &T::Hash, deadline_block: T::BlockNumber) {
 			let expiring_tasks: BoundedVec<T::Hash, MaximumTasksPerBlock> = ExpiringTasksPerBlock::<T>::take(deadline_block)
 				.into_iter()
@@ -948,11 +4647,352 @@ pub mod pallet {
 			ExpiringTasksPerBlock::<T>::insert(deadline_block, expiring_tasks);
 		}
 
-		/// Given a unix duration calculate the amount of blocks required to reach that time.
-		fn get_deadline_block(deadline: u64) -> T::BlockNumber {
-			let deadline_duration = Duration::from_millis(deadline.saturated_into::<u64>());
-			let blocks_till_deadline: T::BlockNumber = ((((deadline_duration - T::Time::now()).as_millis() / T::MillisecondsPerBlock::get() as u128)) as u32).into();
-			blocks_till_deadline + <frame_system::Pallet<T>>::block_number()
+		/// Expires the tasks that reached their deadline, then removes the dead tasks, oldest block first,
+		/// within the weight limit. The tasks that do not fit are carried over to the next call.
+		fn process_expiry(n: T::BlockNumber, limit: Weight) -> Weight {
+			let mut weight = Self::expire_tasks(n, limit);
+			weight = weight.saturating_add(Self::remove_dead_tasks(n, limit.saturating_sub(weight)));
+			weight.saturating_add(Self::end_milestones(n, limit.saturating_sub(weight)))
+		}
+
+		/// Processes the expiring milestones of the blocks up to `n`, from the oldest block not processed yet.
+		pub(crate) fn end_milestones(n: T::BlockNumber, limit: Weight) -> Weight {
+			Self::process_queue::<ExpiringMilestonesPerBlock<T>, NextMilestoneExpiryBlock<T>, _>(n, limit, T::WeightInfo::expire_milestones(), |task_id| {
+				Self::expire_milestones(n, task_id)
+			})
+		}
+
+		/// Expires the milestones of a task that were not submitted before their deadline, and refunds their budget.
+		/// The milestones whose deadline has not been reached yet are moved to a later block. Returns false when they
+		/// can't be scheduled again, because too many tasks have milestones expiring on that block.
+		pub(crate) fn expire_milestones(n: T::BlockNumber, task_id: &T::Hash) -> bool {
+			// Milestones of completed or settled tasks are paid out with the task.
+			let task = match Self::tasks(task_id) {
+				Some(task) if task.status == TaskStatus::Created || task.status == TaskStatus::InProgress => task,
+				_ => return true,
+			};
+
+			let now = T::Time::now().as_millis().saturated_into::<u64>();
+			let mut milestones = Self::milestones(task_id);
+			let mut expired: Vec<(MilestoneIndex, BalanceOf<T>)> = Vec::new();
+			let mut next_deadline: Option<u64> = None;
+			for (index, milestone) in milestones.iter_mut().enumerate().filter(|(_, m)| m.status == MilestoneStatus::Created) {
+				if milestone.deadline <= now {
+					milestone.status = MilestoneStatus::Expired;
+					milestone.updated_at = n;
+					expired.push((index as MilestoneIndex, milestone.budget));
+				} else {
+					next_deadline = Some(next_deadline.map_or(milestone.deadline, |deadline| deadline.min(milestone.deadline)));
+				}
+			}
+
+			// Deadlines that have not been reached yet, because blocks were built faster than expected, are moved to a later block.
+			if let Some(deadline) = next_deadline {
+				if Self::schedule_milestone_expiry(task_id, Self::block_after(deadline - now)).is_err() {
+					return false;
+				}
+			}
+			if expired.is_empty() {
+				return true;
+			}
+
+			let refund = expired.iter().fold(Zero::zero(), |acc: BalanceOf<T>, (_, budget)| acc.saturating_add(*budget));
+			match Self::refund_milestones(task_id, task, milestones, refund) {
+				Ok(()) => for (index, budget) in expired {
+					Self::deposit_event(Event::MilestoneExpired(*task_id, index, budget));
+				},
+				// Milestones that can't be submitted anymore are paid out with the task.
+				Err(error) => Self::deposit_event(Event::MilestoneRefundFailed(*task_id, error)),
+			}
+			true
+		}
+
+		// Refunds the budget of expired milestones to the funder, out of the task budget.
+		#[transactional]
+		fn refund_milestones(task_id: &T::Hash, mut task: Task<T>, milestones: BoundedVec<Milestone<T>, T::MaxMilestonesPerTask>,
+			refund: BalanceOf<T>) -> DispatchResult {
+			Self::release_escrow(task_id, &task.funder, task.asset, refund)?;
+			task.budget = task.budget.saturating_sub(refund);
+			<Tasks<T>>::insert(task_id, task);
+			<Milestones<T>>::insert(task_id, milestones);
+			Ok(())
+		}
+
+		fn schedule_milestone_expiry(task_id: &T::Hash, deadline_block: T::BlockNumber) -> DispatchResult {
+			<ExpiringMilestonesPerBlock<T>>::try_mutate(deadline_block, |expiring| {
+				if !expiring.contains(task_id) {
+					expiring.try_push(*task_id)?;
+				}
+				Ok(())
+			}).map_err(|_: T::Hash| <Error<T>>::ExpiringTaskLimitReached.into())
+		}
+
+		/// Processes the expiring tasks of the blocks up to `n`, from the oldest block not processed yet.
+		pub(crate) fn expire_tasks(n: T::BlockNumber, limit: Weight) -> Weight {
+			Self::process_queue::<ExpiringTasksPerBlock<T>, NextExpiringBlock<T>, _>(n, limit, T::WeightInfo::expire_task(), |task_id| {
+				Self::expire_task(n, task_id)
+			})
+		}
+
+		/// Expires a task, or moves it to a later block when its timestamp deadline has not been reached yet.
+		/// Returns false when the task can't be moved to a later block or scheduled for removal, because too many tasks
+		/// expire or die on the same block, so that it is retried.
+		pub(crate) fn expire_task(n: T::BlockNumber, task_id: &T::Hash) -> bool {
+			let mut task = match Self::tasks(task_id) {
+				Some(task) => task,
+				None => return true,
+			};
+
+			// Timestamp deadlines that have not been reached yet, because blocks were built faster
+			// than expected, are re-anchored to the block timestamp and moved to a later block.
+			let now = T::Time::now().as_millis().saturated_into::<u64>();
+			if !task.deadline_by_block && now < task.deadline {
+				let deadline_block = Self::block_after(task.deadline - now);
+				if ExpiringTasksPerBlock::<T>::try_mutate(deadline_block, |expiring_tasks| expiring_tasks.try_push(*task_id)).is_ok() {
+					task.deadline_block = Some(deadline_block);
+					Tasks::<T>::insert(task_id, task);
+					return true;
+				} else {
+					return false;
+				}
+			}
+
+			// Expired tasks die a while after the block they expired on.
+			let dying_block = n + T::TaskLongevityAfterExpiration::get();
+			if DyingTasksPerBlock::<T>::try_mutate(dying_block, |dying_tasks| dying_tasks.try_push(*task_id)).is_err() {
+				return false;
+			}
+
+			// Update the tasks status to expired;
+			Self::set_status(&mut task, TaskStatus::Expired);
+			task.updated_at = n;
+			Self::unindex_location(task_id, &task.location);
+			Tasks::<T>::insert(task_id, task);
+
+			let _ = T::LifecycleHooks::on_expired(task_id);
+
+			true
+		}
+
+		/// Removes the dying tasks of the blocks up to `n`, from the oldest block not processed yet.
+		pub(crate) fn remove_dead_tasks(n: T::BlockNumber, limit: Weight) -> Weight {
+			Self::process_queue::<DyingTasksPerBlock<T>, NextDyingBlock<T>, _>(n, limit, T::WeightInfo::remove_dead_task(), |task_id| {
+				Self::remove_dead_task(task_id);
+				true
+			})
+		}
+
+		/// Processes the work scheduled for the blocks up to `n` within `limit`.
+		fn process_scheduled(n: T::BlockNumber, limit: Weight) -> Weight {
+			let mut weight = Self::end_disputes(n, limit);
+			weight = weight.saturating_add(Self::end_reviews(n, limit.saturating_sub(weight)));
+			weight = weight.saturating_add(Self::spawn_occurrences(n, limit.saturating_sub(weight)));
+			weight.saturating_add(Self::prune_receipts(n, limit.saturating_sub(weight)))
+		}
+
+		/// Resolves the disputes whose voting period ended on the blocks up to `n`, from the oldest block not processed yet.
+		pub(crate) fn end_disputes(n: T::BlockNumber, limit: Weight) -> Weight {
+			Self::process_queue::<DisputesEndingPerBlock<T>, NextDisputeBlock<T>, _>(n, limit, T::WeightInfo::resolve_dispute(), |task_id| {
+				Self::end_dispute(n, task_id)
+			})
+		}
+
+		/// Resolves a dispute at the end of its voting period. A dispute that can't be resolved is retried once another
+		/// voting period has passed. Returns false when it can't be scheduled again, because too many disputes end on that block.
+		pub(crate) fn end_dispute(n: T::BlockNumber, task_id: &T::Hash) -> bool {
+			// Disputes resolved by the vote of the last arbitrator are already gone.
+			if !<Disputes<T>>::contains_key(task_id) {
+				return true;
+			}
+			let error = match Self::resolve_dispute(task_id) {
+				Ok(()) => return true,
+				Err(error) => error,
+			};
+
+			let voting_ends_at = n + T::DisputeVotingPeriod::get();
+			if <DisputesEndingPerBlock<T>>::try_mutate(voting_ends_at, |disputes| disputes.try_push(*task_id)).is_err() {
+				return false;
+			}
+			<Disputes<T>>::mutate(task_id, |dispute| if let Some(dispute) = dispute {
+				dispute.voting_ends_at = voting_ends_at;
+			});
+
+			Self::deposit_event(Event::DisputeResolutionFailed(*task_id, error));
+			true
+		}
+
+		/// Accepts the completed tasks whose review period ended on the blocks up to `n`, from the oldest block not processed yet.
+		pub(crate) fn end_reviews(n: T::BlockNumber, limit: Weight) -> Weight {
+			Self::process_queue::<ReviewsEndingPerBlock<T>, NextReviewBlock<T>, _>(n, limit, T::WeightInfo::auto_accept_task(), |task_id| {
+				Self::end_review(n, task_id)
+			})
+		}
+
+		/// Accepts a completed task at the end of its review period. A task that can't be accepted is retried once another
+		/// review period has passed. Returns false when it can't be scheduled again, because too many reviews end on that block.
+		pub(crate) fn end_review(n: T::BlockNumber, task_id: &T::Hash) -> bool {
+			// Tasks reviewed, disputed or completed again in the meantime are not accepted on this entry.
+			let task = match Self::tasks(task_id) {
+				Some(task) if task.status == TaskStatus::Completed && task.completed_at + T::ReviewPeriod::get() <= n => task,
+				_ => return true,
+			};
+			let initiator = task.initiator.clone();
+			let error = match Self::settle_accepted_task(&initiator, task_id, task) {
+				Ok(()) => {
+					Self::deposit_event(Event::TaskAutoAccepted(*task_id));
+					return true;
+				},
+				Err(error) => error,
+			};
+
+			let review_ends_at = n + T::ReviewPeriod::get();
+			if <ReviewsEndingPerBlock<T>>::try_mutate(review_ends_at, |reviews| reviews.try_push(*task_id)).is_err() {
+				return false;
+			}
+
+			Self::deposit_event(Event::TaskAutoAcceptFailed(*task_id, error));
+			true
+		}
+
+		/// Spawns the occurrences of recurring tasks due on the blocks up to `n`, from the oldest block not processed yet.
+		pub(crate) fn spawn_occurrences(n: T::BlockNumber, limit: Weight) -> Weight {
+			Self::process_queue::<RecurringTasksPerBlock<T>, NextRecurrenceBlock<T>, _>(n, limit, T::WeightInfo::spawn_occurrence(), |recurring_id| {
+				Self::end_recurrence(n, recurring_id)
+			})
+		}
+
+		/// Spawns the occurrence of a recurring task that is due. The recurring task stops when its initiator can't fund
+		/// the occurrence, an occurrence that can't be spawned for another reason is retried after another interval.
+		/// Returns false when it can't be scheduled again, because too many recurring tasks are due on that block.
+		pub(crate) fn end_recurrence(n: T::BlockNumber, recurring_id: &T::Hash) -> bool {
+			// Recurring tasks cancelled in the meantime are not spawned.
+			let mut recurring = match Self::recurring_tasks(recurring_id) {
+				Some(recurring) => recurring,
+				None => return true,
+			};
+			let error = match Self::spawn_occurrence(recurring_id) {
+				Ok(task_id) => {
+					Self::deposit_event(Event::RecurringTaskSpawned(*recurring_id, task_id));
+					return true;
+				},
+				Err(error) => error,
+			};
+
+			// The initiator can't fund the occurrence anymore.
+			if error == DispatchError::from(Error::<T>::NotEnoughBalance) {
+				<RecurringTasks<T>>::remove(recurring_id);
+				Self::deposit_event(Event::RecurringTaskStopped(*recurring_id));
+				return true;
+			}
+
+			recurring.next_occurrence = n + recurring.interval;
+			if <RecurringTasksPerBlock<T>>::try_mutate(recurring.next_occurrence, |recurring_tasks| recurring_tasks.try_push(*recurring_id)).is_err() {
+				return false;
+			}
+			<RecurringTasks<T>>::insert(recurring_id, recurring);
+
+			Self::deposit_event(Event::RecurringTaskSpawnFailed(*recurring_id, error));
+			true
+		}
+
+		/// Prunes the receipts that outlived the receipt longevity on the blocks up to `n`, from the oldest block not pruned yet.
+		pub(crate) fn prune_receipts(n: T::BlockNumber, limit: Weight) -> Weight {
+			Self::process_queue::<PrunableReceiptsPerBlock<T>, NextPruningBlock<T>, _>(n, limit, T::WeightInfo::prune_receipt(), |task_id| {
+				Self::prune_receipt(task_id);
+				true
+			})
+		}
+
+		/// Processes the items of a queue on the blocks up to `n` within `limit`, from the block of the cursor, and moves
+		/// the cursor to the oldest block not processed yet. Each item costs `item_weight`. When `process` returns false,
+		/// the item and the rest of its block are carried over to the next call.
+		fn process_queue<Queue, Cursor, F>(n: T::BlockNumber, limit: Weight, item_weight: Weight, mut process: F) -> Weight
+			where
+				Queue: frame_support::storage::StorageMap<T::BlockNumber, BoundedVec<T::Hash, MaximumTasksPerBlock>, Query = BoundedVec<T::Hash, MaximumTasksPerBlock>>,
+				Cursor: frame_support::storage::StorageValue<T::BlockNumber, Query = Option<T::BlockNumber>>,
+				F: FnMut(&T::Hash) -> bool,
+		{
+			// Reading and writing the cursor, and each block of the queue.
+			let step_weight = T::DbWeight::get().reads_writes(1, 1);
+			if limit < step_weight {
+				return 0;
+			}
+			let mut weight = step_weight;
+
+			let mut block = Cursor::get().unwrap_or(n);
+			while block <= n && weight.saturating_add(step_weight) <= limit {
+				weight = weight.saturating_add(step_weight);
+
+				let items = Queue::get(block);
+				let mut processed = 0;
+				for item in items.iter() {
+					if weight.saturating_add(item_weight) > limit {
+						break;
+					}
+					// Items are charged whether or not they could be processed.
+					weight = weight.saturating_add(item_weight);
+					if !process(item) {
+						break;
+					}
+					processed += 1;
+				}
+
+				// Carry the items that were not processed over, and stay on this block.
+				if processed < items.len() {
+					let remaining: BoundedVec<T::Hash, MaximumTasksPerBlock> = items.into_inner().split_off(processed)
+						.try_into()
+						.expect("reducing, will not be out of bounds; qed");
+					Queue::insert(block, remaining);
+					break;
+				}
+				Queue::remove(block);
+				block += One::one();
+			}
+			Cursor::put(block);
+
+			weight
+		}
+
+		/// Removes a dead task from storage, unless it has been revived or removed already.
+		/// Its subtasks are detached, and expire on their own.
+		pub(crate) fn remove_dead_task(task_id: &T::Hash) {
+			if let Some(task) = Self::tasks(task_id) {
+				let _ = Self::delete_task(&task.initiator, task_id, None);
+			}
+		}
+
+		/// Returns the timestamp and the block of a deadline, estimating the one that was not given.
+		/// The deadline must not have passed yet, and its block is never earlier than the next one.
+		fn resolve_deadline(deadline: &TaskDeadline<T::BlockNumber>) -> Result<(u64, T::BlockNumber), DispatchError> {
+			let now = T::Time::now().as_millis().saturated_into::<u64>();
+			let current_block = <frame_system::Pallet<T>>::block_number();
+
+			match *deadline {
+				TaskDeadline::Timestamp(timestamp) => {
+					ensure!(now < timestamp, Error::<T>::IncorrectDeadlineTimestamp);
+					Ok((timestamp, Self::block_after(timestamp - now)))
+				},
+				TaskDeadline::Block(block) => {
+					ensure!(current_block < block, Error::<T>::IncorrectDeadlineBlock);
+					let blocks_till_deadline = (block - current_block).saturated_into::<u64>();
+					Ok((now.saturating_add(blocks_till_deadline.saturating_mul(T::MillisecondsPerBlock::get())), block))
+				},
+			}
+		}
+
+		/// Given an amount of milliseconds from now, calculate the block expected to be built by then.
+		fn block_after(millis: u64) -> T::BlockNumber {
+			let blocks_till_deadline: T::BlockNumber = (millis / T::MillisecondsPerBlock::get()).max(1).saturated_into();
+			<frame_system::Pallet<T>>::block_number() + blocks_till_deadline
+		}
+
+		/// Whether the deadline of a task has passed, by block for block deadlines and by time otherwise.
+		fn is_past_deadline(task: &Task<T>) -> bool {
+			if task.deadline_by_block {
+				task.deadline_block.map_or(false, |deadline| deadline <= <frame_system::Pallet<T>>::block_number())
+			} else {
+				task.deadline <= T::Time::now().as_millis().saturated_into::<u64>()
+			}
 		}
 
 	}
@@ -0,0 +1,400 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 UNIVERSALDOT FOUNDATION.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the task pallet, following the pattern of `docs/migrations.md`.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::bounded_vec::BoundedVec,
+	sp_runtime::{traits::{Saturating, Zero}, Percent},
+	traits::{BalanceStatus, Currency, GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Version 1 holds task budgets in escrow and adds every field introduced since the first release to `Task`.
+pub mod v1 {
+	use super::*;
+
+	/// Task layout of storage version 0, whose budget is reserved from the initiator.
+	#[derive(Decode)]
+	pub struct OldTask<T: Config> {
+		pub title: BoundedVec<u8, T::MaxTitleLen>,
+		pub specification: BoundedVec<u8, T::MaxSpecificationLen>,
+		pub initiator: T::AccountId,
+		pub volunteer: T::AccountId,
+		pub current_owner: T::AccountId,
+		pub status: TaskStatus,
+		pub budget: BalanceOf<T>,
+		pub deadline: u64,
+		pub attachments: BoundedVec<u8, T::MaxAttachmentsLen>,
+		pub keywords: BoundedVec<u8, T::MaxKeywordsLen>,
+		pub feedback: Option<BoundedVec<u8, T::MaxFeedbackLen>>,
+		pub created_at: T::BlockNumber,
+		pub updated_at: T::BlockNumber,
+		pub completed_at: T::BlockNumber,
+		pub organization: Option<T::Hash>,
+		pub deadline_block: Option<T::BlockNumber>,
+		pub task_id: T::Hash,
+		pub location: Option<NadLocation>,
+	}
+
+	/// Moves the reserved budget of every task that has not been accepted yet into its escrow account,
+	/// indexes the location of the tasks that have not expired, and fills in the new fields of `Task`.
+	///
+	/// Budgets below the minimum balance would be dusted in a new escrow account, they are unreserved back to
+	/// the initiator instead, and the task is left without a budget.
+	///
+	/// Old tasks stay open to any volunteer, as they were, and are funded by their initiator in the native currency.
+	/// Completed tasks are not scheduled for automatic acceptance, and wait for their initiator.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 1 {
+				log::info!(target: "runtime::task", "skipping v1 migration, storage is at {:?}", onchain_version);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			Tasks::<T>::translate::<OldTask<T>, _>(|task_id, old| {
+				translated += 1;
+
+				let mut budget = old.budget;
+				if old.status != TaskStatus::Accepted && budget < T::Currency::minimum_balance() {
+					T::Currency::unreserve(&old.initiator, budget);
+					budget = Zero::zero();
+				} else if old.status != TaskStatus::Accepted {
+					let escrow = Pallet::<T>::account_id(&task_id);
+					match T::Currency::repatriate_reserved(&old.initiator, &escrow, budget, BalanceStatus::Free) {
+						Ok(not_moved) => Escrows::<T>::insert(task_id, budget.saturating_sub(not_moved)),
+						Err(error) => log::warn!(target: "runtime::task", "budget of task {:?} not moved into escrow: {:?}", task_id, error),
+					}
+				}
+				if old.status != TaskStatus::Accepted && old.status != TaskStatus::Expired {
+					if Pallet::<T>::index_location(&task_id, &old.location).is_err() {
+						log::warn!(target: "runtime::task", "location of task {:?} not indexed", task_id);
+					}
+				}
+
+				Some(super::v3::OldTask {
+					title: old.title,
+					specification: old.specification,
+					initiator: old.initiator.clone(),
+					volunteer: old.volunteer,
+					current_owner: old.current_owner,
+					status: old.status,
+					budget,
+					deadline: old.deadline,
+					attachments: old.attachments,
+					keywords: old.keywords,
+					feedback: old.feedback,
+					created_at: old.created_at,
+					updated_at: old.updated_at,
+					completed_at: old.completed_at,
+					organization: old.organization,
+					deadline_block: old.deadline_block,
+					task_id: old.task_id,
+					location: old.location,
+					open: true,
+					slots: Default::default(),
+					parent: None,
+					delivery_deadline: None,
+					asset: None,
+					template: None,
+					deadline_by_block: false,
+					funder: old.initiator,
+				})
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::task", "migrated {} tasks to v1", translated);
+			// Every task is read and written, together with its initiator, escrow account, escrowed amount and grid cell
+			T::DbWeight::get().reads_writes(5 * translated + 1, 5 * translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 0, "task storage is not at version 0");
+			Self::set_temp_storage(Tasks::<T>::iter_keys().count() as u32, "task_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 1, "task storage is not at version 1");
+			let task_count: u32 = Self::get_temp_storage("task_count").ok_or("task count not stored before the upgrade")?;
+
+			// Every task decodes with the current layout once the later migrations have run, and its budget is held in escrow until it is accepted
+			let tasks: Vec<Task<T>> = Tasks::<T>::iter_values().collect();
+			ensure!(tasks.len() as u32 == task_count, "tasks lost during the migration");
+			for task in tasks.iter().filter(|task| task.status != TaskStatus::Accepted) {
+				let escrowed = Pallet::<T>::escrowed_balance(&task.task_id);
+				ensure!(escrowed == task.budget, "task budget not escrowed");
+				ensure!(escrowed.is_zero() || escrowed >= T::Currency::minimum_balance(), "task budget escrowed below the minimum balance");
+				ensure!(T::Currency::free_balance(&Pallet::<T>::account_id(&task.task_id)) >= escrowed, "task budget not held in escrow");
+			}
+			Ok(())
+		}
+	}
+}
+
+/// Version 2 keeps the submissions of a task as the evidence of its dispute.
+pub mod v2 {
+	use super::*;
+
+	/// Dispute layout of storage versions 0 and 1, without evidence.
+	#[derive(Decode)]
+	pub struct OldDispute<T: Config> {
+		pub opened_by: T::AccountId,
+		pub reason: BoundedVec<u8, T::MaxFeedbackLen>,
+		pub deposit: BalanceOf<T>,
+		pub arbitrators: BoundedVec<T::AccountId, T::ArbitratorsPerDispute>,
+		pub votes: BoundedVec<(T::AccountId, Percent), T::ArbitratorsPerDispute>,
+		pub previous_status: TaskStatus,
+		pub opened_at: T::BlockNumber,
+		pub voting_ends_at: T::BlockNumber,
+	}
+
+	/// Gives every open dispute an empty evidence, deliverables were not submitted before version 2.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 2 {
+				log::info!(target: "runtime::task", "skipping v2 migration, storage is at {:?}", onchain_version);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			Disputes::<T>::translate::<OldDispute<T>, _>(|_, old| {
+				translated += 1;
+				Some(Dispute {
+					opened_by: old.opened_by,
+					reason: old.reason,
+					evidence: Default::default(),
+					deposit: old.deposit,
+					arbitrators: old.arbitrators,
+					votes: old.votes,
+					previous_status: old.previous_status,
+					opened_at: old.opened_at,
+					voting_ends_at: old.voting_ends_at,
+				})
+			});
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::task", "migrated {} disputes to v2", translated);
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			// Runs before the v1 migration of the same upgrade, if any
+			ensure!(Pallet::<T>::on_chain_storage_version() < 2, "task storage is already at version 2");
+			Self::set_temp_storage(Disputes::<T>::iter_keys().count() as u32, "dispute_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 2, "task storage is not at version 2");
+			let dispute_count: u32 = Self::get_temp_storage("dispute_count").ok_or("dispute count not stored before the upgrade")?;
+
+			// Every dispute decodes with the new layout
+			ensure!(Disputes::<T>::iter_values().count() as u32 == dispute_count, "disputes lost during the migration");
+			Ok(())
+		}
+	}
+}
+
+/// Version 3 adds the bond volunteers reserve to start a task to `Task`.
+pub mod v3 {
+	use super::*;
+
+	/// Task layout of storage versions 1 and 2, without a volunteer bond. The v1 migration writes tasks with this layout.
+	#[derive(Encode, Decode)]
+	pub struct OldTask<T: Config> {
+		pub title: BoundedVec<u8, T::MaxTitleLen>,
+		pub specification: BoundedVec<u8, T::MaxSpecificationLen>,
+		pub initiator: T::AccountId,
+		pub volunteer: T::AccountId,
+		pub current_owner: T::AccountId,
+		pub status: TaskStatus,
+		pub budget: BalanceOf<T>,
+		pub deadline: u64,
+		pub attachments: BoundedVec<u8, T::MaxAttachmentsLen>,
+		pub keywords: BoundedVec<u8, T::MaxKeywordsLen>,
+		pub feedback: Option<BoundedVec<u8, T::MaxFeedbackLen>>,
+		pub created_at: T::BlockNumber,
+		pub updated_at: T::BlockNumber,
+		pub completed_at: T::BlockNumber,
+		pub organization: Option<T::Hash>,
+		pub deadline_block: Option<T::BlockNumber>,
+		pub task_id: T::Hash,
+		pub location: Option<NadLocation>,
+		pub open: bool,
+		pub slots: BoundedVec<VolunteerSlot<T>, T::MaxVolunteerSlots>,
+		pub parent: Option<T::Hash>,
+		pub delivery_deadline: Option<T::BlockNumber>,
+		pub asset: Option<T::AssetId>,
+		pub template: Option<(T::Hash, u32)>,
+		pub deadline_by_block: bool,
+		pub funder: T::AccountId,
+	}
+
+	/// Requires no bond from the volunteers of every task, volunteers did not reserve bonds before version 3.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 3 {
+				log::info!(target: "runtime::task", "skipping v3 migration, storage is at {:?}", onchain_version);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			Tasks::<T>::translate::<OldTask<T>, _>(|_, old| {
+				translated += 1;
+				Some(Task {
+					title: old.title,
+					specification: old.specification,
+					initiator: old.initiator,
+					volunteer: old.volunteer,
+					current_owner: old.current_owner,
+					status: old.status,
+					budget: old.budget,
+					deadline: old.deadline,
+					attachments: old.attachments,
+					keywords: old.keywords,
+					feedback: old.feedback,
+					created_at: old.created_at,
+					updated_at: old.updated_at,
+					completed_at: old.completed_at,
+					organization: old.organization,
+					deadline_block: old.deadline_block,
+					task_id: old.task_id,
+					location: old.location,
+					open: old.open,
+					slots: old.slots,
+					parent: old.parent,
+					delivery_deadline: old.delivery_deadline,
+					asset: old.asset,
+					template: old.template,
+					deadline_by_block: old.deadline_by_block,
+					funder: old.funder,
+					bond: None,
+				})
+			});
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::task", "migrated {} tasks to v3", translated);
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			// Runs before the v1 and v2 migrations of the same upgrade, if any
+			ensure!(Pallet::<T>::on_chain_storage_version() < 3, "task storage is already at version 3");
+			Self::set_temp_storage(Tasks::<T>::iter_keys().count() as u32, "bonded_task_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 3, "task storage is not at version 3");
+			let task_count: u32 = Self::get_temp_storage("bonded_task_count").ok_or("task count not stored before the upgrade")?;
+
+			// Every task decodes with the new layout, without a bond yet
+			let mut migrated = 0u32;
+			for task in Tasks::<T>::iter_values() {
+				ensure!(task.bond.is_none(), "task bond set during the migration");
+				migrated += 1;
+			}
+			ensure!(migrated == task_count, "tasks lost during the migration");
+			Ok(())
+		}
+	}
+}
+
+/// Version 4 indexes tasks by status and organization, so the paginated task queries stop scanning all tasks.
+pub mod v4 {
+	use super::*;
+
+	/// Indexes every task by its status, and by its organization if it has one. The task layout is unchanged.
+	pub struct MigrateToV4<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version >= 4 {
+				log::info!(target: "runtime::task", "skipping v4 migration, storage is at {:?}", onchain_version);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let (mut indexed, mut writes) = (0u64, 0u64);
+			for (task_id, task) in Tasks::<T>::iter() {
+				indexed += 1;
+				TasksByStatus::<T>::insert(&task.status, task_id, ());
+				writes += 1;
+				if let Some(organization) = task.organization {
+					TasksByOrganization::<T>::insert(organization, task_id, ());
+					writes += 1;
+				}
+			}
+			StorageVersion::new(4).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::task", "indexed {} tasks for v4", indexed);
+			T::DbWeight::get().reads_writes(indexed + 1, writes + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			// Runs before the v1, v2 and v3 migrations of the same upgrade, if any
+			ensure!(Pallet::<T>::on_chain_storage_version() < 4, "task storage is already at version 4");
+			Self::set_temp_storage(Tasks::<T>::iter_keys().count() as u32, "indexed_task_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() >= 4, "task storage is not at version 4");
+			let task_count: u32 = Self::get_temp_storage("indexed_task_count").ok_or("task count not stored before the upgrade")?;
+
+			// Every task is indexed by its status and organization, and the status index holds nothing else
+			let mut indexed = 0u32;
+			for (task_id, task) in Tasks::<T>::iter() {
+				ensure!(TasksByStatus::<T>::contains_key(&task.status, task_id), "task not indexed by its status");
+				ensure!(task.organization.map_or(true, |organization| TasksByOrganization::<T>::contains_key(organization, task_id)), "task not indexed by its organization");
+				indexed += 1;
+			}
+			ensure!(indexed == task_count, "tasks lost during the migration");
+			ensure!(TasksByStatus::<T>::iter_keys().count() as u32 == task_count, "stale entries in the status index");
+			Ok(())
+		}
+	}
+}
@@ -1,15 +1,17 @@
 
 use crate as pallet_task;
-use frame_support::{parameter_types, PalletId};
+use crate::traits::TaskLifecycleHooks;
+use frame_support::{dispatch::DispatchResult, parameter_types, PalletId, traits::Randomness, weights::Weight};
 use frame_system as system;
 use scale_info::TypeInfo;
 use codec::{Encode, MaxEncodedLen};
 use frame_support::once_cell::sync::Lazy;
 use sp_core::{sr25519, H256};
+use std::cell::RefCell;
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	BuildStorage, Permill,
 	traits::ConstU32
 };
 
@@ -28,6 +30,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Did: pallet_did::{Pallet, Call, Storage, Event<T>},
 		Dao: pallet_dao::{Pallet, Call, Storage, Event<T>},
 		Profile: pallet_profile::{Pallet, Call, Storage, Event<T>},
@@ -77,7 +80,7 @@ impl pallet_timestamp::Config for Test {
 }
 
 parameter_types! {
-	pub const ExistentialDeposit: u64 = 1;
+	pub storage ExistentialDeposit: u64 = 1;
 }
 
 impl pallet_balances::Config for Test {
@@ -92,6 +95,35 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 }
 
+// Stablecoin used to pay task budgets in tests
+pub(crate) const USD: u32 = 1;
+
+parameter_types! {
+	pub const AssetDeposit: u64 = 1;
+	pub const AssetAccountDeposit: u64 = 1;
+	pub const ApprovalDeposit: u64 = 1;
+	pub const MetadataDepositBase: u64 = 1;
+	pub const MetadataDepositPerByte: u64 = 1;
+	pub const StringLimit: u32 = 50;
+}
+
+impl pallet_assets::Config for Test {
+	type Event = Event;
+	type Balance = u64;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	#[derive(TypeInfo, MaxEncodedLen, Encode)]
 	pub const MaxDescriptionLen: u32 = 64;
@@ -178,15 +210,74 @@ parameter_types! {
 	pub const MaxFeedbackLen: u32 = 5000;
 	#[derive(TypeInfo, MaxEncodedLen, Encode)]
 	pub const MaxKeywordsLen: u32 = 100;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxMilestonesPerTask: u32 = 5;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxApplicationsPerTask: u32 = 5;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxCoverNoteLen: u32 = 100;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxVolunteerSlots: u32 = 3;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const ArbitratorsPerDispute: u32 = 2;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxArbitratorPool: u32 = 10;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxReceiptsPerAccount: u32 = 2;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxReceiptsPerOrganization: u32 = 2;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxSubtasksPerTask: u32 = 3;
+	pub const MaxSubtaskDepth: u32 = 2;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxDependenciesPerTask: u32 = 3;
+	pub const MaxTasksWalked: u32 = 3;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxInvitationsPerTask: u32 = 2;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxDeliverableReferenceLen: u32 = 64;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxDeliverableDescriptionLen: u32 = 100;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxDeliverablesPerSubmission: u32 = 3;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxSubmissionsPerTask: u32 = 2;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxReviewLen: u32 = 100;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxRatingsPerAccount: u32 = 2;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxTasksPerCell: u32 = 3;
+	// 1000 blocks archive
+	pub const ReceiptLongevity: BlockNumber = 1000;
+	pub const DisputeDeposit: u64 = 50;
+	pub const DisputeVotingPeriod: BlockNumber = 10;
+	pub storage PlatformFee: Permill = Permill::zero();
+	pub storage OrganizationFee: Permill = Permill::zero();
+	pub PlatformFeeAccount: sr25519::Public = sr25519::Public::from_raw([13u8; 32]);
 	// 100 blocks longevity
 	pub const TaskLongevityAfterExpiration: BlockNumber = 100;
+	pub storage MaxExpiryWeight: Weight = 1_000_000_000_000;
+	pub storage MaxScheduledWeight: Weight = 1_000_000_000_000;
+	// 100 blocks to deliver
+	pub const DeliveryPeriod: BlockNumber = 100;
+	// 50 blocks to review
+	pub const ReviewPeriod: BlockNumber = 50;
+	// 100 blocks to rate
+	pub const RatingPeriod: BlockNumber = 100;
+	// 10 blocks between occurrences
+	pub const MinRecurrenceInterval: BlockNumber = 10;
 	pub const MilisPerBlock: u64 = MILLISECS_PER_BLOCK; 
 }
 
 impl pallet_task::Config for Test {
 	type Event = Event;
 	type Currency = Balances;
+	type AssetId = u32;
+	type Assets = Assets;
 	type Organization = Test;
+	type Profiles = Test;
+	type LifecycleHooks = (ProfileBookkeeping, LifecycleRecorder);
 	type MaxTasksOwned = MaxTasksOwned;
 	type Time = Time;
 	type WeightInfo = ();
@@ -196,19 +287,166 @@ impl pallet_task::Config for Test {
 	type MaxAttachmentsLen = MaxAttachmentsLen;
 	type MaxFeedbackLen = MaxFeedbackLen;
 	type MaxKeywordsLen = MaxKeywordsLen;
+	type MaxMilestonesPerTask = MaxMilestonesPerTask;
+	type MaxApplicationsPerTask = MaxApplicationsPerTask;
+	type MaxCoverNoteLen = MaxCoverNoteLen;
+	type MaxVolunteerSlots = MaxVolunteerSlots;
+	type Randomness = TestRandomness;
+	type DisputeDeposit = DisputeDeposit;
+	type DisputeVotingPeriod = DisputeVotingPeriod;
+	type DisputeSlash = ();
+	type PlatformFee = PlatformFee;
+	type OrganizationFee = OrganizationFee;
+	type PlatformFeeDestination = ();
+	type PlatformFeeAccount = PlatformFeeAccount;
+	type ArbitratorsPerDispute = ArbitratorsPerDispute;
+	type MaxArbitratorPool = MaxArbitratorPool;
+	type ReceiptLongevity = ReceiptLongevity;
+	type MaxReceiptsPerAccount = MaxReceiptsPerAccount;
+	type MaxReceiptsPerOrganization = MaxReceiptsPerOrganization;
+	type MaxSubtasksPerTask = MaxSubtasksPerTask;
+	type MaxSubtaskDepth = MaxSubtaskDepth;
+	type MaxDependenciesPerTask = MaxDependenciesPerTask;
+	type MaxTasksWalked = MaxTasksWalked;
+	type MaxInvitationsPerTask = MaxInvitationsPerTask;
+	type MaxDeliverableReferenceLen = MaxDeliverableReferenceLen;
+	type MaxDeliverableDescriptionLen = MaxDeliverableDescriptionLen;
+	type MaxDeliverablesPerSubmission = MaxDeliverablesPerSubmission;
+	type MaxSubmissionsPerTask = MaxSubmissionsPerTask;
 	type MillisecondsPerBlock = MilisPerBlock;
 	type TaskLongevityAfterExpiration = TaskLongevityAfterExpiration;
+	type MaxExpiryWeight = MaxExpiryWeight;
+	type MaxScheduledWeight = MaxScheduledWeight;
+	type DeliveryPeriod = DeliveryPeriod;
+	type ReviewPeriod = ReviewPeriod;
+	type MinRecurrenceInterval = MinRecurrenceInterval;
+	type RatingPeriod = RatingPeriod;
+	type MaxReviewLen = MaxReviewLen;
+	type MaxRatingsPerAccount = MaxRatingsPerAccount;
+	type MaxTasksPerCell = MaxTasksPerCell;
 }
 
-impl pallet_task::traits::Organization<H256> for Test {
+impl pallet_task::traits::Organization<H256, sr25519::Public> for Test {
 	fn exists(id: &H256) -> bool {
 		Dao::does_organization_exist(id)
 	}
+
+	fn members(id: &H256) -> Vec<sr25519::Public> {
+		Dao::members(id).into_inner()
+	}
+
+	fn account(id: &H256) -> sr25519::Public {
+		Dao::account_id(id)
+	}
+
+	fn can_spend(id: &H256, who: &sr25519::Public) -> bool {
+		Dao::can_spend(id, who)
+	}
+}
+
+impl pallet_task::traits::Profiles<sr25519::Public> for Test {
+	fn exists(who: &sr25519::Public) -> bool {
+		Profile::profiles(who).is_some()
+	}
+
+	fn location(who: &sr25519::Public) -> Option<pallet_task::NadLocation> {
+		Profile::profiles(who).and_then(|profile| profile.location)
+	}
+}
+
+// Keeps profiles up to date with the lifecycle of tasks, as the runtime does.
+pub struct ProfileBookkeeping;
+impl TaskLifecycleHooks<sr25519::Public, H256> for ProfileBookkeeping {
+	fn on_accepted(task_id: &H256, initiator: &sr25519::Public, volunteers: &[sr25519::Public]) -> DispatchResult {
+		Profile::add_reputation(initiator)?;
+		for volunteer in volunteers {
+			Profile::add_reputation(volunteer)?;
+			Profile::add_task_to_completed_tasks(volunteer, *task_id)?;
+		}
+		Ok(())
+	}
+
+	fn on_abandoned(_task_id: &H256, volunteers: &[sr25519::Public]) -> DispatchResult {
+		for volunteer in volunteers {
+			let _ = Profile::remove_reputation(volunteer);
+		}
+		Ok(())
+	}
+
+	fn on_rated(_task_id: &H256, _reviewer: &sr25519::Public, reviewee: &sr25519::Public, rating: u8) -> DispatchResult {
+		Profile::add_rating(reviewee, rating)
+	}
+}
+
+thread_local! {
+	pub static LIFECYCLE_EVENTS: RefCell<Vec<(&'static str, H256)>> = RefCell::new(Vec::new());
+}
+
+// Records every lifecycle callback, to check the hooks are called in tests.
+pub struct LifecycleRecorder;
+impl LifecycleRecorder {
+	fn record(event: &'static str, task_id: &H256) -> DispatchResult {
+		LIFECYCLE_EVENTS.with(|events| events.borrow_mut().push((event, *task_id)));
+		Ok(())
+	}
+
+	/// Returns the callbacks recorded for a task, oldest first.
+	pub fn events(task_id: &H256) -> Vec<&'static str> {
+		LIFECYCLE_EVENTS.with(|events| events.borrow().iter().filter(|(_, id)| id == task_id).map(|(event, _)| *event).collect())
+	}
+}
+
+impl TaskLifecycleHooks<sr25519::Public, H256> for LifecycleRecorder {
+	fn on_created(task_id: &H256, _initiator: &sr25519::Public) -> DispatchResult {
+		Self::record("created", task_id)
+	}
+
+	fn on_started(task_id: &H256, _volunteers: &[sr25519::Public]) -> DispatchResult {
+		Self::record("started", task_id)
+	}
+
+	fn on_completed(task_id: &H256, _volunteers: &[sr25519::Public]) -> DispatchResult {
+		Self::record("completed", task_id)
+	}
+
+	fn on_accepted(task_id: &H256, _initiator: &sr25519::Public, _volunteers: &[sr25519::Public]) -> DispatchResult {
+		Self::record("accepted", task_id)
+	}
+
+	fn on_rejected(task_id: &H256, _volunteers: &[sr25519::Public]) -> DispatchResult {
+		Self::record("rejected", task_id)
+	}
+
+	fn on_abandoned(task_id: &H256, _volunteers: &[sr25519::Public]) -> DispatchResult {
+		Self::record("abandoned", task_id)
+	}
+
+	fn on_expired(task_id: &H256) -> DispatchResult {
+		Self::record("expired", task_id)
+	}
+
+	fn on_removed(task_id: &H256) -> DispatchResult {
+		Self::record("removed", task_id)
+	}
+
+	fn on_rated(task_id: &H256, _reviewer: &sr25519::Public, _reviewee: &sr25519::Public, _rating: u8) -> DispatchResult {
+		Self::record("rated", task_id)
+	}
+}
+
+// Deterministic randomness, only used to draw arbitrators in tests.
+pub struct TestRandomness;
+impl Randomness<H256, BlockNumber> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumber) {
+		(BlakeTwo256::hash(subject), System::block_number())
+	}
 }
 
 pub static ALICE : Lazy<sr25519::Public> = Lazy::new(||{sr25519::Public::from_raw([1u8; 32])});
 pub static BOB : Lazy<sr25519::Public> = Lazy::new(||{sr25519::Public::from_raw([2u8; 32])});
 pub static TED : Lazy<sr25519::Public> = Lazy::new(||{sr25519::Public::from_raw([10u8; 32])});
+pub static DAVE : Lazy<sr25519::Public> = Lazy::new(||{sr25519::Public::from_raw([11u8; 32])});
+pub static EVE : Lazy<sr25519::Public> = Lazy::new(||{sr25519::Public::from_raw([12u8; 32])});
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
@@ -216,6 +454,11 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 		balances: BalancesConfig {
 			balances: vec![(*ALICE,  1000), (*BOB,  1000), (*TED, 1000)]
 		},
+		assets: AssetsConfig {
+			assets: vec![(USD, *ALICE, true, 1)],
+			metadata: vec![],
+			accounts: vec![(USD, *ALICE, 1000), (USD, *TED, 1000)],
+		},
 		..Default::default()
 	}
 		.assimilate_storage(&mut t)
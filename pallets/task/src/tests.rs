@@ -1,10 +1,12 @@
 use core::convert::TryInto;
-use crate::TaskStatus;
-use crate::{mock::*, Error, Config, ExpiringTasksPerBlock, DyingTasksPerBlock};
+use crate::{TaskStatus, MilestoneStatus, ApplicationStatus, TaskDeadline, Deliverable, VestingSchedule, VolunteerBond};
+use crate::{mock::*, Error, Config, MaximumTasksPerBlock, ExpiringTasksPerBlock, DyingTasksPerBlock, RecurringTasksPerBlock, Templates, TasksByCell, TasksByStatus, TasksByOrganization};
 use frame_support::traits::fungible::Inspect;
 use frame_support::storage::bounded_vec::BoundedVec;
-use frame_support::{assert_noop, assert_ok, traits::{UnixTime, Hooks}};
-use sp_core::H256;
+use frame_support::{assert_noop, assert_ok, traits::{Get, UnixTime, Hooks, ReservableCurrency}};
+use crate::weights::WeightInfo;
+use sp_core::{H256, sr25519};
+use sp_runtime::{Percent, Permill, traits::{BlakeTwo256, Hash}};
 
 // <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<  Constants and Functions used in TESTS >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
@@ -49,6 +51,14 @@ fn keywords() -> BoundedVec<u8, MaxKeywordsLen> {
 	vec![1u8, 5].try_into().unwrap()
 }
 
+fn deliverables() -> BoundedVec<Deliverable<Test>, MaxDeliverablesPerSubmission> {
+	let deliverable = Deliverable::<Test> {
+		reference: b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec().try_into().unwrap(),
+		description: Some(vec![1u8, 2].try_into().unwrap()),
+	};
+	vec![deliverable].try_into().unwrap()
+}
+
 fn keywords2() -> BoundedVec<u8, MaxKeywordsLen> {
 	vec![1u8, 5].try_into().unwrap()
 }
@@ -76,6 +86,10 @@ fn get_deadline(multiple: u64) -> u64 {
 	deadline_u64
 }
 
+fn task_deadline(multiple: u64) -> TaskDeadline<u64> {
+	TaskDeadline::Timestamp(get_deadline(multiple))
+}
+
 fn get_deadline_block(multiple: u64) -> u64 {
 	// deadline is current time + 1 hour
 	(((get_deadline(multiple) - <Time as UnixTime>::now().as_millis() as u64) as f64 / MILLISEC_PER_BLOCK as f64).floor() as u64) + System::block_number()
@@ -101,6 +115,15 @@ fn next_block(n: u64) {
 	Task::on_initialize(n);
 }
 
+// Builds blocks up to n, each block_time milliseconds after the previous one.
+fn run_to_block_with_block_time(n: u64, block_time: u64) {
+	for b in (System::block_number() + 1)..=n {
+		Time::set_timestamp(<Time as UnixTime>::now().as_millis() as u64 + block_time);
+		System::set_block_number(b);
+		Task::on_initialize(b);
+	}
+}
+
 fn create_organization() -> H256 {
 	// Create organization
 	let name : BoundedVec<u8, MaxDaoNameLen> = vec![1u8, 10].try_into().unwrap();
@@ -164,8 +187,12 @@ fn fund_transfer_on_create_task(){
 		// Ensure new task can be created.
 		assert_ok!(Task::create_task(Origin::signed(*ALICE), title(), spec() , BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
 
+		// Ensure budget is moved into the task escrow account
+		let task_id = Task::tasks_owned(*ALICE)[0];
 		assert_eq!(Balances::free_balance(&*ALICE), 993);
-		assert_eq!(Balances::reserved_balance(&*ALICE), BUDGET);
+		assert_eq!(Balances::reserved_balance(&*ALICE), 0);
+		assert_eq!(Balances::free_balance(&Task::account_id(&task_id)), BUDGET);
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET);
 	});
 }
 
@@ -321,10 +348,12 @@ fn check_balance_after_update_task(){
 		assert_eq!(new_balance_of_sender + BUDGET2, initial_balance_of_sender);
 		assert_eq!(task.budget, BUDGET2);
 
-		// Update task again with previous budget: can use reserved balance here because there is only one task to play with.
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET2);
+
+		// Update task again with previous budget: the difference is refunded from escrow.
 		assert_ok!(Task::update_task(Origin::signed(*TED), task_id, title2(), spec2(), BUDGET, get_deadline(1), attachments2(), keywords2(), None, Some(x()), Some(y())));
-		let reserved_balance = Balances::reserved_balance(&*TED);
-		assert_eq!(reserved_balance, BUDGET);
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET);
+		assert_eq!(Balances::free_balance(&*TED) + BUDGET, initial_balance_of_sender);
 	});
 }
 
@@ -347,7 +376,7 @@ fn check_balance_after_complete_task(){
 
 		// Ensure task is started by new current_owner (user 2)
 		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
-		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
 		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
 
 		// Ensure the escrow account is 0
@@ -471,7 +500,7 @@ fn completing_tasks_assigns_new_current_owner(){
 		assert_eq!(Task::tasks_owned(*BOB).len(), 1);
 
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
 
 		// Ensure that the ownership is reversed again
 		assert_eq!(task.current_owner, *ALICE);
@@ -525,7 +554,7 @@ fn task_can_only_be_finished_by_the_user_who_started_it(){
 		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
 
 		// Ensure that a user who didn't start the task has no permission to complete it
-		assert_noop!(Task::complete_task(Origin::signed(*ALICE), task_id), Error::<Test>::NoPermissionToComplete);
+		assert_noop!(Task::complete_task(Origin::signed(*ALICE), task_id, deliverables()), Error::<Test>::NoPermissionToComplete);
 	});
 }
 
@@ -592,7 +621,7 @@ fn only_creator_accepts_task(){
 		assert_eq!(Task::tasks_owned(*BOB).len(), 1);
 
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
 
 		// Ensure that the ownership is reversed again
 		assert_eq!(Task::tasks_owned(*ALICE).len(), 1);
@@ -617,7 +646,7 @@ fn accepted_task_is_added_to_completed_task_for_volunteer(){
 		// Ensure the task can be started, completed and accepted
 		let task_id = Task::tasks_owned(*ALICE)[0];
 		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
-		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
 		assert_ok!(Task::accept_task(Origin::signed(*ALICE), task_id));
 
 		// An accepted task is added as completed task on volunteer's profile.
@@ -642,7 +671,7 @@ fn volunteer_gets_paid_on_task_completion(){
 		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
 
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
 
 		// Ensure User 2 gets funds for completing task after it is accepted by user 1
 		assert_eq!(Balances::balance(&*BOB), 1000);
@@ -668,13 +697,13 @@ fn only_started_task_can_be_completed(){
 		assert_eq!(Task::tasks_owned(*ALICE).len(), 1);
 
 		// Ensure that a task can't be completed if it has not been started first
-		assert_noop!(Task::complete_task(Origin::signed(*BOB), task_id), Error::<Test>::NoPermissionToComplete);
+		assert_noop!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()), Error::<Test>::NoPermissionToComplete);
 
 		// Ensure task is started by new current_owner (user 2)
 		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
 
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
 	});
 }
 
@@ -702,7 +731,7 @@ fn when_task_is_accepted_ownership_is_cleared(){
 		assert_eq!(Task::tasks_owned(*BOB).len(), 1);
 
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
 
 		// Ensure that the ownership is reversed again
 		assert_eq!(Task::tasks_owned(*ALICE).len(), 1);
@@ -757,7 +786,7 @@ fn task_can_be_rejected_by_creator(){
 		assert_eq!(Task::tasks_owned(*BOB).len(), 1);
 
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
 
 		// Task is rejected by creator
 		assert_ok!(Task::reject_task(Origin::signed(*ALICE), task_id, feedback()));
@@ -791,7 +820,7 @@ fn feedback_is_given_when_task_is_rejected(){
 		assert_eq!(Task::tasks_owned(*BOB).len(), 1);
 
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
 
 		// Task is rejected by creator
 		assert_ok!(Task::reject_task(Origin::signed(*ALICE), task_id, feedback()));
@@ -822,7 +851,7 @@ fn increase_profile_reputation_when_task_completed(){
 		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
 
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
 
 		// Ensure task is accepted by task creator (user 1)
 		assert_ok!(Task::accept_task(Origin::signed(*ALICE), task_id));
@@ -896,20 +925,75 @@ fn balance_check_after_task_deletion() {
 		// Assign balances to task creator and escrow after task creation
 		let signer_free_balance = Balances::free_balance(&*ALICE);
 		let task_id = Task::tasks_owned(*ALICE)[0];
-		let signer_reserved_balance = Balances::reserved_balance(&*ALICE);
-		
+		let escrow_balance = Task::escrowed_balance(&task_id);
+
 		// Ensure balances are correct
-		assert_eq!(signer_reserved_balance, BUDGET);
-		assert_eq!(signer_balance, signer_reserved_balance + signer_free_balance);
+		assert_eq!(escrow_balance, BUDGET);
+		assert_eq!(signer_balance, escrow_balance + signer_free_balance);
 
 		// Ensure task can be removed
 		assert_ok!(Task::remove_task(Origin::signed(*ALICE), task_id));
 		let signer_free_balance_post_removal = Balances::free_balance(&*ALICE);
-		let signer_reserved_balance_post_removal = Balances::reserved_balance(&*ALICE);
+		let escrow_balance_post_removal = Task::escrowed_balance(&task_id);
 
 		// Ensure balances are correct after task removal
 		assert_eq!(signer_balance, signer_free_balance_post_removal);
-		assert_eq!(signer_reserved_balance_post_removal, 0);
+		assert_eq!(escrow_balance_post_removal, 0);
+	});
+}
+
+#[test]
+fn escrow_is_refunded_when_expired_task_dies() {
+	new_test_ext().execute_with(|| {
+		// Create profile and task
+		assert_ok!(Profile::create_profile(Origin::signed(*ALICE), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		let signer_balance = Balances::balance(&*ALICE);
+		assert_ok!(Task::create_task(Origin::signed(*ALICE), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*ALICE)[0];
+		let task_account = Task::account_id(&task_id);
+
+		// Ensure budget is held by the escrow account
+		assert_eq!(Balances::balance(&task_account), BUDGET);
+		assert_eq!(Balances::balance(&*ALICE), signer_balance - BUDGET);
+
+		// Run past the grace period so the task is removed from storage
+		run_to_block(get_dying_deadline_block(1));
+		assert!(Task::tasks(task_id).is_none());
+
+		// Ensure the escrow account is emptied back into the initiator's account
+		assert_eq!(Balances::balance(&task_account), 0);
+		assert_eq!(Balances::balance(&*ALICE), signer_balance);
+	});
+}
+
+#[test]
+fn escrow_holds_at_least_the_existential_deposit() {
+	new_test_ext().execute_with(|| {
+		ExistentialDeposit::set(&5);
+		assert_ok!(Profile::create_profile(Origin::signed(*ALICE), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		let signer_balance = Balances::balance(&*ALICE);
+
+		// A budget below the existential deposit can't be held by the escrow account
+		assert_noop!(Task::create_task(Origin::signed(*ALICE), title(), spec(), 4, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())), Error::<Test>::BudgetBelowMinimum);
+		assert_ok!(Task::create_task(Origin::signed(*ALICE), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*ALICE)[0];
+		let task_account = Task::account_id(&task_id);
+
+		// Decreasing the budget keeps the escrow account alive
+		assert_ok!(Task::update_task(Origin::signed(*ALICE), task_id, title(), spec(), 5, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		assert_eq!(Balances::free_balance(&task_account), 5);
+		assert_eq!(Task::escrowed_balance(&task_id), 5);
+		assert_noop!(Task::update_task(Origin::signed(*ALICE), task_id, title(), spec(), 3, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())), Error::<Test>::BudgetBelowMinimum);
+
+		// Funds sent to the escrow account by anyone else are not escrowed for the task
+		assert_ok!(Balances::transfer(Origin::signed(*BOB), task_account.clone(), 100));
+		assert_eq!(Task::escrowed_balance(&task_id), 5);
+
+		// Only the escrowed budget is refunded on removal
+		assert_ok!(Task::remove_task(Origin::signed(*ALICE), task_id));
+		assert_eq!(Balances::balance(&*ALICE), signer_balance);
+		assert_eq!(Balances::free_balance(&task_account), 100);
+		assert_eq!(Task::escrowed_balance(&task_id), 0);
 	});
 }
 
@@ -942,7 +1026,7 @@ fn block_time_is_added_when_task_is_updated() {
 		System::set_block_number(100);
 		
 		// Ensure task is completed by current current_owner (user 2)
-		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
 		let task = Task::tasks(task_id).expect("no task found");
 		assert_eq!(task.completed_at, 100);
 	})
@@ -958,16 +1042,20 @@ fn test_multiple_tasks_and_reserve_amounts() {
 		assert_ok!(Task::create_task(Origin::signed(*ALICE), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
 		assert_ok!(Task::create_task(Origin::signed(*ALICE), title2(), spec2(), BUDGET2, get_deadline(1), attachments2(), keywords2(), None, Some(x()), Some(y())));
 
-		// Assert that the reserved balances add up
-		assert_eq!(Balances::reserved_balance(&*ALICE), BUDGET + BUDGET2);
+		// Assert that each task holds its own budget in escrow
+		let task_id = Task::tasks_owned(*ALICE)[0];
+		let task_id_2 = Task::tasks_owned(*ALICE)[1];
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET);
+		assert_eq!(Task::escrowed_balance(&task_id_2), BUDGET2);
+		assert_eq!(Balances::free_balance(&*ALICE), 1000 - BUDGET - BUDGET2);
 
 		// Swap around budgets
-		let task_id = Task::tasks_owned(*ALICE)[0];
 		assert_ok!(Task::update_task(Origin::signed(*ALICE), task_id, title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
 
-		assert_eq!(Balances::reserved_balance(&*ALICE), BUDGET2 + BUDGET2);
+		assert_eq!(Task::escrowed_balance(&task_id) + Task::escrowed_balance(&task_id_2), BUDGET2 + BUDGET2);
 		assert_ok!(Task::update_task(Origin::signed(*ALICE), task_id, title2(), spec2(), BUDGET, get_deadline(1), attachments2(), keywords2(), None, Some(x()), Some(y())));
-		assert_eq!(Balances::reserved_balance(&*ALICE), BUDGET2 + BUDGET);
+		assert_eq!(Task::escrowed_balance(&task_id) + Task::escrowed_balance(&task_id_2), BUDGET2 + BUDGET);
+		assert_eq!(Balances::free_balance(&*ALICE), 1000 - BUDGET - BUDGET2);
 	})
 }
 #[test]
@@ -1020,8 +1108,8 @@ fn tasks_are_moved_to_expiry() {
 		// Setup state;
 		assert_ok!(Profile::create_profile(Origin::signed(*ALICE), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
 		assert_ok!(Task::create_task(Origin::signed(*ALICE), title(), spec2(), Balances::free_balance(&*ALICE) - 1000, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
-		assert_ok!(Task::create_task(Origin::signed(*ALICE), title(), spec(), Balances::free_balance(&*ALICE), get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
-		
+		assert_ok!(Task::create_task(Origin::signed(*ALICE), title(), spec(), Balances::free_balance(&*ALICE) - 1, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+
 		let task_id_0 = Task::tasks_owned(*ALICE)[0];
 		let task_id_1 = Task::tasks_owned(*ALICE)[1];
 		
@@ -1121,7 +1209,7 @@ fn test_expired_task_revival_status() {
 		assert!(task.status == TaskStatus::Expired);
 
 		// Revive the task;
-		let _ = Task::revive_expired_task(Origin::signed(*ALICE), task_id, get_deadline(2));
+		let _ = Task::revive_expired_task(Origin::signed(*ALICE), task_id, task_deadline(2));
 		let task = Task::tasks(task_id).expect("no task found");
 
 		// Assert that the task state has been updated correctly;
@@ -1152,7 +1240,7 @@ fn test_revival_swaps_task_from_dying_to_expired() {
 		assert!(DyingTasksPerBlock::<Test>::get(dying_deadline_block).contains(&task_id));
 
 		// Revive the task;
-		let _ = Task::revive_expired_task(Origin::signed(*ALICE), task_id, get_deadline(2));
+		let _ = Task::revive_expired_task(Origin::signed(*ALICE), task_id, task_deadline(2));
 
 		// Assert the task exists in expiring storage and not in dying storage;
 		assert!(ExpiringTasksPerBlock::<Test>::get(get_deadline_block(2)).contains(&task_id));
@@ -1174,7 +1262,7 @@ fn test_only_initiator_can_revive() {
 			run_to_block(deadline_block);
 
 			// Assert Bobo cannot revive as he is not task initiator;
-			assert_noop!(Task::revive_expired_task(Origin::signed(*BOB), task_id, get_deadline(2)), Error::<Test>::OnlyInitiatorUpdatesTask);
+			assert_noop!(Task::revive_expired_task(Origin::signed(*BOB), task_id, task_deadline(2)), Error::<Test>::OnlyInitiatorUpdatesTask);
 	
 	})
 }
@@ -1194,8 +1282,2259 @@ fn test_revive_task_with_invalid_new_deadline() {
 		run_to_block(deadline_block);
 
 		// Assert it does not operate with an invalid deadline;
-		assert_noop!(Task::revive_expired_task(Origin::signed(*ALICE), task_id, (<Time as UnixTime>::now().as_millis() - 100000) as u64), Error::<Test>::IncorrectDeadlineTimestamp);
+		assert_noop!(Task::revive_expired_task(Origin::signed(*ALICE), task_id, TaskDeadline::Timestamp((<Time as UnixTime>::now().as_millis() - 100000) as u64)), Error::<Test>::IncorrectDeadlineTimestamp);
 	})
 }
 
 
+
+fn milestone_title() -> BoundedVec<u8, MaxTitleLen> {
+	vec![1u8, 9].try_into().unwrap()
+}
+
+#[test]
+fn milestones_can_be_added_within_task_budget() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+
+		// Split the budget into two milestones;
+		assert_ok!(Task::add_milestone(Origin::signed(*TED), task_id, milestone_title(), 4, get_deadline(1)));
+		System::assert_last_event(Event::Task(crate::Event::MilestoneAdded(*TED, task_id, 0)));
+		assert_ok!(Task::add_milestone(Origin::signed(*TED), task_id, milestone_title(), 6, get_deadline(1)));
+		System::assert_last_event(Event::Task(crate::Event::MilestoneAdded(*TED, task_id, 1)));
+
+		let milestones = Task::milestones(task_id);
+		assert_eq!(milestones.len(), 2);
+		assert_eq!(milestones[0].budget, 4);
+		assert_eq!(milestones[1].status, MilestoneStatus::Created);
+		assert_eq!(Task::milestones_budget(&task_id), BUDGET2);
+
+		// Assert milestones can not exceed the task budget;
+		assert_noop!(Task::add_milestone(Origin::signed(*TED), task_id, milestone_title(), 1, get_deadline(1)), Error::<Test>::MilestoneBudgetExceeded);
+
+		// Assert the task budget can not be lowered below its milestones;
+		assert_noop!(Task::update_task(Origin::signed(*TED), task_id, title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())), Error::<Test>::MilestoneBudgetExceeded);
+
+		// Assert only the initiator can add milestones;
+		assert_noop!(Task::add_milestone(Origin::signed(*BOB), task_id, milestone_title(), 0, get_deadline(1)), Error::<Test>::OnlyInitiatorUpdatesTask);
+	});
+}
+
+#[test]
+fn milestones_can_not_exceed_max_milestones_per_task() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+
+		for _n in 0..MaxMilestonesPerTask::get() {
+			assert_ok!(Task::add_milestone(Origin::signed(*TED), task_id, milestone_title(), 1, get_deadline(1)));
+		}
+
+		assert_noop!(Task::add_milestone(Origin::signed(*TED), task_id, milestone_title(), 1, get_deadline(1)), Error::<Test>::TooManyMilestones);
+	});
+}
+
+#[test]
+fn milestones_are_paid_out_when_accepted() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::add_milestone(Origin::signed(*TED), task_id, milestone_title(), 4, get_deadline(1)));
+		assert_ok!(Task::add_milestone(Origin::signed(*TED), task_id, milestone_title(), 3, get_deadline(1)));
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+
+		// Submit and accept the first milestone;
+		assert_ok!(Task::submit_milestone(Origin::signed(*BOB), task_id, 0));
+		assert_eq!(Task::milestones(task_id)[0].status, MilestoneStatus::Submitted);
+		assert_ok!(Task::accept_milestone(Origin::signed(*TED), task_id, 0));
+		System::assert_last_event(Event::Task(crate::Event::MilestoneAccepted(*TED, task_id, 0, 4)));
+
+		// Assert only the milestone share has been paid out of escrow;
+		assert_eq!(Task::milestones(task_id)[0].status, MilestoneStatus::Accepted);
+		assert_eq!(Balances::balance(&*BOB), 1000 + 4);
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET2 - 4);
+
+		// Assert accepted milestones can not be reviewed or submitted again;
+		assert_noop!(Task::accept_milestone(Origin::signed(*TED), task_id, 0), Error::<Test>::OnlySubmittedMilestonesAreReviewed);
+		assert_noop!(Task::submit_milestone(Origin::signed(*BOB), task_id, 0), Error::<Test>::NoPermissionToSubmitMilestone);
+
+		// Complete and accept the task, the remaining budget is paid out;
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+		assert_eq!(Balances::balance(&*BOB), 1000 + BUDGET2);
+		assert_eq!(Task::escrowed_balance(&task_id), 0);
+		assert!(Task::milestones(task_id).is_empty());
+	});
+}
+
+#[test]
+fn rejected_milestones_can_be_submitted_again() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::add_milestone(Origin::signed(*TED), task_id, milestone_title(), 4, get_deadline(1)));
+
+		// Assert milestones are submitted only by the volunteer of a started task;
+		assert_noop!(Task::submit_milestone(Origin::signed(*BOB), task_id, 0), Error::<Test>::NoPermissionToComplete);
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_noop!(Task::submit_milestone(Origin::signed(*ALICE), task_id, 0), Error::<Test>::NoPermissionToComplete);
+		assert_noop!(Task::submit_milestone(Origin::signed(*BOB), task_id, 1), Error::<Test>::MilestoneNotExist);
+
+		// Assert milestones are reviewed only after submission;
+		assert_noop!(Task::reject_milestone(Origin::signed(*TED), task_id, 0, feedback()), Error::<Test>::OnlySubmittedMilestonesAreReviewed);
+
+		// Submit and reject the milestone;
+		assert_ok!(Task::submit_milestone(Origin::signed(*BOB), task_id, 0));
+		assert_noop!(Task::reject_milestone(Origin::signed(*BOB), task_id, 0, feedback()), Error::<Test>::OnlyInitiatorAcceptsTask);
+		assert_ok!(Task::reject_milestone(Origin::signed(*TED), task_id, 0, feedback()));
+		System::assert_last_event(Event::Task(crate::Event::MilestoneRejected(*TED, task_id, 0)));
+
+		let milestones = Task::milestones(task_id);
+		assert_eq!(milestones[0].status, MilestoneStatus::Rejected);
+		assert_eq!(milestones[0].feedback, Some(feedback()));
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET2);
+
+		// Assert the milestone can be submitted again;
+		assert_ok!(Task::submit_milestone(Origin::signed(*BOB), task_id, 0));
+		assert_eq!(Task::milestones(task_id)[0].status, MilestoneStatus::Submitted);
+	});
+}
+
+#[test]
+fn milestones_not_submitted_before_their_deadline_expire() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(2), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		let deadline = get_deadline(2);
+		let deadline_block = get_deadline_block(1);
+		assert_ok!(Task::add_milestone(Origin::signed(*TED), task_id, milestone_title(), 4, get_deadline(1)));
+		assert_ok!(Task::add_milestone(Origin::signed(*TED), task_id, milestone_title(), 3, deadline));
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+
+		// Assert the milestone waits for the volunteer until its deadline;
+		run_to_block(deadline_block - 1);
+		assert_eq!(Task::milestones(task_id)[0].status, MilestoneStatus::Created);
+
+		// Assert the milestone expires, and its budget is refunded out of the task budget;
+		run_to_block(deadline_block);
+		System::assert_last_event(Event::Task(crate::Event::MilestoneExpired(task_id, 0, 4)));
+		assert_eq!(Task::milestones(task_id)[0].status, MilestoneStatus::Expired);
+		assert_eq!(Task::milestones(task_id)[1].status, MilestoneStatus::Created);
+		assert_eq!(Task::tasks(task_id).unwrap().budget, BUDGET2 - 4);
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET2 - 4);
+		assert_eq!(Balances::balance(&*TED), 1000 - BUDGET2 + 4);
+		assert_eq!(Task::milestones_budget(&task_id), 3);
+		assert_noop!(Task::submit_milestone(Origin::signed(*BOB), task_id, 0), Error::<Test>::NoPermissionToSubmitMilestone);
+
+		// Ensure a milestone can't be submitted once its deadline has passed;
+		Time::set_timestamp(deadline);
+		assert_noop!(Task::submit_milestone(Origin::signed(*BOB), task_id, 1), Error::<Test>::MilestoneDeadlinePassed);
+	});
+}
+
+fn cover_note() -> BoundedVec<u8, MaxCoverNoteLen> {
+	vec![1u8, 7].try_into().unwrap()
+}
+
+#[test]
+fn tasks_that_are_not_open_are_started_by_application() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task_with_options(Origin::signed(*TED), title(), spec(), BUDGET2, task_deadline(1), attachments(), keywords(), None, Some(x()), Some(y()), false, None));
+		let task_id = Task::tasks_owned(*TED)[0];
+
+		// Assert the task can not be started directly;
+		assert_noop!(Task::start_task(Origin::signed(*BOB), task_id), Error::<Test>::TaskRequiresApplication);
+
+		// Assert only volunteers with a profile can apply, and only once;
+		assert_noop!(Task::apply_for_task(Origin::signed(*TED), task_id, cover_note(), None), Error::<Test>::NoPermissionToApply);
+		assert_noop!(Task::apply_for_task(Origin::signed(*ALICE), task_id, cover_note(), None), Error::<Test>::NoProfile);
+		assert_ok!(Task::apply_for_task(Origin::signed(*BOB), task_id, cover_note(), None));
+		System::assert_last_event(Event::Task(crate::Event::ApplicationSubmitted(*BOB, task_id)));
+		assert_noop!(Task::apply_for_task(Origin::signed(*BOB), task_id, cover_note(), None), Error::<Test>::AlreadyApplied);
+
+		let applications = Task::applications(task_id);
+		assert_eq!(applications.len(), 1);
+		assert_eq!(applications[0].applicant, *BOB);
+		assert_eq!(applications[0].cover_note, cover_note());
+		assert_eq!(applications[0].status, ApplicationStatus::Pending);
+
+		// Assert the application can be withdrawn;
+		assert_noop!(Task::withdraw_application(Origin::signed(*ALICE), task_id), Error::<Test>::ApplicationNotExist);
+		assert_ok!(Task::withdraw_application(Origin::signed(*BOB), task_id));
+		System::assert_last_event(Event::Task(crate::Event::ApplicationWithdrawn(*BOB, task_id)));
+		assert!(Task::applications(task_id).is_empty());
+	});
+}
+
+#[test]
+fn selecting_an_application_starts_task_and_declines_others() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*ALICE), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task_with_options(Origin::signed(*TED), title(), spec(), BUDGET2, task_deadline(1), attachments(), keywords(), None, Some(x()), Some(y()), false, None));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::apply_for_task(Origin::signed(*BOB), task_id, cover_note(), None));
+		assert_ok!(Task::apply_for_task(Origin::signed(*ALICE), task_id, cover_note(), None));
+
+		// Assert only the initiator can shortlist and select applications;
+		assert_noop!(Task::shortlist_application(Origin::signed(*BOB), task_id, *ALICE), Error::<Test>::OnlyInitiatorUpdatesTask);
+		assert_noop!(Task::select_application(Origin::signed(*BOB), task_id, *BOB), Error::<Test>::OnlyInitiatorUpdatesTask);
+		assert_noop!(Task::select_application(Origin::signed(*TED), task_id, *TED), Error::<Test>::ApplicationNotExist);
+
+		assert_ok!(Task::shortlist_application(Origin::signed(*TED), task_id, *ALICE));
+		System::assert_last_event(Event::Task(crate::Event::ApplicationShortlisted(*TED, task_id, *ALICE)));
+		assert_eq!(Task::applications(task_id)[1].status, ApplicationStatus::Shortlisted);
+
+		// Select the application of BOB;
+		assert_ok!(Task::select_application(Origin::signed(*TED), task_id, *BOB));
+		System::assert_has_event(Event::Task(crate::Event::ApplicationSelected(*TED, task_id, *BOB)));
+		System::assert_last_event(Event::Task(crate::Event::TaskAssigned(*BOB, task_id)));
+
+		// Assert the task is started by the selected volunteer;
+		let task = Task::tasks(task_id).expect("should find the task");
+		assert_eq!(task.status, TaskStatus::InProgress);
+		assert_eq!(task.volunteer, *BOB);
+		assert_eq!(task.current_owner, *BOB);
+		assert_eq!(Task::tasks_owned(*BOB), vec![task_id]);
+
+		// Assert the other applications are declined and closed;
+		let applications = Task::applications(task_id);
+		assert_eq!(applications[0].status, ApplicationStatus::Selected);
+		assert_eq!(applications[1].status, ApplicationStatus::Declined);
+		assert_noop!(Task::withdraw_application(Origin::signed(*ALICE), task_id), Error::<Test>::ApplicationClosed);
+		assert_noop!(Task::select_application(Origin::signed(*TED), task_id, *ALICE), Error::<Test>::ApplicationClosed);
+		assert_noop!(Task::apply_for_task(Origin::signed(*ALICE), task_id, cover_note(), None), Error::<Test>::NoPermissionToApply);
+
+		// Assert applications are removed with the task;
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+		assert!(Task::applications(task_id).is_empty());
+	});
+}
+
+#[test]
+fn selecting_a_counter_offer_changes_task_budget() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task_with_options(Origin::signed(*TED), title(), spec(), BUDGET, task_deadline(1), attachments(), keywords(), None, Some(x()), Some(y()), false, None));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::apply_for_task(Origin::signed(*BOB), task_id, cover_note(), Some(BUDGET2)));
+		assert_eq!(Task::applications(task_id)[0].price, Some(BUDGET2));
+
+		// Assert the difference is moved into escrow when the offer is selected;
+		assert_ok!(Task::select_application(Origin::signed(*TED), task_id, *BOB));
+		assert_eq!(Task::tasks(task_id).unwrap().budget, BUDGET2);
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET2);
+		assert_eq!(Balances::balance(&*TED), 1000 - BUDGET2);
+
+		// Assert the volunteer is paid the offered price;
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+		assert_eq!(Balances::balance(&*BOB), 1000 + BUDGET2);
+	});
+}
+
+fn shares(shares: Vec<u64>) -> BoundedVec<u64, MaxVolunteerSlots> {
+	shares.try_into().unwrap()
+}
+
+#[test]
+fn volunteer_slots_must_add_up_to_task_budget() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+
+		// Assert slots are set only by the initiator, with shares adding up to the budget;
+		assert_noop!(Task::set_volunteer_slots(Origin::signed(*BOB), task_id, shares(vec![6, 4])), Error::<Test>::OnlyInitiatorUpdatesTask);
+		assert_noop!(Task::set_volunteer_slots(Origin::signed(*TED), task_id, shares(vec![3, 3])), Error::<Test>::SlotSharesMismatch);
+		assert_ok!(Task::set_volunteer_slots(Origin::signed(*TED), task_id, shares(vec![6, 4])));
+		System::assert_last_event(Event::Task(crate::Event::VolunteerSlotsSet(*TED, task_id, 2)));
+
+		let task = Task::tasks(task_id).expect("should find the task");
+		assert_eq!(task.slots.len(), 2);
+		assert_eq!(Task::slots_budget(&task), BUDGET2);
+
+		// Assert team tasks can't be split into milestones;
+		assert_noop!(Task::add_milestone(Origin::signed(*TED), task_id, milestone_title(), 4, get_deadline(1)), Error::<Test>::MilestonesNotSupportedForTeamTasks);
+
+		// Assert the budget of a team task can only be updated together with its slots;
+		assert_noop!(Task::update_task(Origin::signed(*TED), task_id, title2(), spec2(), BUDGET, get_deadline(2), attachments2(), keywords2(), None, Some(x()), Some(y())), Error::<Test>::SlotSharesMismatch);
+		assert_ok!(Task::update_task(Origin::signed(*TED), task_id, title2(), spec2(), BUDGET2, get_deadline(2), attachments2(), keywords2(), None, Some(x()), Some(y())));
+		assert_eq!(Task::tasks(task_id).unwrap().slots.len(), 2);
+
+		// Assert empty shares turn the task back into a single volunteer task;
+		assert_ok!(Task::set_volunteer_slots(Origin::signed(*TED), task_id, shares(vec![])));
+		assert!(Task::tasks(task_id).unwrap().slots.is_empty());
+	});
+}
+
+#[test]
+fn team_task_pays_every_volunteer_their_share() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*ALICE), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::set_volunteer_slots(Origin::signed(*TED), task_id, shares(vec![6, 4])));
+
+		// Assert the task waits in created status until every slot is taken;
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_noop!(Task::start_task(Origin::signed(*BOB), task_id), Error::<Test>::AlreadyJoined);
+		assert_noop!(Task::set_volunteer_slots(Origin::signed(*TED), task_id, shares(vec![5, 5])), Error::<Test>::NoPermissionToChangeSlots);
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Created);
+
+		assert_ok!(Task::start_task(Origin::signed(*ALICE), task_id));
+		let task = Task::tasks(task_id).expect("should find the task");
+		assert_eq!(task.status, TaskStatus::InProgress);
+		assert_eq!(Task::participants(&task), vec![*BOB, *ALICE]);
+		assert_eq!(Task::tasks_owned(*BOB), vec![task_id]);
+		assert_eq!(Task::tasks_owned(*ALICE), vec![task_id]);
+		assert!(Task::tasks_owned(*TED).is_empty());
+
+		// Assert every slot is completed independently;
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_noop!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()), Error::<Test>::NoPermissionToComplete);
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::InProgress);
+		assert!(Task::tasks_owned(*BOB).is_empty());
+
+		// Assert a rejected team task goes back to every volunteer;
+		assert_ok!(Task::complete_task(Origin::signed(*ALICE), task_id, deliverables()));
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Completed);
+		assert_ok!(Task::reject_task(Origin::signed(*TED), task_id, feedback()));
+		assert_eq!(Task::tasks_owned(*BOB), vec![task_id]);
+		assert_eq!(Task::tasks_owned(*ALICE), vec![task_id]);
+		assert!(Task::tasks(task_id).unwrap().slots.iter().all(|slot| !slot.completed));
+
+		// Complete and accept the task;
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::complete_task(Origin::signed(*ALICE), task_id, deliverables()));
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+
+		// Assert every volunteer is paid their share, and credited for the task;
+		assert_eq!(Balances::balance(&*BOB), 1000 + 6);
+		assert_eq!(Balances::balance(&*ALICE), 1000 + 4);
+		assert_eq!(Task::escrowed_balance(&task_id), 0);
+		for volunteer in [*BOB, *ALICE] {
+			assert_eq!(Profile::profiles(volunteer).unwrap().reputation, 1);
+			assert_eq!(Profile::completed_tasks(volunteer).unwrap().into_inner(), vec![task_id]);
+		}
+		assert_eq!(Profile::profiles(*TED).unwrap().reputation, 1);
+	});
+}
+
+fn reason() -> BoundedVec<u8, MaxFeedbackLen> {
+	vec![1u8, 3].try_into().unwrap()
+}
+
+fn arbitrators(arbitrators: Vec<sr25519::Public>) -> BoundedVec<sr25519::Public, MaxArbitratorPool> {
+	arbitrators.try_into().unwrap()
+}
+
+// Creates a task of TED that BOB has started, completed and got rejected.
+fn create_rejected_task(organization: Option<H256>) -> H256 {
+	assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+	assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+	assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), organization, Some(x()), Some(y())));
+	let task_id = Task::tasks_owned(*TED)[0];
+	assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+	assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+	assert_ok!(Task::reject_task(Origin::signed(*TED), task_id, feedback()));
+	task_id
+}
+
+#[test]
+fn disputes_are_opened_by_parties_of_a_rejected_task() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+
+		// Assert tasks that have not been rejected can't be disputed;
+		assert_noop!(Task::open_dispute(Origin::signed(*BOB), task_id, reason()), Error::<Test>::NoPermissionToDispute);
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::reject_task(Origin::signed(*TED), task_id, feedback()));
+
+		// Assert only parties can dispute, and only when there are arbitrators;
+		assert_noop!(Task::open_dispute(Origin::signed(*ALICE), task_id, reason()), Error::<Test>::NoPermissionToDispute);
+		assert_noop!(Task::open_dispute(Origin::signed(*BOB), task_id, reason()), Error::<Test>::NotEnoughArbitrators);
+
+		// Assert only root configures the arbitrator pool;
+		assert_noop!(Task::set_arbitrator_pool(Origin::signed(*TED), arbitrators(vec![*DAVE, *EVE])), sp_runtime::DispatchError::BadOrigin);
+		assert_ok!(Task::set_arbitrator_pool(Origin::root(), arbitrators(vec![*DAVE, *EVE, *BOB])));
+
+		assert_ok!(Task::open_dispute(Origin::signed(*BOB), task_id, reason()));
+		System::assert_last_event(Event::Task(crate::Event::DisputeOpened(*BOB, task_id)));
+
+		// Assert the deposit is reserved and arbitrators are drawn from the pool, without the parties;
+		assert_eq!(Balances::reserved_balance(&*BOB), DisputeDeposit::get());
+		let dispute = Task::disputes(task_id).expect("should find the dispute");
+		assert_eq!(dispute.arbitrators.len(), 2);
+		assert!(dispute.arbitrators.contains(&*DAVE));
+		assert!(dispute.arbitrators.contains(&*EVE));
+		assert_eq!(dispute.voting_ends_at, 1 + DisputeVotingPeriod::get());
+
+		// Assert the disputed task is frozen;
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Disputed);
+		assert_noop!(Task::open_dispute(Origin::signed(*TED), task_id, reason()), Error::<Test>::TaskDisputed);
+		assert_noop!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()), Error::<Test>::NoPermissionToComplete);
+	});
+}
+
+#[test]
+fn dispute_verdict_splits_escrow() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let task_id = create_rejected_task(None);
+		assert_ok!(Task::set_arbitrator_pool(Origin::root(), arbitrators(vec![*DAVE, *EVE])));
+		assert_ok!(Task::open_dispute(Origin::signed(*BOB), task_id, reason()));
+
+		// Assert only drawn arbitrators vote, and only once;
+		assert_noop!(Task::vote_on_dispute(Origin::signed(*TED), task_id, Percent::from_percent(0)), Error::<Test>::NotAnArbitrator);
+		assert_ok!(Task::vote_on_dispute(Origin::signed(*DAVE), task_id, Percent::from_percent(60)));
+		System::assert_last_event(Event::Task(crate::Event::DisputeVoted(*DAVE, task_id, Percent::from_percent(60))));
+		assert_noop!(Task::vote_on_dispute(Origin::signed(*DAVE), task_id, Percent::from_percent(60)), Error::<Test>::AlreadyVoted);
+
+		// Assert the last vote resolves the dispute by the average verdict;
+		assert_ok!(Task::vote_on_dispute(Origin::signed(*EVE), task_id, Percent::from_percent(80)));
+		System::assert_last_event(Event::Task(crate::Event::DisputeResolved(task_id, Percent::from_percent(70), 7, 3)));
+		assert_eq!(Balances::balance(&*BOB), 1000 + 7);
+		assert_eq!(Balances::balance(&*TED), 1000 - BUDGET2 + 3);
+		assert_eq!(Task::escrowed_balance(&task_id), 0);
+
+		// Assert the deposit of the winning opener is returned and the task is removed;
+		assert_eq!(Balances::reserved_balance(&*BOB), 0);
+		assert!(Task::disputes(task_id).is_none());
+		assert!(Task::tasks(task_id).is_none());
+		assert!(Task::tasks_owned(*BOB).is_empty());
+		assert_eq!(Task::task_count(), 0);
+	});
+}
+
+#[test]
+fn lost_dispute_slashes_deposit() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let task_id = create_rejected_task(None);
+		assert_ok!(Task::set_arbitrator_pool(Origin::root(), arbitrators(vec![*DAVE])));
+		assert_ok!(Task::open_dispute(Origin::signed(*TED), task_id, reason()));
+
+		// Arbitrator awards the whole budget to the volunteer;
+		assert_ok!(Task::vote_on_dispute(Origin::signed(*DAVE), task_id, Percent::from_percent(100)));
+		assert_eq!(Balances::balance(&*BOB), 1000 + BUDGET2);
+
+		// Assert the deposit of the initiator is slashed;
+		assert_eq!(Balances::reserved_balance(&*TED), 0);
+		assert_eq!(Balances::balance(&*TED), 1000 - BUDGET2 - DisputeDeposit::get());
+	});
+}
+
+#[test]
+fn even_dispute_verdict_goes_against_neither_side() {
+	new_test_ext().execute_with(|| {
+		// Setup state, the volunteer reserves a bond to start the task;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::set_volunteer_bond(Origin::signed(*TED), task_id, Some(VolunteerBond::Amount(2))));
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::reject_task(Origin::signed(*TED), task_id, feedback()));
+		assert_ok!(Task::set_arbitrator_pool(Origin::root(), arbitrators(vec![*DAVE, *EVE])));
+		assert_ok!(Task::open_dispute(Origin::signed(*TED), task_id, reason()));
+
+		// Arbitrators split the escrow evenly;
+		assert_ok!(Task::vote_on_dispute(Origin::signed(*DAVE), task_id, Percent::from_percent(40)));
+		assert_ok!(Task::vote_on_dispute(Origin::signed(*EVE), task_id, Percent::from_percent(60)));
+		System::assert_last_event(Event::Task(crate::Event::DisputeResolved(task_id, Percent::from_percent(50), 5, 5)));
+
+		// Assert neither the deposit of the opener nor the bond of the volunteer is slashed;
+		assert_eq!(Balances::reserved_balance(&*TED), 0);
+		assert_eq!(Balances::reserved_balance(&*BOB), 0);
+		assert_eq!(Balances::balance(&*TED), 1000 - BUDGET2 + 5);
+		assert_eq!(Balances::balance(&*BOB), 1000 + 5);
+	});
+}
+
+#[test]
+fn dispute_that_fails_to_resolve_is_retried() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let task_id = create_rejected_task(None);
+		assert_ok!(Task::set_arbitrator_pool(Origin::root(), arbitrators(vec![*DAVE, *EVE])));
+		assert_ok!(Task::open_dispute(Origin::signed(*BOB), task_id, reason()));
+		assert_ok!(Task::vote_on_dispute(Origin::signed(*DAVE), task_id, Percent::from_percent(70)));
+
+		// The refund to the initiator would leave less than the existential deposit in escrow;
+		ExistentialDeposit::set(&5);
+		run_to_block(1 + DisputeVotingPeriod::get());
+		assert!(matches!(System::events().last().unwrap().event, Event::Task(crate::Event::DisputeResolutionFailed(id, _)) if id == task_id));
+
+		// Assert the dispute is kept, and retried after another voting period;
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Disputed);
+		assert_eq!(Task::disputes(task_id).unwrap().voting_ends_at, 1 + 2 * DisputeVotingPeriod::get());
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET2);
+
+		ExistentialDeposit::set(&1);
+		run_to_block(1 + 2 * DisputeVotingPeriod::get());
+		System::assert_last_event(Event::Task(crate::Event::DisputeResolved(task_id, Percent::from_percent(70), 7, 3)));
+		assert!(Task::disputes(task_id).is_none());
+	});
+}
+
+#[test]
+fn dispute_without_votes_is_dismissed() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let task_id = create_rejected_task(None);
+		assert_ok!(Task::set_arbitrator_pool(Origin::root(), arbitrators(vec![*DAVE])));
+		assert_ok!(Task::open_dispute(Origin::signed(*BOB), task_id, reason()));
+
+		// Let the voting period pass;
+		run_to_block(1 + DisputeVotingPeriod::get());
+		System::assert_last_event(Event::Task(crate::Event::DisputeDismissed(task_id)));
+
+		// Assert the task is back in progress with the escrow untouched, and the deposit returned;
+		assert!(Task::disputes(task_id).is_none());
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::InProgress);
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET2);
+		assert_eq!(Balances::reserved_balance(&*BOB), 0);
+		assert_noop!(Task::vote_on_dispute(Origin::signed(*DAVE), task_id, Percent::from_percent(50)), Error::<Test>::DisputeNotExist);
+	});
+}
+
+#[test]
+fn vesting_payout_is_claimed_as_it_vests() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), 101, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		let schedule = VestingSchedule { cliff: 10, period: 10 };
+
+		// Ensure only the initiator vests the payout of a created task;
+		assert_noop!(Task::set_vesting_schedule(Origin::signed(*BOB), task_id, Some(schedule)), Error::<Test>::OnlyInitiatorUpdatesTask);
+		assert_ok!(Task::set_vesting_schedule(Origin::signed(*TED), task_id, Some(schedule)));
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_noop!(Task::set_vesting_schedule(Origin::signed(*TED), task_id, None), Error::<Test>::NoPermissionToUpdate);
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+
+		// Assert the payout stays in escrow once the task is accepted, its minimum balance kept out of the share;
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+		System::assert_has_event(Event::Task(crate::Event::PayoutVesting(task_id, 11, 21)));
+		assert_eq!(Balances::balance(&*BOB), 1000);
+		assert_eq!(Task::escrowed_balance(&task_id), 101);
+		assert_eq!(Task::vesting_payouts(task_id).unwrap().shares[0].amount, 100);
+		assert_noop!(Task::claim_vested(Origin::signed(*BOB), task_id), Error::<Test>::NothingVested);
+
+		// Assert the payout vests linearly after the cliff, and is claimed by its volunteers only;
+		run_to_block(16);
+		assert_eq!(Task::claimable(&task_id, &*BOB), 50);
+		assert_noop!(Task::claim_vested(Origin::signed(*TED), task_id), Error::<Test>::NoPermissionToClaim);
+		assert_ok!(Task::claim_vested(Origin::signed(*BOB), task_id));
+		System::assert_last_event(Event::Task(crate::Event::VestedClaimed(*BOB, task_id, 50)));
+		assert_eq!(Balances::balance(&*BOB), 1000 + 50);
+
+		// Assert the payout is removed once completely claimed;
+		run_to_block(30);
+		assert_ok!(Task::claim_vested(Origin::signed(*BOB), task_id));
+		assert_eq!(Balances::balance(&*BOB), 1000 + 101);
+		assert_eq!(Task::escrowed_balance(&task_id), 0);
+		assert!(Task::vesting_payouts(task_id).is_none());
+	});
+}
+
+#[test]
+fn final_vesting_claims_are_not_held_back_by_the_minimum_balance() {
+	new_test_ext().execute_with(|| {
+		// Setup state, the team payout vests from block 1 to block 11;
+		ExistentialDeposit::set(&10);
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*ALICE), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), 110, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::set_volunteer_slots(Origin::signed(*TED), task_id, shares(vec![55, 55])));
+		assert_ok!(Task::set_vesting_schedule(Origin::signed(*TED), task_id, Some(VestingSchedule { cliff: 0, period: 10 })));
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::start_task(Origin::signed(*ALICE), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::complete_task(Origin::signed(*ALICE), task_id, deliverables()));
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+
+		// Assert the minimum balance of the escrow account is kept out of the shares;
+		let payout = Task::vesting_payouts(task_id).expect("should find the vesting payout");
+		assert_eq!(payout.shares.iter().map(|share| share.amount).collect::<Vec<_>>(), vec![50, 50]);
+		assert_eq!(Task::escrowed_balance(&task_id), 110);
+
+		// Assert a volunteer claims the rest of their share while the other one still owes their last tranche;
+		run_to_block(10);
+		assert_ok!(Task::claim_vested(Origin::signed(*ALICE), task_id));
+		assert_eq!(Balances::balance(&*ALICE), 1000 + 45);
+		run_to_block(11);
+		assert_ok!(Task::claim_vested(Origin::signed(*BOB), task_id));
+		assert_eq!(Balances::balance(&*BOB), 1000 + 50);
+		assert_eq!(Task::escrowed_balance(&task_id), 15);
+
+		// Assert the claim settling the payout takes the minimum balance with it;
+		assert_eq!(Task::claimable(&task_id, &*ALICE), 15);
+		assert_ok!(Task::claim_vested(Origin::signed(*ALICE), task_id));
+		System::assert_last_event(Event::Task(crate::Event::VestedClaimed(*ALICE, task_id, 15)));
+		assert_eq!(Balances::balance(&*ALICE), 1000 + 60);
+		assert_eq!(Task::escrowed_balance(&task_id), 0);
+		assert!(Task::vesting_payouts(task_id).is_none());
+	});
+}
+
+#[test]
+fn unvested_payout_is_cancelled_only_through_a_dispute() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), 101, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::set_vesting_schedule(Origin::signed(*TED), task_id, Some(VestingSchedule { cliff: 0, period: 10 })));
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+		assert_ok!(Task::set_arbitrator_pool(Origin::root(), arbitrators(vec![*DAVE])));
+		run_to_block(5);
+		assert_ok!(Task::claim_vested(Origin::signed(*BOB), task_id));
+		assert_eq!(Balances::balance(&*BOB), 1000 + 40);
+
+		// Assert only the initiator disputes the payout, and claims wait for the verdict;
+		run_to_block(6);
+		assert_noop!(Task::open_dispute(Origin::signed(*BOB), task_id, reason()), Error::<Test>::NoPermissionToDispute);
+		assert_ok!(Task::open_dispute(Origin::signed(*TED), task_id, reason()));
+		run_to_block(8);
+		assert_noop!(Task::claim_vested(Origin::signed(*BOB), task_id), Error::<Test>::TaskDisputed);
+
+		// Assert the volunteer is paid what had vested when the dispute was opened and the awarded share of the rest;
+		assert_ok!(Task::vote_on_dispute(Origin::signed(*DAVE), task_id, Percent::from_percent(20)));
+		System::assert_last_event(Event::Task(crate::Event::DisputeResolved(task_id, Percent::from_percent(20), 20, 41)));
+		assert_eq!(Balances::balance(&*BOB), 1000 + 60);
+
+		// Assert the remainder is refunded, and the deposit of the initiator returned;
+		assert_eq!(Balances::balance(&*TED), 1000 - 101 + 41);
+		assert_eq!(Balances::reserved_balance(&*TED), 0);
+		assert_eq!(Task::escrowed_balance(&task_id), 0);
+		assert!(Task::vesting_payouts(task_id).is_none());
+	});
+}
+
+#[test]
+fn arbitrators_are_drawn_from_task_organization() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let org_id = create_organization();
+		assert_ok!(Dao::add_members(Origin::signed(*ALICE), org_id, *TED));
+		assert_ok!(Dao::add_members(Origin::signed(*ALICE), org_id, *BOB));
+		let task_id = create_rejected_task(Some(org_id));
+
+		// Assert the pool is not used for tasks of an organization;
+		assert_ok!(Task::set_arbitrator_pool(Origin::root(), arbitrators(vec![*DAVE, *EVE])));
+		assert_ok!(Task::open_dispute(Origin::signed(*BOB), task_id, reason()));
+
+		// Assert only members who are not parties of the task are drawn;
+		let dispute = Task::disputes(task_id).expect("should find the dispute");
+		assert_eq!(dispute.arbitrators.into_inner(), vec![*ALICE]);
+	});
+}
+
+#[test]
+fn accepted_task_leaves_a_receipt() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let org_id = create_organization();
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), Some(org_id), Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::add_milestone(Origin::signed(*TED), task_id, milestone_title(), 4, get_deadline(1)));
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::submit_milestone(Origin::signed(*BOB), task_id, 0));
+		assert_ok!(Task::accept_milestone(Origin::signed(*TED), task_id, 0));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+
+		// Assert no receipt is kept before acceptance;
+		assert!(Task::receipts(task_id).is_none());
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+
+		// Assert the receipt survives the task;
+		assert!(Task::tasks(task_id).is_none());
+		let receipt = Task::receipts(task_id).expect("should find the receipt");
+		assert_eq!(receipt.initiator, *TED);
+		assert_eq!(receipt.volunteers.into_inner(), vec![*BOB]);
+		assert_eq!(receipt.amount, BUDGET2);
+		assert_eq!(receipt.organization, Some(org_id));
+		assert_eq!(receipt.accepted_at, 1);
+		assert_eq!(receipt.title_hash, BlakeTwo256::hash(&title()));
+
+		// Assert the receipt is queryable by account and organization;
+		assert_eq!(Task::receipts_by_account(*TED).into_inner(), vec![task_id]);
+		assert_eq!(Task::receipts_by_account(*BOB).into_inner(), vec![task_id]);
+		assert_eq!(Task::receipts_by_organization(org_id).into_inner(), vec![task_id]);
+		assert_eq!(Task::account_receipts(&*BOB)[0].task_id, task_id);
+		assert_eq!(Task::organization_receipts(&org_id)[0].task_id, task_id);
+
+		// Assert the completed task of the volunteer's profile resolves to the receipt;
+		let completed_task = Profile::completed_tasks(*BOB).unwrap()[0];
+		assert!(Task::receipts(completed_task).is_some());
+	});
+}
+
+#[test]
+fn receipts_are_bounded_and_pruned() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+
+		let mut accepted_tasks = vec![];
+		for n in 1..=3 {
+			assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(n), attachments(), keywords(), None, Some(x()), Some(y())));
+			let task_id = Task::tasks_owned(*TED)[0];
+			assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+			assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+			assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+			accepted_tasks.push(task_id);
+		}
+
+		// Assert the account index keeps only the most recent receipts;
+		assert_eq!(Task::receipts_by_account(*BOB).into_inner(), vec![accepted_tasks[1], accepted_tasks[2]]);
+		assert!(Task::receipts(accepted_tasks[0]).is_some());
+
+		// Assert receipts are pruned once they outlive the receipt longevity;
+		run_to_block(1 + ReceiptLongevity::get());
+		for task_id in accepted_tasks {
+			assert!(Task::receipts(task_id).is_none());
+		}
+		assert!(Task::receipts_by_account(*BOB).is_empty());
+		assert!(Task::receipts_by_account(*TED).is_empty());
+	});
+}
+
+#[test]
+fn subtasks_are_funded_from_the_parent_budget() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let org_id = create_organization();
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), Some(org_id), Some(x()), Some(y())));
+		let parent_id = Task::tasks_owned(*TED)[0];
+
+		// Only the initiator can split the budget, and no more than the parent budget;
+		assert_noop!(Task::create_subtask(Origin::signed(*BOB), parent_id, title2(), spec(), 4, task_deadline(1), attachments(), keywords(), true), Error::<Test>::OnlyInitiatorUpdatesTask);
+		assert_noop!(Task::create_subtask(Origin::signed(*TED), parent_id, title2(), spec(), BUDGET2 + 1, task_deadline(1), attachments(), keywords(), true), Error::<Test>::SubtaskBudgetExceeded);
+
+		assert_ok!(Task::create_subtask(Origin::signed(*TED), parent_id, title2(), spec(), 4, task_deadline(1), attachments(), keywords(), true));
+		let task_id = Task::subtasks(parent_id)[0];
+		System::assert_last_event(Event::Task(crate::Event::SubtaskCreated(*TED, parent_id, task_id)));
+
+		// Assert the budget has moved from the parent escrow into the subtask escrow;
+		assert_eq!(Task::tasks(parent_id).unwrap().budget, BUDGET2 - 4);
+		assert_eq!(Task::escrowed_balance(&parent_id), BUDGET2 - 4);
+		assert_eq!(Task::escrowed_balance(&task_id), 4);
+		assert_eq!(Balances::balance(&*TED), 1000 - BUDGET2);
+
+		// Assert the subtask belongs to its parent and its organization;
+		let subtask = Task::tasks(task_id).expect("should find the subtask");
+		assert_eq!(subtask.parent, Some(parent_id));
+		assert_eq!(subtask.organization, Some(org_id));
+
+		// Assert subtasks can be nested up to the maximum depth;
+		assert_ok!(Task::create_subtask(Origin::signed(*TED), task_id, title(), spec(), 2, task_deadline(1), attachments(), keywords(), true));
+		let nested_id = Task::subtasks(task_id)[0];
+		assert_noop!(Task::create_subtask(Origin::signed(*TED), nested_id, title(), spec(), 1, task_deadline(1), attachments(), keywords(), true), Error::<Test>::SubtaskTooDeep);
+		assert_eq!(Task::subtree(&parent_id), vec![task_id, nested_id]);
+	});
+}
+
+#[test]
+fn removing_a_parent_cascades_to_its_subtasks() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let parent_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::create_subtask(Origin::signed(*TED), parent_id, title(), spec(), 3, task_deadline(1), attachments(), keywords(), true));
+		assert_ok!(Task::create_subtask(Origin::signed(*TED), parent_id, title2(), spec(), 4, task_deadline(1), attachments(), keywords(), true));
+		assert_ok!(Task::create_subtask(Origin::signed(*TED), parent_id, title2(), spec2(), 2, task_deadline(1), attachments(), keywords(), true));
+		let subtasks = Task::subtasks(parent_id);
+		let (started_id, created_id, removed_id) = (subtasks[0], subtasks[1], subtasks[2]);
+		assert_ok!(Task::start_task(Origin::signed(*BOB), started_id));
+
+		// Assert the budget of a removed subtask flows back into its parent;
+		assert_ok!(Task::remove_task(Origin::signed(*TED), removed_id));
+		assert_eq!(Task::tasks(parent_id).unwrap().budget, BUDGET2 - 3 - 4);
+		assert_eq!(Task::escrowed_balance(&parent_id), BUDGET2 - 3 - 4);
+		assert_eq!(Task::subtasks(parent_id).into_inner(), vec![started_id, created_id]);
+
+		// Remove the parent;
+		assert_ok!(Task::remove_task(Origin::signed(*TED), parent_id));
+
+		// Assert the created subtask is removed and the started one is detached;
+		assert!(Task::tasks(parent_id).is_none());
+		assert!(Task::tasks(created_id).is_none());
+		assert!(Task::subtasks(parent_id).is_empty());
+		assert_eq!(Task::tasks(started_id).unwrap().parent, None);
+
+		// Assert everything but the budget of the started subtask is refunded;
+		assert_eq!(Balances::balance(&*TED), 1000 - 3);
+		assert_eq!(Task::escrowed_balance(&started_id), 3);
+	});
+}
+
+#[test]
+fn removing_a_parent_walks_a_bounded_number_of_subtasks() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let parent_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::create_subtask(Origin::signed(*TED), parent_id, title(), spec(), 4, task_deadline(1), attachments(), keywords(), true));
+		assert_ok!(Task::create_subtask(Origin::signed(*TED), parent_id, title2(), spec(), 2, task_deadline(1), attachments(), keywords(), true));
+		assert_ok!(Task::create_subtask(Origin::signed(*TED), parent_id, title2(), spec2(), 2, task_deadline(1), attachments(), keywords(), true));
+		let first_id = Task::subtasks(parent_id)[0];
+		assert_ok!(Task::create_subtask(Origin::signed(*TED), first_id, title(), spec(), 2, task_deadline(1), attachments(), keywords(), true));
+
+		// Assert the subtree query returns at most the walk limit;
+		assert_eq!(Task::subtree(&parent_id).len(), MaxTasksWalked::get() as usize);
+
+		// Ensure a parent with more subtasks than the walk limit can't be removed at once;
+		assert_noop!(Task::remove_task(Origin::signed(*TED), parent_id), Error::<Test>::WalkLimitReached);
+
+		// Assert the tree can be removed from the bottom up;
+		assert_ok!(Task::remove_task(Origin::signed(*TED), first_id));
+		assert_ok!(Task::remove_task(Origin::signed(*TED), parent_id));
+		assert!(Task::tasks(parent_id).is_none());
+		assert_eq!(Task::task_count(), 0);
+		assert_eq!(Balances::balance(&*TED), 1000);
+	});
+}
+
+#[test]
+fn tasks_can_only_be_started_once_their_dependencies_are_accepted() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title2(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let first_id = Task::tasks_owned(*TED)[0];
+		let second_id = Task::tasks_owned(*TED)[1];
+
+		// Block the second task until the first one is accepted;
+		assert_ok!(Task::add_dependency(Origin::signed(*TED), second_id, first_id));
+		System::assert_last_event(Event::Task(crate::Event::DependencyAdded(*TED, second_id, first_id)));
+		assert_noop!(Task::add_dependency(Origin::signed(*TED), second_id, first_id), Error::<Test>::AlreadyDependsOn);
+		assert!(Task::is_blocked(&second_id));
+
+		// Assert dependencies can't form a cycle;
+		assert_noop!(Task::add_dependency(Origin::signed(*TED), first_id, second_id), Error::<Test>::DependencyCycle);
+		assert_noop!(Task::add_dependency(Origin::signed(*TED), first_id, first_id), Error::<Test>::DependencyCycle);
+
+		// Assert the blocked task can't be started;
+		assert_noop!(Task::start_task(Origin::signed(*BOB), second_id), Error::<Test>::TaskBlocked);
+
+		// Accept the first task;
+		assert_ok!(Task::start_task(Origin::signed(*BOB), first_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), first_id, deliverables()));
+		assert_ok!(Task::accept_task(Origin::signed(*TED), first_id));
+
+		// Assert the second task has been unblocked;
+		assert!(!Task::is_blocked(&second_id));
+		assert!(Task::dependents(first_id).is_empty());
+		assert_ok!(Task::start_task(Origin::signed(*BOB), second_id));
+	});
+}
+
+#[test]
+fn dependencies_are_dropped_when_removed() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title2(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title2(), spec2(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let first_id = Task::tasks_owned(*TED)[0];
+		let second_id = Task::tasks_owned(*TED)[1];
+		let third_id = Task::tasks_owned(*TED)[2];
+		assert_ok!(Task::add_dependency(Origin::signed(*TED), third_id, first_id));
+		assert_ok!(Task::add_dependency(Origin::signed(*TED), third_id, second_id));
+
+		// Assert only the initiator can remove a dependency;
+		assert_noop!(Task::remove_dependency(Origin::signed(*BOB), third_id, first_id), Error::<Test>::OnlyInitiatorUpdatesTask);
+		assert_ok!(Task::remove_dependency(Origin::signed(*TED), third_id, first_id));
+		System::assert_last_event(Event::Task(crate::Event::DependencyRemoved(*TED, third_id, first_id)));
+		assert_noop!(Task::remove_dependency(Origin::signed(*TED), third_id, first_id), Error::<Test>::DependencyNotExist);
+		assert!(Task::is_blocked(&third_id));
+
+		// Assert removing a task drops the dependencies on it;
+		assert_ok!(Task::remove_task(Origin::signed(*TED), second_id));
+		assert!(Task::dependencies(third_id).is_empty());
+		assert!(!Task::is_blocked(&third_id));
+	});
+}
+
+#[test]
+fn dependency_checks_walk_a_bounded_number_of_tasks() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		for budget in 1..=5 {
+			assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), budget, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		}
+		let tasks = Task::tasks_owned(*TED);
+
+		// Chain the first four tasks, each depending on the previous one;
+		for index in 1..4 {
+			assert_ok!(Task::add_dependency(Origin::signed(*TED), tasks[index], tasks[index - 1]));
+		}
+
+		// Ensure a dependency whose cycle check walks more tasks than the limit is rejected;
+		assert_noop!(Task::add_dependency(Origin::signed(*TED), tasks[4], tasks[3]), Error::<Test>::WalkLimitReached);
+		assert_ok!(Task::add_dependency(Origin::signed(*TED), tasks[4], tasks[2]));
+	});
+}
+
+#[test]
+fn tasks_can_be_queried_page_by_page() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let org_id = create_organization();
+		let tagged: BoundedVec<u8, MaxKeywordsLen> = vec![7u8, 9].try_into().unwrap();
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), Some(org_id), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title2(), spec(), BUDGET, get_deadline(2), attachments(), tagged, None, Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title2(), spec2(), BUDGET, get_deadline(3), attachments(), keywords(), None, Some(x()), Some(y())));
+		let owned = Task::tasks_owned(*TED);
+		assert_ok!(Task::start_task(Origin::signed(*BOB), owned[2]));
+
+		// Assert pages are filled up to the limit, and the cursor leads to the next page;
+		let (first_page, cursor) = Task::tasks_by_status(TaskStatus::Created, None, 1);
+		assert_eq!(first_page.len(), 1);
+		assert!(cursor.is_some());
+		let (second_page, cursor) = Task::tasks_by_status(TaskStatus::Created, cursor, 1);
+		assert_eq!(second_page.len(), 1);
+		assert_ne!(first_page[0].task_id, second_page[0].task_id);
+		let (last_page, cursor) = Task::tasks_by_status(TaskStatus::Created, cursor, 1);
+		assert!(last_page.is_empty());
+		assert!(cursor.is_none());
+
+		// Assert tasks can be queried by owner;
+		let (page, cursor) = Task::tasks_by_owner(&*TED, None, 1);
+		assert_eq!(page[0].task_id, owned[0]);
+		let (page, cursor) = Task::tasks_by_owner(&*TED, cursor, 10);
+		assert_eq!(page.iter().map(|task| task.task_id).collect::<Vec<_>>(), vec![owned[1]]);
+		assert!(cursor.is_none());
+		assert_eq!(Task::tasks_by_owner(&*BOB, None, 10).0[0].task_id, owned[2]);
+
+		// Assert tasks can be queried by organization, keyword and deadline;
+		assert_eq!(Task::tasks_by_organization(org_id, None, 10).0[0].task_id, owned[0]);
+		let (page, _) = Task::tasks_by_keyword(&[9u8], None, 10);
+		assert_eq!(page.len(), 1);
+		assert_eq!(page[0].task_id, owned[1]);
+		let (page, _) = Task::tasks_expiring_before(get_deadline_block(2), None, 10);
+		assert_eq!(page.len(), 1);
+		assert_eq!(page[0].task_id, owned[0]);
+	});
+}
+
+#[test]
+fn keyword_queries_scan_a_bounded_amount_of_tasks() {
+	new_test_ext().execute_with(|| {
+		// Setup state, a single tagged task among more tasks than a query scans;
+		let tagged: BoundedVec<u8, MaxKeywordsLen> = vec![7u8, 9].try_into().unwrap();
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), tagged, None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		let mut untagged = Task::tasks(task_id).unwrap();
+		untagged.keywords = keywords();
+		for i in 0..crate::MAX_TASKS_SCANNED as u64 {
+			untagged.task_id = H256::from_low_u64_be(i);
+			crate::Tasks::<Test>::insert(untagged.task_id, untagged.clone());
+		}
+
+		// Assert a scan stops at its limit with a cursor, and the query finds the task page by page;
+		let (_, cursor) = Task::tasks_by_keyword(&[9u8], None, 10);
+		assert!(cursor.is_some());
+		let (mut found, mut cursor) = (Vec::new(), None);
+		for _ in 0..3 {
+			let (page, next) = Task::tasks_by_keyword(&[9u8], cursor, 10);
+			found.extend(page.into_iter().map(|task| task.task_id));
+			cursor = next;
+			if cursor.is_none() {
+				break;
+			}
+		}
+		assert_eq!(found, vec![task_id]);
+		assert!(cursor.is_none());
+	});
+}
+
+#[test]
+fn task_indexes_follow_the_status_and_removal_of_tasks() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let org_id = create_organization();
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), Some(org_id), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title2(), spec(), BUDGET, get_deadline(2), attachments(), keywords(), None, Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title2(), spec2(), BUDGET, get_deadline(3), attachments(), keywords(), None, Some(x()), Some(y())));
+		let owned = Task::tasks_owned(*TED);
+		let task_ids = |status| Task::tasks_by_status(status, None, 10).0.into_iter().map(|task| task.task_id).collect::<Vec<_>>();
+
+		// Ensure a task moves between the status indexes, and leaves them once accepted;
+		assert_ok!(Task::start_task(Origin::signed(*BOB), owned[2]));
+		assert_eq!(task_ids(TaskStatus::InProgress), vec![owned[2]]);
+		assert!(!task_ids(TaskStatus::Created).contains(&owned[2]));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), owned[2], deliverables()));
+		assert_eq!(task_ids(TaskStatus::Completed), vec![owned[2]]);
+		assert!(task_ids(TaskStatus::InProgress).is_empty());
+		assert_ok!(Task::accept_task(Origin::signed(*TED), owned[2]));
+		assert!(task_ids(TaskStatus::Completed).is_empty());
+
+		// Ensure expiring tasks are paged from one expiring block to the next, in block order, started tasks left out;
+		let (first_page, cursor) = Task::tasks_expiring_before(get_deadline_block(4), None, 1);
+		let (second_page, cursor) = Task::tasks_expiring_before(get_deadline_block(4), cursor, 1);
+		let (last_page, cursor) = Task::tasks_expiring_before(get_deadline_block(4), cursor, 1);
+		assert_eq!(first_page[0].task_id, owned[0]);
+		assert_eq!(second_page[0].task_id, owned[1]);
+		assert!(last_page.is_empty());
+		assert!(cursor.is_none());
+
+		// Ensure a removed task leaves the indexes of its status and organization;
+		assert_eq!(Task::tasks_by_organization(org_id, None, 10).0.len(), 1);
+		assert_ok!(Task::remove_task(Origin::signed(*TED), owned[0]));
+		assert!(Task::tasks_by_organization(org_id, None, 10).0.is_empty());
+		assert_eq!(task_ids(TaskStatus::Created), vec![owned[1]]);
+	});
+}
+
+#[test]
+fn stale_tasks_can_be_reclaimed_after_the_delivery_deadline() {
+	new_test_ext().execute_with(|| {
+		// Setup state, the volunteer has earned reputation on an earlier task;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let earlier_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::start_task(Origin::signed(*BOB), earlier_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), earlier_id, deliverables()));
+		assert_ok!(Task::accept_task(Origin::signed(*TED), earlier_id));
+		assert_eq!(Profile::profiles(*BOB).unwrap().reputation, 1);
+
+		assert_ok!(Task::create_task(Origin::signed(*TED), title2(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+
+		// Assert the delivery deadline is the task deadline;
+		let task = Task::tasks(task_id).unwrap();
+		let delivery_deadline = task.delivery_deadline.expect("started tasks have a delivery deadline");
+		assert_eq!(Some(delivery_deadline), task.deadline_block);
+
+		// Assert the task can't be reclaimed before the delivery deadline, nor by the volunteer;
+		assert_noop!(Task::reclaim_task(Origin::signed(*TED), task_id), Error::<Test>::DeliveryDeadlineNotReached);
+		run_to_block(delivery_deadline + 1);
+		assert_noop!(Task::reclaim_task(Origin::signed(*BOB), task_id), Error::<Test>::NoPermissionToReclaim);
+
+		assert_ok!(Task::reclaim_task(Origin::signed(*TED), task_id));
+		System::assert_last_event(Event::Task(crate::Event::TaskReclaimed(*TED, task_id)));
+
+		// Assert the task is back with the initiator and the volunteer lost reputation;
+		let task = Task::tasks(task_id).unwrap();
+		assert_eq!(task.status, TaskStatus::Created);
+		assert_eq!(task.volunteer, *TED);
+		assert_eq!(task.delivery_deadline, None);
+		assert!(Task::tasks_owned(*TED).contains(&task_id));
+		assert!(!Task::tasks_owned(*BOB).contains(&task_id));
+		assert_eq!(Profile::profiles(*BOB).unwrap().reputation, 0);
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET);
+
+		// Assert the task expires right away, its deadline has passed;
+		run_to_block(delivery_deadline + 2);
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Expired);
+	});
+}
+
+#[test]
+fn lifecycle_hooks_are_called_on_every_change_of_a_task() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+
+		// Assert the hooks follow the task until it is accepted;
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::reject_task(Origin::signed(*TED), task_id, feedback()));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+		assert_ok!(Task::rate_task(Origin::signed(*BOB), task_id, *TED, 5, review()));
+		assert_eq!(LifecycleRecorder::events(&task_id), vec!["created", "started", "completed", "rejected", "completed", "accepted", "rated"]);
+
+		// Assert the hooks follow the task until it is removed;
+		assert_ok!(Task::create_task(Origin::signed(*TED), title2(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::abandon_task(Origin::signed(*BOB), task_id));
+		run_to_block(get_deadline_block(1) + 1);
+		assert_ok!(Task::remove_task(Origin::signed(*TED), task_id));
+		assert_eq!(LifecycleRecorder::events(&task_id), vec!["created", "started", "abandoned", "expired", "removed"]);
+	});
+}
+
+#[test]
+fn volunteers_can_abandon_tasks() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_noop!(Task::abandon_task(Origin::signed(*BOB), task_id), Error::<Test>::NoPermissionToAbandon);
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+
+		// Assert only the volunteer can abandon the task;
+		assert_noop!(Task::abandon_task(Origin::signed(*TED), task_id), Error::<Test>::NoPermissionToAbandon);
+		assert_ok!(Task::abandon_task(Origin::signed(*BOB), task_id));
+		System::assert_last_event(Event::Task(crate::Event::TaskAbandoned(*BOB, task_id)));
+
+		// Assert the task can be started by another volunteer;
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Created);
+		assert!(!Task::tasks_owned(*BOB).contains(&task_id));
+		assert_ok!(Task::start_task(Origin::signed(*ALICE), task_id));
+		assert_eq!(Task::tasks(task_id).unwrap().volunteer, *ALICE);
+	});
+}
+
+#[test]
+fn volunteer_bond_is_reserved_on_start_and_returned_on_acceptance() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), 100, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+
+		// Ensure only the initiator requires a bond, and volunteers who can't reserve it can't start the task;
+		assert_noop!(Task::set_volunteer_bond(Origin::signed(*BOB), task_id, Some(VolunteerBond::Amount(20))), Error::<Test>::OnlyInitiatorUpdatesTask);
+		assert_ok!(Task::set_volunteer_bond(Origin::signed(*TED), task_id, Some(VolunteerBond::Amount(2000))));
+		assert_noop!(Task::start_task(Origin::signed(*BOB), task_id), Error::<Test>::CannotReserveBond);
+
+		// Assert the bond is part of the task, and is reserved from the volunteer on start;
+		assert_ok!(Task::set_volunteer_bond(Origin::signed(*TED), task_id, Some(VolunteerBond::Percent(Percent::from_percent(10)))));
+		System::assert_last_event(Event::Task(crate::Event::VolunteerBondSet(*TED, task_id)));
+		assert_eq!(Task::tasks(task_id).unwrap().bond, Some(VolunteerBond::Percent(Percent::from_percent(10))));
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_eq!(Task::bonds(task_id, *BOB), Some(10));
+		assert_eq!(Balances::reserved_balance(&*BOB), 10);
+		assert_noop!(Task::set_volunteer_bond(Origin::signed(*TED), task_id, None), Error::<Test>::NoPermissionToUpdate);
+
+		// Assert the bond is returned together with the payout;
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+		assert_eq!(Task::bonds(task_id, *BOB), None);
+		assert_eq!(Balances::reserved_balance(&*BOB), 0);
+		assert_eq!(Balances::free_balance(&*BOB), 1000 + 100);
+	});
+}
+
+#[test]
+fn volunteer_bond_is_slashed_to_the_initiator_on_abandonment() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), 100, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::set_volunteer_bond(Origin::signed(*TED), task_id, Some(VolunteerBond::Amount(20))));
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+
+		// Assert the bond of the volunteer who leaves goes to the initiator;
+		assert_ok!(Task::abandon_task(Origin::signed(*BOB), task_id));
+		System::assert_has_event(Event::Task(crate::Event::BondSlashed(*BOB, task_id, 20)));
+		assert_eq!(Task::bonds(task_id, *BOB), None);
+		assert_eq!(Balances::reserved_balance(&*BOB), 0);
+		assert_eq!(Balances::free_balance(&*BOB), 1000 - 20);
+		assert_eq!(Balances::free_balance(&*TED), 1000 - 100 + 20);
+
+		// Assert the next volunteer reserves the bond again;
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_eq!(Balances::reserved_balance(&*BOB), 20);
+	});
+}
+
+#[test]
+fn team_volunteers_who_stay_keep_their_slot() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::set_volunteer_slots(Origin::signed(*TED), task_id, shares(vec![6, 4])));
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::start_task(Origin::signed(*ALICE), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+
+		// Assert a volunteer who completed their slot can't abandon the task;
+		assert_noop!(Task::abandon_task(Origin::signed(*BOB), task_id), Error::<Test>::NoPermissionToAbandon);
+		assert_ok!(Task::abandon_task(Origin::signed(*ALICE), task_id));
+
+		// Assert the completed slot is kept, and the abandoned one is free again;
+		let task = Task::tasks(task_id).unwrap();
+		assert_eq!(task.status, TaskStatus::Created);
+		assert_eq!(task.slots[0].volunteer, Some(*BOB));
+		assert!(task.slots[0].completed);
+		assert_eq!(task.slots[1].volunteer, None);
+
+		// Assert the task is completed once the free slot has been taken and completed;
+		assert_ok!(Task::start_task(Origin::signed(*EVE), task_id));
+		assert!(Task::tasks_owned(*EVE).contains(&task_id));
+		assert!(!Task::tasks_owned(*BOB).contains(&task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*EVE), task_id, deliverables()));
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Completed);
+	});
+}
+
+#[test]
+fn completed_tasks_are_accepted_after_the_review_period() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+
+		// Assert the task waits for the initiator during the review period;
+		run_to_block(ReviewPeriod::get());
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Completed);
+
+		// Assert the task is accepted once the review period has passed;
+		run_to_block(1 + ReviewPeriod::get());
+		System::assert_last_event(Event::Task(crate::Event::TaskAutoAccepted(task_id)));
+		assert!(Task::tasks(task_id).is_none());
+		assert!(Task::tasks_owned(*TED).is_empty());
+		assert_eq!(Balances::balance(&*BOB), 1000 + BUDGET);
+		assert_eq!(Task::escrowed_balance(&task_id), 0);
+		assert_eq!(Profile::profiles(*BOB).unwrap().reputation, 1);
+		assert_eq!(Profile::profiles(*TED).unwrap().reputation, 1);
+		assert_eq!(Profile::completed_tasks(*BOB).unwrap().into_inner(), vec![task_id]);
+		assert_eq!(Task::receipts(task_id).unwrap().amount, BUDGET);
+	});
+}
+
+#[test]
+fn reviewed_tasks_are_not_accepted_automatically() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+
+		// Assert a rejected task stays with the volunteer;
+		assert_ok!(Task::reject_task(Origin::signed(*TED), task_id, feedback()));
+		run_to_block(1 + ReviewPeriod::get());
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::InProgress);
+
+		// Assert the review period starts again when the task is completed again;
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		run_to_block(2 * ReviewPeriod::get());
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Completed);
+		run_to_block(1 + 2 * ReviewPeriod::get());
+		assert!(Task::tasks(task_id).is_none());
+		assert_eq!(Balances::balance(&*BOB), 1000 + BUDGET);
+	});
+}
+
+#[test]
+fn completed_tasks_that_fail_to_be_accepted_are_retried() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::start_task(Origin::signed(*DAVE), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*DAVE), task_id, deliverables()));
+
+		// The payout would leave the volunteer with less than the existential deposit;
+		ExistentialDeposit::set(&10);
+		run_to_block(1 + ReviewPeriod::get());
+		assert!(matches!(System::events().last().unwrap().event, Event::Task(crate::Event::TaskAutoAcceptFailed(id, _)) if id == task_id));
+
+		// Assert the task is kept, and accepted after another review period;
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Completed);
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET);
+
+		ExistentialDeposit::set(&1);
+		run_to_block(1 + 2 * ReviewPeriod::get());
+		System::assert_last_event(Event::Task(crate::Event::TaskAutoAccepted(task_id)));
+		assert!(Task::tasks(task_id).is_none());
+		assert_eq!(Balances::balance(&*DAVE), BUDGET);
+	});
+}
+
+#[test]
+fn completed_tasks_are_reviewed_again_after_a_dismissed_dispute() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let task_id = create_rejected_task(None);
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::set_arbitrator_pool(Origin::root(), arbitrators(vec![*DAVE])));
+		assert_ok!(Task::open_dispute(Origin::signed(*TED), task_id, reason()));
+
+		// Let the voting period pass without votes;
+		run_to_block(1 + DisputeVotingPeriod::get());
+		System::assert_last_event(Event::Task(crate::Event::DisputeDismissed(task_id)));
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Completed);
+
+		// Assert the review period starts again from the dismissal;
+		run_to_block(1 + ReviewPeriod::get());
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Completed);
+		run_to_block(1 + DisputeVotingPeriod::get() + ReviewPeriod::get());
+		System::assert_last_event(Event::Task(crate::Event::TaskAutoAccepted(task_id)));
+		assert!(Task::tasks(task_id).is_none());
+		assert_eq!(Balances::balance(&*BOB), 1000 + BUDGET2);
+	});
+}
+
+#[test]
+fn asset_budget_is_escrowed_and_paid_on_acceptance() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task_with_options(Origin::signed(*TED), title(), spec(), BUDGET, task_deadline(1), attachments(), keywords(), None, Some(x()), Some(y()), true, Some(USD)));
+		let task_id = Task::tasks_owned(*TED)[0];
+
+		// Assert the budget is escrowed in the asset, not in the native currency;
+		assert_eq!(Task::tasks(task_id).unwrap().asset, Some(USD));
+		assert_eq!(Assets::balance(USD, *TED), 1000 - BUDGET);
+		assert_eq!(Balances::balance(&*TED), 1000);
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET);
+
+		// Assert the volunteer is paid in the asset;
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+		assert_eq!(Assets::balance(USD, *BOB), BUDGET);
+		assert_eq!(Balances::balance(&*BOB), 1000);
+		assert_eq!(Task::escrowed_balance(&task_id), 0);
+
+		let receipt = Task::receipts(task_id).expect("should find the receipt");
+		assert_eq!(receipt.amount, BUDGET);
+		assert_eq!(receipt.asset, Some(USD));
+	});
+}
+
+#[test]
+fn asset_budget_is_changed_and_refunded_in_the_asset() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+
+		// Ensure the creator holds enough of the asset;
+		assert_noop!(Task::create_task_with_options(Origin::signed(*BOB), title(), spec(), BUDGET, task_deadline(1), attachments(), keywords(), None, Some(x()), Some(y()), true, Some(USD)), Error::<Test>::NotEnoughBalance);
+
+		assert_ok!(Task::create_task_with_options(Origin::signed(*TED), title(), spec(), BUDGET, task_deadline(1), attachments(), keywords(), None, Some(x()), Some(y()), true, Some(USD)));
+		let task_id = Task::tasks_owned(*TED)[0];
+
+		// Assert a budget change is moved in the asset;
+		assert_ok!(Task::update_task(Origin::signed(*TED), task_id, title2(), spec2(), BUDGET2, get_deadline(1), attachments2(), keywords2(), None, Some(x()), Some(y())));
+		assert_eq!(Task::tasks(task_id).unwrap().asset, Some(USD));
+		assert_eq!(Assets::balance(USD, *TED), 1000 - BUDGET2);
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET2);
+
+		// Assert the budget is refunded in the asset;
+		assert_ok!(Task::remove_task(Origin::signed(*TED), task_id));
+		assert_eq!(Assets::balance(USD, *TED), 1000);
+		assert_eq!(Balances::balance(&*TED), 1000);
+	});
+}
+
+#[test]
+fn recurring_task_spawns_a_task_every_interval() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_recurring_task(Origin::signed(*TED), title(), spec(), BUDGET, attachments(), keywords(), None, Some(x()), Some(y()), true, None, 10, 3));
+
+		// Assert the first occurrence is spawned right away;
+		assert_eq!(Task::tasks_owned(*TED).len(), 1);
+		assert_eq!(Balances::balance(&*TED), 1000 - BUDGET);
+		let recurring_id = RecurringTasksPerBlock::<Test>::get(11)[0];
+		assert_eq!(Task::recurring_tasks(recurring_id).unwrap().occurrences, 1);
+
+		// Assert an occurrence is spawned every interval, due when the next one is spawned;
+		run_to_block(11);
+		let task_id = Task::tasks_owned(*TED)[1];
+		System::assert_last_event(Event::Task(crate::Event::RecurringTaskSpawned(recurring_id, task_id)));
+		assert_eq!(Task::tasks(task_id).unwrap().deadline_block, Some(21));
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET);
+
+		run_to_block(21);
+		assert_eq!(Task::tasks_owned(*TED).len(), 3);
+		assert_eq!(Balances::balance(&*TED), 1000 - 3 * BUDGET);
+
+		// Assert no occurrence is spawned past the maximum;
+		assert!(Task::recurring_tasks(recurring_id).is_none());
+		run_to_block(31);
+		assert_eq!(Task::tasks_owned(*TED).len(), 3);
+	});
+}
+
+#[test]
+fn recurring_task_stops_when_initiator_cannot_fund_it() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_recurring_task(Origin::signed(*TED), title(), spec(), 400, attachments(), keywords(), None, Some(x()), Some(y()), true, None, 10, 5));
+		let recurring_id = RecurringTasksPerBlock::<Test>::get(11)[0];
+
+		run_to_block(11);
+		assert_eq!(Balances::balance(&*TED), 200);
+
+		// Assert the recurring task stops once an occurrence can't be funded;
+		run_to_block(21);
+		System::assert_last_event(Event::Task(crate::Event::RecurringTaskStopped(recurring_id)));
+		assert!(Task::recurring_tasks(recurring_id).is_none());
+		assert!(RecurringTasksPerBlock::<Test>::get(31).is_empty());
+		assert_eq!(Task::tasks_owned(*TED).len(), 2);
+		assert_eq!(Balances::balance(&*TED), 200);
+	});
+}
+
+#[test]
+fn recurring_task_retries_an_occurrence_that_fails_to_spawn() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_recurring_task(Origin::signed(*TED), title(), spec(), BUDGET, attachments(), keywords(), None, Some(x()), Some(y()), true, None, 10, 3));
+		let recurring_id = RecurringTasksPerBlock::<Test>::get(11)[0];
+
+		// The budget of the occurrence is below the existential deposit;
+		ExistentialDeposit::set(&10);
+		run_to_block(11);
+		System::assert_last_event(Event::Task(crate::Event::RecurringTaskSpawnFailed(recurring_id, Error::<Test>::BudgetBelowMinimum.into())));
+
+		// Assert the recurring task is kept, and the occurrence retried after another interval;
+		let recurring = Task::recurring_tasks(recurring_id).unwrap();
+		assert_eq!((recurring.occurrences, recurring.next_occurrence), (1, 21));
+		assert_eq!(Task::tasks_owned(*TED).len(), 1);
+		assert_eq!(Balances::balance(&*TED), 1000 - BUDGET);
+
+		ExistentialDeposit::set(&1);
+		run_to_block(21);
+		let task_id = Task::tasks_owned(*TED)[1];
+		System::assert_last_event(Event::Task(crate::Event::RecurringTaskSpawned(recurring_id, task_id)));
+		assert_eq!(Task::recurring_tasks(recurring_id).unwrap().occurrences, 2);
+	});
+}
+
+#[test]
+fn recurring_task_can_be_cancelled_by_initiator() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+
+		// Ensure the recurrence is valid;
+		assert_noop!(Task::create_recurring_task(Origin::signed(*TED), title(), spec(), BUDGET, attachments(), keywords(), None, Some(x()), Some(y()), true, None, 5, 3), Error::<Test>::RecurrenceIntervalTooShort);
+		assert_noop!(Task::create_recurring_task(Origin::signed(*TED), title(), spec(), BUDGET, attachments(), keywords(), None, Some(x()), Some(y()), true, None, 10, 0), Error::<Test>::NoOccurrences);
+
+		assert_ok!(Task::create_recurring_task(Origin::signed(*TED), title(), spec(), BUDGET, attachments(), keywords(), None, Some(x()), Some(y()), true, None, 10, 3));
+		let recurring_id = RecurringTasksPerBlock::<Test>::get(11)[0];
+
+		// Ensure only the initiator can cancel it;
+		assert_noop!(Task::cancel_recurring_task(Origin::signed(*BOB), recurring_id), Error::<Test>::NoPermissionToRemove);
+		assert_ok!(Task::cancel_recurring_task(Origin::signed(*TED), recurring_id));
+		System::assert_last_event(Event::Task(crate::Event::RecurringTaskCancelled(*TED, recurring_id)));
+
+		// Assert spawned tasks are kept, but no new occurrence is spawned;
+		assert!(Task::recurring_tasks(recurring_id).is_none());
+		run_to_block(11);
+		assert_eq!(Task::tasks_owned(*TED).len(), 1);
+		assert_eq!(Balances::balance(&*TED), 1000 - BUDGET);
+	});
+}
+
+#[test]
+fn task_created_from_template_records_template_version() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_template(Origin::signed(*TED), title(), spec(), attachments(), keywords(), None, true));
+		let template_id = Templates::<Test>::iter_keys().next().unwrap();
+		System::assert_last_event(Event::Task(crate::Event::TemplateCreated(*TED, template_id)));
+
+		// Ensure only the owner can use and update the template;
+		assert_noop!(Task::create_task_from_template(Origin::signed(*BOB), template_id, BUDGET, task_deadline(1), None, None), Error::<Test>::NoPermissionForTemplate);
+		assert_noop!(Task::update_template(Origin::signed(*BOB), template_id, title2(), spec2(), attachments2(), keywords2(), false), Error::<Test>::NoPermissionForTemplate);
+
+		// Assert the task is filled in from the template;
+		assert_ok!(Task::create_task_from_template(Origin::signed(*TED), template_id, BUDGET, task_deadline(1), Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		let task = Task::tasks(task_id).expect("should find the task");
+		assert_eq!(task.title, title());
+		assert_eq!(task.specification, spec());
+		assert_eq!(task.budget, BUDGET);
+		assert_eq!(task.location, Some((x(), y())));
+		assert_eq!(task.template, Some((template_id, 1)));
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET);
+
+		// Assert updating the template bumps its version for new tasks only;
+		assert_ok!(Task::update_template(Origin::signed(*TED), template_id, title2(), spec2(), attachments2(), keywords2(), false));
+		System::assert_last_event(Event::Task(crate::Event::TemplateUpdated(*TED, template_id, 2)));
+		assert_ok!(Task::create_task_from_template(Origin::signed(*TED), template_id, BUDGET2, task_deadline(2), None, None));
+		let task_id2 = Task::tasks_owned(*TED)[1];
+		let task2 = Task::tasks(task_id2).expect("should find the task");
+		assert_eq!(task2.title, title2());
+		assert!(!task2.open);
+		assert_eq!(task2.template, Some((template_id, 2)));
+		assert_eq!(Task::tasks(task_id).unwrap().template, Some((template_id, 1)));
+	});
+}
+
+#[test]
+fn organization_templates_are_shared_by_members() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let org_id = create_organization();
+		assert_ok!(Dao::add_members(Origin::signed(*ALICE), org_id, *TED));
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+
+		// Ensure only members can create templates for the organization;
+		assert_noop!(Task::create_template(Origin::signed(*BOB), title(), spec(), attachments(), keywords(), Some(org_id), true), Error::<Test>::NoPermissionForTemplate);
+		assert_ok!(Task::create_template(Origin::signed(*ALICE), title(), spec(), attachments(), keywords(), Some(org_id), true));
+		let template_id = Templates::<Test>::iter_keys().next().unwrap();
+
+		// Assert other members create tasks of the organization from it;
+		assert_ok!(Task::create_task_from_template(Origin::signed(*TED), template_id, BUDGET, task_deadline(1), None, None));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_eq!(Task::tasks(task_id).unwrap().organization, Some(org_id));
+		assert_noop!(Task::create_task_from_template(Origin::signed(*BOB), template_id, BUDGET, task_deadline(1), None, None), Error::<Test>::NoPermissionForTemplate);
+
+		// Assert removing the template keeps the tasks created from it;
+		assert_noop!(Task::remove_template(Origin::signed(*BOB), template_id), Error::<Test>::NoPermissionForTemplate);
+		assert_ok!(Task::remove_template(Origin::signed(*TED), template_id));
+		System::assert_last_event(Event::Task(crate::Event::TemplateRemoved(*TED, template_id)));
+		assert!(Task::templates(template_id).is_none());
+		assert!(Task::tasks(task_id).is_some());
+		assert_noop!(Task::create_task_from_template(Origin::signed(*TED), template_id, BUDGET, task_deadline(1), None, None), Error::<Test>::TemplateNotExist);
+	});
+}
+
+#[test]
+fn organization_task_is_funded_and_refunded_by_organization_account() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let org_id = create_organization();
+		let org_account = Dao::account_id(&org_id);
+		assert_ok!(Dao::add_members(Origin::signed(*ALICE), org_id, *TED));
+		assert_ok!(Balances::transfer(Origin::signed(*ALICE), org_account, 100));
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+
+		// Ensure only members can draw on the organization account;
+		assert_noop!(Task::create_organization_task(Origin::signed(*BOB), title(), spec(), BUDGET2, task_deadline(1), attachments(), keywords(), org_id, Some(x()), Some(y()), true, None), Error::<Test>::NoPermissionToFund);
+		assert_noop!(Task::create_organization_task(Origin::signed(*TED), title(), spec(), 1000, task_deadline(1), attachments(), keywords(), org_id, Some(x()), Some(y()), true, None), Error::<Test>::NotEnoughBalance);
+
+		// Assert the budget is drawn from the organization account;
+		assert_ok!(Task::create_organization_task(Origin::signed(*TED), title(), spec(), BUDGET2, task_deadline(1), attachments(), keywords(), org_id, Some(x()), Some(y()), true, None));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_eq!(Task::tasks(task_id).unwrap().funder, org_account);
+		assert_eq!(Balances::balance(&org_account), 100 - BUDGET2);
+		assert_eq!(Balances::balance(&*TED), 1000);
+
+		// Assert budget changes and removal refund the organization account;
+		assert_ok!(Task::update_task(Origin::signed(*TED), task_id, title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), Some(org_id), Some(x()), Some(y())));
+		assert_eq!(Balances::balance(&org_account), 100 - BUDGET);
+		assert_noop!(Task::update_task(Origin::signed(*TED), task_id, title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())), Error::<Test>::NoPermissionToUpdate);
+		assert_ok!(Task::remove_task(Origin::signed(*TED), task_id));
+		assert_eq!(Balances::balance(&org_account), 100);
+		assert_eq!(Balances::balance(&*TED), 1000);
+	});
+}
+
+#[test]
+fn accepted_payout_gives_platform_fee_and_organization_cut() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		PlatformFee::set(&Permill::from_percent(10));
+		OrganizationFee::set(&Permill::from_percent(5));
+		let org_id = create_organization();
+		let org_account = Dao::account_id(&org_id);
+		assert_ok!(Dao::add_members(Origin::signed(*ALICE), org_id, *TED));
+		assert_ok!(Balances::transfer(Origin::signed(*ALICE), org_account, 200));
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_organization_task(Origin::signed(*TED), title(), spec(), 100, task_deadline(1), attachments(), keywords(), org_id, Some(x()), Some(y()), true, None));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		let issuance = Balances::total_issuance();
+
+		// Assert the fees are taken from the payout, and the volunteer is paid the rest;
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+		System::assert_has_event(Event::Task(crate::Event::PayoutFeesTaken(task_id, 10, 5, 85)));
+		assert_eq!(Balances::balance(&*BOB), 1000 + 85);
+		assert_eq!(Balances::balance(&org_account), 100 + 5);
+		assert_eq!(Balances::balance(&Task::account_id(&task_id)), 0);
+
+		// Assert the platform fee went to the fee destination, which burns it in the mock;
+		assert_eq!(Balances::total_issuance(), issuance - 10);
+
+		// Assert the receipt records what the volunteer was paid, net of the fees;
+		assert_eq!(Task::receipts(task_id).expect("should find the receipt").amount, 85);
+	});
+}
+
+#[test]
+fn asset_payout_gives_platform_fee_to_the_fee_account() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		PlatformFee::set(&Permill::from_percent(10));
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task_with_options(Origin::signed(*TED), title(), spec(), 100, task_deadline(1), attachments(), keywords(), None, Some(x()), Some(y()), true, Some(USD)));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+
+		// Assert the platform fee is taken in the asset, and transferred to the fee account;
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+		System::assert_has_event(Event::Task(crate::Event::PayoutFeesTaken(task_id, 10, 0, 90)));
+		assert_eq!(Assets::balance(USD, PlatformFeeAccount::get()), 10);
+		assert_eq!(Assets::balance(USD, *BOB), 90);
+		assert_eq!(Task::receipts(task_id).expect("should find the receipt").amount, 90);
+	});
+}
+
+#[test]
+fn only_treasurers_fund_organization_tasks_once_designated() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let org_id = create_organization();
+		let org_account = Dao::account_id(&org_id);
+		assert_ok!(Dao::add_members(Origin::signed(*ALICE), org_id, *TED));
+		assert_ok!(Balances::transfer(Origin::signed(*ALICE), org_account, 100));
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Dao::add_treasurer(Origin::signed(*ALICE), org_id, *BOB));
+
+		// Assert members are denied once the organization has treasurers;
+		assert_noop!(Task::create_organization_task(Origin::signed(*TED), title(), spec(), BUDGET, task_deadline(1), attachments(), keywords(), org_id, Some(x()), Some(y()), true, None), Error::<Test>::NoPermissionToFund);
+		assert_ok!(Task::create_organization_task(Origin::signed(*BOB), title(), spec(), BUDGET, task_deadline(1), attachments(), keywords(), org_id, Some(x()), Some(y()), true, None));
+		assert_eq!(Balances::balance(&org_account), 100 - BUDGET);
+	});
+}
+
+#[test]
+fn block_deadline_expires_on_its_block_whatever_the_block_time() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task_with_options(Origin::signed(*TED), title(), spec(), BUDGET, TaskDeadline::Block(11), attachments(), keywords(), None, Some(x()), Some(y()), true, None));
+		let task_id = Task::tasks_owned(*TED)[0];
+
+		// Assert both deadlines are stored, the timestamp being estimated;
+		let task = Task::tasks(task_id).unwrap();
+		assert!(task.deadline_by_block);
+		assert_eq!(task.deadline_block, Some(11));
+		assert_eq!(task.deadline, 10 * MILLISEC_PER_BLOCK);
+
+		// Assert the task expires on its block although blocks were built faster than expected;
+		run_to_block_with_block_time(10, MILLISEC_PER_BLOCK / 6);
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Created);
+		run_to_block_with_block_time(11, MILLISEC_PER_BLOCK / 6);
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Expired);
+		assert!(DyingTasksPerBlock::<Test>::get(11 + <Test as Config>::TaskLongevityAfterExpiration::get()).contains(&task_id));
+	});
+}
+
+#[test]
+fn timestamp_deadline_is_reanchored_when_blocks_are_faster() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, 10 * MILLISEC_PER_BLOCK, attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert!(!Task::tasks(task_id).unwrap().deadline_by_block);
+		assert_eq!(Task::tasks(task_id).unwrap().deadline_block, Some(11));
+
+		// Assert the task is moved to a later block while its deadline has not been reached;
+		run_to_block_with_block_time(11, MILLISEC_PER_BLOCK / 2);
+		let task = Task::tasks(task_id).unwrap();
+		assert_eq!(task.status, TaskStatus::Created);
+		assert_eq!(task.deadline_block, Some(16));
+		assert!(ExpiringTasksPerBlock::<Test>::get(16).contains(&task_id));
+		assert!(!DyingTasksPerBlock::<Test>::get(11 + <Test as Config>::TaskLongevityAfterExpiration::get()).contains(&task_id));
+
+		// Assert the task expires once the block timestamp reaches the deadline;
+		run_to_block_with_block_time(15, MILLISEC_PER_BLOCK);
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Created);
+		run_to_block_with_block_time(16, MILLISEC_PER_BLOCK);
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Expired);
+		assert!(DyingTasksPerBlock::<Test>::get(16 + <Test as Config>::TaskLongevityAfterExpiration::get()).contains(&task_id));
+	});
+}
+
+#[test]
+fn timestamp_deadline_is_not_expired_early_when_its_later_block_is_full() {
+	new_test_ext().execute_with(|| {
+		// Setup state, the block the task would be moved to is full;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, 10 * MILLISEC_PER_BLOCK, attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		let full: BoundedVec<H256, MaximumTasksPerBlock> = (0..MaximumTasksPerBlock::get() as u64)
+			.map(H256::from_low_u64_be)
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		ExpiringTasksPerBlock::<Test>::insert(16, full);
+
+		// Assert the task is kept on its block to be retried, instead of expiring before its deadline;
+		run_to_block_with_block_time(11, MILLISEC_PER_BLOCK / 2);
+		let task = Task::tasks(task_id).unwrap();
+		assert_eq!(task.status, TaskStatus::Created);
+		assert_eq!(task.deadline_block, Some(11));
+		assert!(ExpiringTasksPerBlock::<Test>::get(11).contains(&task_id));
+		assert_eq!(Task::next_expiring_block(), Some(11));
+		assert!(!DyingTasksPerBlock::<Test>::get(11 + <Test as Config>::TaskLongevityAfterExpiration::get()).contains(&task_id));
+
+		// Assert the task is moved to a later block with room on the next block;
+		run_to_block_with_block_time(12, MILLISEC_PER_BLOCK / 2);
+		let task = Task::tasks(task_id).unwrap();
+		assert_eq!(task.status, TaskStatus::Created);
+		let deadline_block = task.deadline_block.unwrap();
+		assert!(deadline_block > 16);
+		assert!(ExpiringTasksPerBlock::<Test>::get(deadline_block).contains(&task_id));
+		assert!(!ExpiringTasksPerBlock::<Test>::get(11).contains(&task_id));
+	});
+}
+
+#[test]
+fn timestamp_deadline_stops_tasks_from_starting_when_blocks_are_slower() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, 10 * MILLISEC_PER_BLOCK, attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_eq!(Task::tasks(task_id).unwrap().deadline_block, Some(11));
+
+		// Assert the task can't be started once the block timestamp reaches the deadline, before its deadline block;
+		run_to_block_with_block_time(6, 2 * MILLISEC_PER_BLOCK);
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Created);
+		assert_noop!(Task::start_task(Origin::signed(*BOB), task_id), Error::<Test>::TaskDeadlinePassed);
+
+		// Assert the task still expires on its deadline block;
+		run_to_block_with_block_time(11, 2 * MILLISEC_PER_BLOCK);
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::Expired);
+	});
+}
+
+#[test]
+fn expiry_beyond_the_weight_limit_is_carried_over() {
+	new_test_ext().execute_with(|| {
+		// Setup state, three tasks expire on the same block but only one fits the expiry weight;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		for budget in [BUDGET, BUDGET + 1, BUDGET + 2] {
+			assert_ok!(Task::create_task_with_options(Origin::signed(*TED), title(), spec(), budget, TaskDeadline::Block(11), attachments(), keywords(), None, Some(x()), Some(y()), true, None));
+		}
+		let expire_task_weight = <() as WeightInfo>::expire_task();
+		MaxExpiryWeight::set(&expire_task_weight);
+		let expired = || Task::tasks_owned(*TED).iter().filter(|id| Task::tasks(id).unwrap().status == TaskStatus::Expired).count();
+
+		// Assert a single task expires at the start of the block, the others are carried over;
+		run_to_block_with_block_time(11, MILLISEC_PER_BLOCK);
+		assert_eq!(expired(), 1);
+		assert_eq!(ExpiringTasksPerBlock::<Test>::get(11).len(), 2);
+		assert_eq!(Task::next_expiring_block(), Some(11));
+
+		// Assert the weight left at the end of the block is used;
+		Task::on_idle(11, expire_task_weight);
+		assert_eq!(expired(), 2);
+
+		// Assert the last task expires on the next block, and the queue moves on;
+		run_to_block_with_block_time(12, MILLISEC_PER_BLOCK);
+		assert_eq!(expired(), 3);
+		assert!(ExpiringTasksPerBlock::<Test>::get(11).is_empty());
+		assert_eq!(Task::next_expiring_block(), Some(13));
+		assert_eq!(DyingTasksPerBlock::<Test>::get(11 + <Test as Config>::TaskLongevityAfterExpiration::get()).len(), 2);
+		assert_eq!(DyingTasksPerBlock::<Test>::get(12 + <Test as Config>::TaskLongevityAfterExpiration::get()).len(), 1);
+	});
+}
+
+#[test]
+fn passed_deadlines_are_rejected_without_panicking() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		run_to_block(5);
+
+		// Ensure deadlines that have already passed are rejected;
+		let now = <Time as UnixTime>::now().as_millis() as u64;
+		assert_noop!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, now, attachments(), keywords(), None, Some(x()), Some(y())), Error::<Test>::IncorrectDeadlineTimestamp);
+		assert_noop!(Task::create_task_with_options(Origin::signed(*TED), title(), spec(), BUDGET, TaskDeadline::Block(5), attachments(), keywords(), None, Some(x()), Some(y()), true, None), Error::<Test>::IncorrectDeadlineBlock);
+
+		// Assert a deadline less than a block away expires on the next block;
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, now + 1, attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_eq!(Task::tasks(task_id).unwrap().deadline_block, Some(6));
+
+		// Ensure updating to a passed deadline is rejected, and switching to a block deadline is accepted;
+		assert_noop!(Task::update_task(Origin::signed(*TED), task_id, title(), spec(), BUDGET, now - 1, attachments(), keywords(), None, Some(x()), Some(y())), Error::<Test>::IncorrectDeadlineTimestamp);
+		assert_ok!(Task::update_task_with_options(Origin::signed(*TED), task_id, title(), spec(), BUDGET, TaskDeadline::Block(20), attachments(), keywords(), None, Some(x()), Some(y()), true));
+		assert!(Task::tasks(task_id).unwrap().deadline_by_block);
+		assert!(ExpiringTasksPerBlock::<Test>::get(20).contains(&task_id));
+		assert!(!ExpiringTasksPerBlock::<Test>::get(6).contains(&task_id));
+	});
+}
+
+fn review() -> BoundedVec<u8, MaxReviewLen> {
+	vec![1u8, 5].try_into().unwrap()
+}
+
+#[test]
+fn parties_of_accepted_task_rate_each_other() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+
+		// Assert tasks can't be rated before they are accepted;
+		assert_noop!(Task::rate_task(Origin::signed(*BOB), task_id, *TED, 5, review()), Error::<Test>::ReceiptNotExist);
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+
+		// Assert only the initiator and the volunteer rate each other, from 1 to 5 stars;
+		assert_noop!(Task::rate_task(Origin::signed(*ALICE), task_id, *TED, 5, review()), Error::<Test>::NoPermissionToRate);
+		assert_noop!(Task::rate_task(Origin::signed(*BOB), task_id, *BOB, 5, review()), Error::<Test>::NoPermissionToRate);
+		assert_noop!(Task::rate_task(Origin::signed(*BOB), task_id, *TED, 0, review()), Error::<Test>::InvalidRating);
+		assert_noop!(Task::rate_task(Origin::signed(*BOB), task_id, *TED, 6, review()), Error::<Test>::InvalidRating);
+		assert_ok!(Task::rate_task(Origin::signed(*BOB), task_id, *TED, 5, review()));
+		assert_ok!(Task::rate_task(Origin::signed(*TED), task_id, *BOB, 3, review()));
+		assert_noop!(Task::rate_task(Origin::signed(*BOB), task_id, *TED, 4, review()), Error::<Test>::AlreadyRated);
+
+		// Assert the ratings are stored and listed per account;
+		let rating = Task::ratings(task_id, (*BOB, *TED)).expect("should find the rating");
+		assert_eq!(rating.rating, 5);
+		assert_eq!(rating.review, review());
+		assert_eq!(rating.rated_at, 1);
+		assert_eq!(Task::account_ratings(&*TED), vec![rating]);
+		assert_eq!(Task::account_ratings(&*BOB)[0].reviewer, *TED);
+
+		// Assert the ratings are aggregated into the profiles;
+		let profile = Profile::profiles(*TED).unwrap();
+		assert_eq!((profile.average_rating, profile.review_count, profile.last_reviewed_at), (500, 1, Some(1)));
+		let profile = Profile::profiles(*BOB).unwrap();
+		assert_eq!((profile.average_rating, profile.review_count, profile.last_reviewed_at), (300, 1, Some(1)));
+	});
+}
+
+#[test]
+fn ratings_are_bounded_and_close_after_rating_period() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+
+		let mut accepted_tasks = vec![];
+		for n in 1..=3 {
+			assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(n), attachments(), keywords(), None, Some(x()), Some(y())));
+			let task_id = Task::tasks_owned(*TED)[0];
+			assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+			assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+			assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+			assert_ok!(Task::rate_task(Origin::signed(*BOB), task_id, *TED, 4, review()));
+			accepted_tasks.push(task_id);
+		}
+
+		// Assert the account index keeps only the most recent ratings;
+		assert_eq!(Task::ratings_by_account(*TED).into_inner(), vec![(accepted_tasks[1], *BOB), (accepted_tasks[2], *BOB)]);
+		assert!(Task::ratings(accepted_tasks[0], (*BOB, *TED)).is_none());
+		assert_eq!(Profile::profiles(*TED).unwrap().review_count, 3);
+
+		// Assert the rating period ends;
+		run_to_block(1 + <Test as Config>::RatingPeriod::get() + 1);
+		assert_noop!(Task::rate_task(Origin::signed(*TED), accepted_tasks[0], *BOB, 4, review()), Error::<Test>::RatingPeriodEnded);
+	});
+}
+
+#[test]
+fn created_tasks_are_found_within_an_area_and_near_a_profile() {
+	new_test_ext().execute_with(|| {
+		// Setup state, ALICE lives in grid cell (460, 460);
+		assert_ok!(Profile::create_profile(Origin::signed(*ALICE), username(), interests(), HOURS, Some(additional_info()), Some(*b"HB000"), Some(*b"HB000")));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_eq!(Task::grid_cell(&(*b"HB000", *b"hb000")), Some((460, 460)));
+		assert_eq!(Task::grid_cell(&(x(), y())), None);
+
+		assert_ok!(Task::create_task(Origin::signed(*ALICE), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(*b"HC000"), Some(*b"HB000")));
+		let near = Task::tasks_owned(*ALICE)[0];
+		assert_ok!(Task::create_task(Origin::signed(*ALICE), title2(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(*b"J0000"), Some(*b"HB000")));
+		let far = Task::tasks_owned(*ALICE)[1];
+		assert_ok!(Task::create_task(Origin::signed(*ALICE), title(), spec2(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+
+		// Assert tasks are found within a radius of grid cells around the profile;
+		let ids = |(tasks, _): crate::TaskPage<Test>| tasks.into_iter().map(|task| task.task_id).collect::<Vec<_>>();
+		assert_eq!(ids(Task::tasks_near(&*ALICE, 0, None, 10)), vec![]);
+		assert_eq!(ids(Task::tasks_near(&*ALICE, 1, None, 10)), vec![near]);
+		assert_eq!(ids(Task::tasks_near(&*ALICE, 20, None, 10)), vec![near, far]);
+		assert_eq!(ids(Task::tasks_near(&*BOB, 20, None, 10)), vec![]);
+
+		// Assert tasks are found within a box, by their exact location;
+		assert_eq!(ids(Task::tasks_in_area((*b"HB000", *b"HB000"), (*b"HCZZZ", *b"HBZZZ"), None, 10)), vec![near]);
+		assert_eq!(ids(Task::tasks_in_area((*b"HC001", *b"HB000"), (*b"HCZZZ", *b"HBZZZ"), None, 10)), vec![]);
+
+		// Assert large boxes are searched through the cells holding tasks;
+		let mut world = ids(Task::tasks_in_area((*b"00000", *b"00000"), (*b"ZZZZZ", *b"ZZZZZ"), None, 10));
+		let mut expected = vec![near, far];
+		world.sort();
+		expected.sort();
+		assert_eq!(world, expected);
+
+		// Assert results are paginated;
+		let (page, cursor) = Task::tasks_near(&*ALICE, 20, None, 1);
+		assert_eq!(page[0].task_id, near);
+		assert_eq!(ids(Task::tasks_near(&*ALICE, 20, cursor, 1)), vec![far]);
+
+		// Assert only created tasks are found;
+		assert_ok!(Task::start_task(Origin::signed(*BOB), near));
+		assert_eq!(ids(Task::tasks_near(&*ALICE, 20, None, 10)), vec![far]);
+	});
+}
+
+#[test]
+fn grid_index_follows_the_task_lifecycle() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		let (cell, other_cell) = ((460, 460), (461, 460));
+
+		// Assert tasks are indexed on creation and on location change;
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(*b"HB000"), Some(*b"HB000")));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_eq!(TasksByCell::<Test>::get(cell).into_inner(), vec![task_id]);
+		assert_ok!(Task::update_task(Origin::signed(*TED), task_id, title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(*b"HC000"), Some(*b"HB000")));
+		assert!(TasksByCell::<Test>::get(cell).is_empty());
+		assert_eq!(TasksByCell::<Test>::get(other_cell).into_inner(), vec![task_id]);
+
+		// Assert expired tasks leave the index until they are revived;
+		run_to_block(get_deadline_block(1) + 1);
+		assert!(TasksByCell::<Test>::get(other_cell).is_empty());
+		assert_ok!(Task::revive_expired_task(Origin::signed(*TED), task_id, task_deadline(2)));
+		assert_eq!(TasksByCell::<Test>::get(other_cell).into_inner(), vec![task_id]);
+
+		// Assert removed tasks leave the index;
+		assert_ok!(Task::remove_task(Origin::signed(*TED), task_id));
+		assert!(TasksByCell::<Test>::get(other_cell).is_empty());
+
+		// Assert accepted tasks leave the index;
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(*b"HB000"), Some(*b"HB000")));
+		let task_id = Task::tasks_owned(*TED)[0];
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_eq!(TasksByCell::<Test>::get(cell).into_inner(), vec![task_id]);
+		assert_ok!(Task::accept_task(Origin::signed(*TED), task_id));
+		assert!(TasksByCell::<Test>::get(cell).is_empty());
+
+		// Assert the number of tasks per cell is bounded;
+		let max_tasks = <Test as Config>::MaxTasksPerCell::get() as u64;
+		for n in 1..=max_tasks {
+			assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(n), attachments(), keywords(), None, Some(*b"HB000"), Some(*b"HB000")));
+		}
+		assert_noop!(
+			Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(max_tasks + 1), attachments(), keywords(), None, Some(*b"HB000"), Some(*b"HB000")),
+			Error::<Test>::CellLimitReached
+		);
+	});
+}
+
+#[test]
+fn migration_to_v1_moves_budgets_into_escrow() {
+	new_test_ext().execute_with(|| {
+		use codec::Encode;
+		use frame_support::{storage::migration::put_storage_value, traits::{OnRuntimeUpgrade, StorageVersion, GetStorageVersion}, Twox64Concat, StorageHasher};
+
+		// Store a version 0 task, whose budget is reserved from its initiator
+		let task_id = H256::repeat_byte(7);
+		let location = (*b"HB000", *b"HB000");
+		let old_task = (
+			(title(), spec(), *TED, *TED, *TED, TaskStatus::Created, BUDGET, get_deadline(1), attachments()),
+			(keywords(), None::<Vec<u8>>, 1u64, 1u64, 0u64, None::<H256>, None::<u64>, task_id, Some(location)),
+		);
+		put_storage_value(b"Task", b"Tasks", &Twox64Concat::hash(&task_id.encode()), old_task);
+		assert_ok!(Balances::reserve(&*TED, BUDGET));
+		StorageVersion::new(0).put::<Task>();
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Task::on_chain_storage_version(), 1);
+
+		// Ensure the task decodes once it requires no bond, like the later migrations of the upgrade do
+		crate::migrations::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+		assert_eq!(Task::on_chain_storage_version(), 3);
+
+		// Ensure the budget is held in escrow, and the task gets the defaults of the new fields
+		let task = Task::tasks(task_id).expect("should find the task");
+		assert_eq!(Task::escrowed_balance(&task_id), BUDGET);
+		assert_eq!(Balances::reserved_balance(&*TED), 0);
+		assert_eq!(task.funder, *TED);
+		assert!(task.open);
+		assert_eq!(task.location, Some(location));
+		assert_eq!(TasksByCell::<Test>::get(Task::grid_cell(&location).unwrap()).into_inner(), vec![task_id]);
+		assert_eq!(task.bond, None);
+	});
+}
+
+#[test]
+fn migration_to_v1_returns_budgets_below_the_minimum_balance() {
+	new_test_ext().execute_with(|| {
+		use codec::Encode;
+		use frame_support::{storage::migration::put_storage_value, traits::{OnRuntimeUpgrade, StorageVersion, GetStorageVersion}, Twox64Concat, StorageHasher};
+
+		// Store a version 0 task, whose reserved budget is below the minimum balance
+		let task_id = H256::repeat_byte(7);
+		let old_task = (
+			(title(), spec(), *TED, *TED, *TED, TaskStatus::Created, BUDGET, get_deadline(1), attachments()),
+			(keywords(), None::<Vec<u8>>, 1u64, 1u64, 0u64, None::<H256>, None::<u64>, task_id, None::<([u8; 5], [u8; 5])>),
+		);
+		put_storage_value(b"Task", b"Tasks", &Twox64Concat::hash(&task_id.encode()), old_task);
+		assert_ok!(Balances::reserve(&*TED, BUDGET));
+		ExistentialDeposit::set(&(BUDGET + 1));
+		StorageVersion::new(0).put::<Task>();
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		crate::migrations::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+		assert_eq!(Task::on_chain_storage_version(), 3);
+
+		// Ensure the budget went back to the initiator instead of being dusted in the escrow account
+		assert_eq!(Balances::reserved_balance(&*TED), 0);
+		assert_eq!(Balances::free_balance(&*TED), 1000);
+		assert_eq!(Balances::free_balance(&Task::account_id(&task_id)), 0);
+		assert_eq!(Task::escrowed_balance(&task_id), 0);
+		assert_eq!(Task::tasks(task_id).expect("should find the task").budget, 0);
+	});
+}
+
+#[test]
+fn migration_to_v2_gives_open_disputes_an_empty_evidence() {
+	new_test_ext().execute_with(|| {
+		use codec::Encode;
+		use frame_support::{storage::migration::put_storage_value, traits::{OnRuntimeUpgrade, StorageVersion, GetStorageVersion}, Twox64Concat, StorageHasher};
+
+		// Store a version 1 dispute, without evidence
+		let task_id = H256::repeat_byte(7);
+		let reason: Vec<u8> = b"late".to_vec();
+		let old_dispute = (*BOB, reason.clone(), 50u64, vec![*ALICE], vec![(*ALICE, Percent::from_percent(40))], TaskStatus::Completed, 1u64, 11u64);
+		put_storage_value(b"Task", b"Disputes", &Twox64Concat::hash(&task_id.encode()), old_dispute);
+		StorageVersion::new(1).put::<Task>();
+
+		crate::migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(Task::on_chain_storage_version(), 2);
+
+		// Ensure the dispute keeps its fields, with an empty evidence
+		let dispute = Task::disputes(task_id).expect("should find the dispute");
+		assert_eq!(dispute.opened_by, *BOB);
+		assert_eq!(dispute.reason.into_inner(), reason);
+		assert!(dispute.evidence.is_empty());
+		assert_eq!(dispute.deposit, 50);
+		assert_eq!(dispute.arbitrators.into_inner(), vec![*ALICE]);
+		assert_eq!(dispute.votes.into_inner(), vec![(*ALICE, Percent::from_percent(40))]);
+		assert_eq!(dispute.previous_status, TaskStatus::Completed);
+		assert_eq!(dispute.opened_at, 1);
+		assert_eq!(dispute.voting_ends_at, 11);
+	});
+}
+
+#[test]
+fn migration_to_v4_indexes_tasks_by_status_and_organization() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::{OnRuntimeUpgrade, StorageVersion, GetStorageVersion};
+
+		// Store a version 3 task, which is not indexed yet
+		let org_id = create_organization();
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*TED), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), Some(org_id), Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*TED)[0];
+		TasksByStatus::<Test>::remove(TaskStatus::Created, task_id);
+		TasksByOrganization::<Test>::remove(org_id, task_id);
+		StorageVersion::new(3).put::<Task>();
+		assert!(Task::tasks_by_status(TaskStatus::Created, None, 10).0.is_empty());
+
+		crate::migrations::v4::MigrateToV4::<Test>::on_runtime_upgrade();
+		assert_eq!(Task::on_chain_storage_version(), 4);
+
+		// Ensure the task is indexed by its status and organization
+		assert_eq!(Task::tasks_by_status(TaskStatus::Created, None, 10).0[0].task_id, task_id);
+		assert_eq!(Task::tasks_by_organization(org_id, None, 10).0[0].task_id, task_id);
+	});
+}
+
+#[test]
+fn invite_only_tasks_are_started_by_invitees() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		assert_ok!(Profile::create_profile(Origin::signed(*ALICE), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task(Origin::signed(*ALICE), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*ALICE)[0];
+
+		// Ensure only the initiator restricts the task;
+		assert_noop!(Task::set_task_visibility(Origin::signed(*BOB), task_id, crate::TaskVisibility::InviteOnly), Error::<Test>::OnlyInitiatorUpdatesTask);
+		assert_ok!(Task::set_task_visibility(Origin::signed(*ALICE), task_id, crate::TaskVisibility::InviteOnly));
+		System::assert_last_event(Event::Task(crate::Event::TaskVisibilitySet(*ALICE, task_id, crate::TaskVisibility::InviteOnly)));
+
+		// Ensure invitations are unique, bounded, and not sent to the initiator;
+		assert_ok!(Task::invite_volunteer(Origin::signed(*ALICE), task_id, *BOB));
+		System::assert_last_event(Event::Task(crate::Event::VolunteerInvited(*ALICE, task_id, *BOB)));
+		assert_noop!(Task::invite_volunteer(Origin::signed(*ALICE), task_id, *BOB), Error::<Test>::AlreadyInvited);
+		assert_noop!(Task::invite_volunteer(Origin::signed(*ALICE), task_id, *ALICE), Error::<Test>::NoPermissionToInvite);
+		assert_ok!(Task::invite_volunteer(Origin::signed(*ALICE), task_id, *TED));
+		assert_noop!(Task::invite_volunteer(Origin::signed(*ALICE), task_id, sr25519::Public::from_raw([9u8; 32])), Error::<Test>::TooManyInvitations);
+
+		// Ensure revoked and declined invitees can't start the task;
+		assert_ok!(Task::revoke_invitation(Origin::signed(*ALICE), task_id, *TED));
+		System::assert_last_event(Event::Task(crate::Event::InvitationRevoked(*ALICE, task_id, *TED)));
+		assert_noop!(Task::start_task(Origin::signed(*TED), task_id), Error::<Test>::NotInvited);
+		assert_ok!(Task::decline_invitation(Origin::signed(*BOB), task_id));
+		System::assert_last_event(Event::Task(crate::Event::InvitationDeclined(*BOB, task_id)));
+		assert_noop!(Task::decline_invitation(Origin::signed(*BOB), task_id), Error::<Test>::NotInvited);
+		assert_noop!(Task::start_task(Origin::signed(*BOB), task_id), Error::<Test>::NotInvited);
+
+		// Assert invitees start the task;
+		assert_ok!(Task::invite_volunteer(Origin::signed(*ALICE), task_id, *BOB));
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_eq!(Task::tasks(task_id).unwrap().volunteer, *BOB);
+
+		// Ensure invitations can't change once the task has been started;
+		assert_noop!(Task::invite_volunteer(Origin::signed(*ALICE), task_id, *TED), Error::<Test>::NoPermissionToInvite);
+	});
+}
+
+#[test]
+fn invitees_start_tasks_that_require_an_application() {
+	new_test_ext().execute_with(|| {
+		// Setup state, a task started by selecting an application;
+		assert_ok!(Profile::create_profile(Origin::signed(*ALICE), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Task::create_task_with_options(Origin::signed(*ALICE), title(), spec(), BUDGET, task_deadline(1), attachments(), keywords(), None, Some(x()), Some(y()), false, None));
+		let task_id = Task::tasks_owned(*ALICE)[0];
+		assert_noop!(Task::start_task(Origin::signed(*BOB), task_id), Error::<Test>::TaskRequiresApplication);
+
+		// Assert the invitee skips the application;
+		assert_ok!(Task::invite_volunteer(Origin::signed(*ALICE), task_id, *BOB));
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+		assert_eq!(Task::tasks(task_id).unwrap().status, TaskStatus::InProgress);
+	});
+}
+
+#[test]
+fn organization_only_tasks_are_started_by_members() {
+	new_test_ext().execute_with(|| {
+		// Setup state;
+		let org_id = create_organization();
+		assert_ok!(Dao::add_members(Origin::signed(*ALICE), org_id, *BOB));
+		assert_ok!(Profile::create_profile(Origin::signed(*ALICE), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*BOB), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+		assert_ok!(Profile::create_profile(Origin::signed(*TED), username(), interests(), HOURS, Some(additional_info()), Some(x()), Some(y())));
+
+		// Ensure only tasks of an organization are made organization-only;
+		assert_ok!(Task::create_task(Origin::signed(*ALICE), title(), spec(), BUDGET, get_deadline(1), attachments(), keywords(), None, Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*ALICE)[0];
+		assert_noop!(Task::set_task_visibility(Origin::signed(*ALICE), task_id, crate::TaskVisibility::OrganizationOnly), Error::<Test>::TaskHasNoOrganization);
+
+		// Assert only members of the organization start the task;
+		assert_ok!(Task::create_task(Origin::signed(*ALICE), title(), spec(), BUDGET2, get_deadline(1), attachments(), keywords(), Some(org_id), Some(x()), Some(y())));
+		let task_id = Task::tasks_owned(*ALICE)[1];
+		assert_ok!(Task::set_task_visibility(Origin::signed(*ALICE), task_id, crate::TaskVisibility::OrganizationOnly));
+		assert_noop!(Task::start_task(Origin::signed(*TED), task_id), Error::<Test>::NotOrganizationMember);
+		assert_ok!(Task::start_task(Origin::signed(*BOB), task_id));
+
+		// Assert the visibility and invitations are removed with the task;
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, deliverables()));
+		assert_ok!(Task::accept_task(Origin::signed(*ALICE), task_id));
+		assert_eq!(Task::visibility(task_id), crate::TaskVisibility::Public);
+	});
+}
+
+#[test]
+fn submissions_are_kept_across_rejections_and_become_dispute_evidence() {
+	new_test_ext().execute_with(|| {
+		// Setup state, the first submission is rejected;
+		let org_id = create_organization();
+		let task_id = create_rejected_task(Some(org_id));
+		let resubmission = |reference: H256| -> BoundedVec<Deliverable<Test>, MaxDeliverablesPerSubmission> {
+			vec![Deliverable::<Test> { reference: reference.as_bytes().to_vec().try_into().unwrap(), description: None }].try_into().unwrap()
+		};
+
+		// Assert resubmissions are appended to the history of the task;
+		run_to_block(2);
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, resubmission(H256::repeat_byte(2))));
+		let submissions = Task::submissions(task_id);
+		assert_eq!(submissions.len(), 2);
+		assert_eq!(submissions[0].deliverables, deliverables());
+		assert_eq!(submissions[1].volunteer, *BOB);
+		assert_eq!(submissions[1].deliverables, resubmission(H256::repeat_byte(2)));
+		assert_eq!(submissions[1].submitted_at, 2);
+
+		// Assert the oldest submission is dropped once the history is full;
+		assert_ok!(Task::reject_task(Origin::signed(*TED), task_id, feedback()));
+		run_to_block(3);
+		assert_ok!(Task::complete_task(Origin::signed(*BOB), task_id, resubmission(H256::repeat_byte(3))));
+		let submissions = Task::submissions(task_id);
+		assert_eq!(submissions.len(), 2);
+		assert_eq!(submissions[0].deliverables, resubmission(H256::repeat_byte(2)));
+		assert_eq!(submissions[1].deliverables, resubmission(H256::repeat_byte(3)));
+
+		// Assert the submissions become the evidence of the dispute;
+		assert_ok!(Task::reject_task(Origin::signed(*TED), task_id, feedback()));
+		assert_ok!(Task::open_dispute(Origin::signed(*BOB), task_id, reason()));
+		assert_eq!(Task::disputes(task_id).unwrap().evidence, submissions);
+	});
+}
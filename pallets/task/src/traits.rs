@@ -1,4 +1,124 @@
-pub trait Organization<OrganizationId> {
+use crate::NadLocation;
+use frame_support::dispatch::DispatchResult;
+use sp_std::vec::Vec;
+
+pub trait Organization<OrganizationId, AccountId> {
 	/// Determines whether an organization with the supplied identifier exists.
 	fn exists(id: &OrganizationId) -> bool;
+
+	/// Returns the members of the organization with the supplied identifier.
+	fn members(id: &OrganizationId) -> Vec<AccountId>;
+
+	/// Returns the account holding the funds of the organization with the supplied identifier.
+	fn account(id: &OrganizationId) -> AccountId;
+
+	/// Determines whether the account can spend from the funds of the organization with the supplied identifier.
+	fn can_spend(id: &OrganizationId, who: &AccountId) -> bool;
+}
+
+pub trait Profiles<AccountId> {
+	/// Determines whether the account has a profile.
+	fn exists(who: &AccountId) -> bool;
+
+	/// Returns the location of the profile of the account, if it has a profile with a location.
+	fn location(who: &AccountId) -> Option<NadLocation>;
+}
+
+/// Callbacks on the lifecycle of a task, for other pallets to react to it. Every callback does nothing
+/// unless implemented. Implemented for tuples, whose members are called in order.
+/// An error aborts the change of the task that triggered it, except on expiry and on the removal of expired tasks,
+/// where errors are ignored.
+pub trait TaskLifecycleHooks<AccountId, TaskId> {
+	/// Called when a task is created by its initiator.
+	fn on_created(_task_id: &TaskId, _initiator: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called when a task is started, once every volunteer slot of a team task has been taken.
+	fn on_started(_task_id: &TaskId, _volunteers: &[AccountId]) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called when a task is completed, once every volunteer of a team task has completed their slot.
+	fn on_completed(_task_id: &TaskId, _volunteers: &[AccountId]) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called when a task is accepted, right before it is removed from storage.
+	fn on_accepted(_task_id: &TaskId, _initiator: &AccountId, _volunteers: &[AccountId]) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called when a completed task is rejected, and goes back to its volunteers.
+	fn on_rejected(_task_id: &TaskId, _volunteers: &[AccountId]) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called when volunteers leave a task before completing it, by abandoning it or when the initiator reclaims it.
+	fn on_abandoned(_task_id: &TaskId, _volunteers: &[AccountId]) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called when a task expires.
+	fn on_expired(_task_id: &TaskId) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called when a task that has not been accepted is removed from storage, by its initiator, after expiry or by a dispute.
+	fn on_removed(_task_id: &TaskId) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called when one party of an accepted task rates the other, with a rating from 1 to 5.
+	fn on_rated(_task_id: &TaskId, _reviewer: &AccountId, _reviewee: &AccountId, _rating: u8) -> DispatchResult {
+		Ok(())
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId, TaskId> TaskLifecycleHooks<AccountId, TaskId> for Tuple {
+	fn on_created(task_id: &TaskId, initiator: &AccountId) -> DispatchResult {
+		for_tuples!( #( Tuple::on_created(task_id, initiator)?; )* );
+		Ok(())
+	}
+
+	fn on_started(task_id: &TaskId, volunteers: &[AccountId]) -> DispatchResult {
+		for_tuples!( #( Tuple::on_started(task_id, volunteers)?; )* );
+		Ok(())
+	}
+
+	fn on_completed(task_id: &TaskId, volunteers: &[AccountId]) -> DispatchResult {
+		for_tuples!( #( Tuple::on_completed(task_id, volunteers)?; )* );
+		Ok(())
+	}
+
+	fn on_accepted(task_id: &TaskId, initiator: &AccountId, volunteers: &[AccountId]) -> DispatchResult {
+		for_tuples!( #( Tuple::on_accepted(task_id, initiator, volunteers)?; )* );
+		Ok(())
+	}
+
+	fn on_rejected(task_id: &TaskId, volunteers: &[AccountId]) -> DispatchResult {
+		for_tuples!( #( Tuple::on_rejected(task_id, volunteers)?; )* );
+		Ok(())
+	}
+
+	fn on_abandoned(task_id: &TaskId, volunteers: &[AccountId]) -> DispatchResult {
+		for_tuples!( #( Tuple::on_abandoned(task_id, volunteers)?; )* );
+		Ok(())
+	}
+
+	fn on_expired(task_id: &TaskId) -> DispatchResult {
+		for_tuples!( #( Tuple::on_expired(task_id)?; )* );
+		Ok(())
+	}
+
+	fn on_removed(task_id: &TaskId) -> DispatchResult {
+		for_tuples!( #( Tuple::on_removed(task_id)?; )* );
+		Ok(())
+	}
+
+	fn on_rated(task_id: &TaskId, reviewer: &AccountId, reviewee: &AccountId, rating: u8) -> DispatchResult {
+		for_tuples!( #( Tuple::on_rated(task_id, reviewer, reviewee, rating)?; )* );
+		Ok(())
+	}
 }
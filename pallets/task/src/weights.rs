@@ -20,6 +20,10 @@
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
 //! DATE: 2022-07-05, STEPS: `100`, REPEAT: 50, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+//!
+//! NOTE: the weights of the calls and hooks added or changed after this run were written by hand, from the
+//! storage accesses listed above each of them, and are not benchmark results. Regenerate this file with the
+//! command below before relying on them.
 
 // Executed Command:
 
@@ -90,6 +94,88 @@ pub trait WeightInfo {
 
 	fn revive_task(s: u32, x: u32, ) -> Weight;
 
+	fn add_milestone() -> Weight;
+
+	fn submit_milestone() -> Weight;
+
+	fn accept_milestone() -> Weight;
+
+	fn reject_milestone() -> Weight;
+
+	fn apply_for_task() -> Weight;
+
+	fn withdraw_application() -> Weight;
+
+	fn shortlist_application() -> Weight;
+
+	fn select_application() -> Weight;
+
+	fn set_volunteer_slots(s: u32, ) -> Weight;
+
+	fn open_dispute() -> Weight;
+
+	fn vote_on_dispute() -> Weight;
+
+	fn set_arbitrator_pool(s: u32, ) -> Weight;
+
+	fn create_subtask() -> Weight;
+
+	fn add_dependency() -> Weight;
+
+	fn remove_dependency() -> Weight;
+
+	fn reclaim_task() -> Weight;
+
+	fn abandon_task() -> Weight;
+
+	fn create_asset_task() -> Weight;
+
+	fn accept_asset_task() -> Weight;
+
+	fn create_recurring_task() -> Weight;
+
+	fn cancel_recurring_task() -> Weight;
+
+	fn create_template() -> Weight;
+
+	fn update_template() -> Weight;
+
+	fn remove_template() -> Weight;
+
+	fn create_task_from_template() -> Weight;
+
+	fn create_organization_task() -> Weight;
+
+	fn rate_task() -> Weight;
+
+	fn expire_task() -> Weight;
+
+	fn remove_dead_task() -> Weight;
+
+	fn set_task_visibility() -> Weight;
+
+	fn invite_volunteer() -> Weight;
+
+	fn revoke_invitation() -> Weight;
+
+	fn decline_invitation() -> Weight;
+
+	fn set_vesting_schedule() -> Weight;
+
+	fn claim_vested() -> Weight;
+
+	fn set_volunteer_bond() -> Weight;
+
+	fn resolve_dispute() -> Weight;
+
+	fn prune_receipt() -> Weight;
+
+	fn auto_accept_task() -> Weight;
+
+	fn spawn_occurrence() -> Weight;
+
+	fn expire_milestones() -> Weight;
+
 }
 
 /// Weights for pallet_task using the Substrate node and recommended hardware.
@@ -109,6 +195,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: Task Tasks (r:0 w:1)
 	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
 	fn create_task(s: u32, _x: u32, ) -> Weight {
 		(25_626_000 as Weight)
 			
@@ -116,11 +208,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((3_000 as Weight).saturating_mul(s as Weight))
 			
 			
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			
 			
 			
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 			
 			
 	}
@@ -132,6 +224,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: Timestamp Now (r:1 w:0)
 	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:2)
+	
 	fn update_task(s: u32, _x: u32, ) -> Weight {
 		(28_208_000 as Weight)
 			
@@ -139,11 +235,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((1_000 as Weight).saturating_mul(s as Weight))
 			
 			
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			
 			
 			
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 			
 			
 	}
@@ -153,6 +249,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: Task TasksOwned (r:2 w:2)
 	
+	// Storage: Task Bonds (r:0 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
 	fn start_task(s: u32, _x: u32, ) -> Weight {
 		(25_492_000 as Weight)
 			
@@ -160,11 +264,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((5_000 as Weight).saturating_mul(s as Weight))
 			
 			
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
 			
 			
 			
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 			
 			
 	}
@@ -174,15 +278,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: Task TaskCount (r:1 w:1)
 	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
 	fn remove_task(_s: u32, _x: u32, ) -> Weight {
 		(23_267_000 as Weight)
 			
 			
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			
 			
 			
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 			
 			
 	}
@@ -192,6 +302,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: Task TasksOwned (r:2 w:2)
 	
+	// Storage: Task ReviewsEndingPerBlock (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
 	fn complete_task(s: u32, _x: u32, ) -> Weight {
 		(26_754_000 as Weight)
 			
@@ -199,11 +313,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((1_000 as Weight).saturating_mul(s as Weight))
 			
 			
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			
 			
 			
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 			
 			
 	}
@@ -219,15 +333,35 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: Profile CompletedTasks (r:1 w:1)
 	
+	// Storage: Task PrunableReceiptsPerBlock (r:1 w:1)
+	
+	// Storage: Task ReceiptsByAccount (r:2 w:2)
+	
+	// Storage: Task ReceiptsByOrganization (r:1 w:1)
+	
+	// Storage: Task Receipts (r:0 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Task VestingSchedules (r:1 w:1)
+	
+	// Storage: Task Bonds (r:1 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
 	fn accept_task(_s: u32, _x: u32, ) -> Weight {
-		(45_985_000 as Weight)
+		(61_240_000 as Weight)
 			
 			
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
 			
 			
 			
-			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(16 as Weight))
 			
 			
 	}
@@ -237,19 +371,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	
 	// Storage: Task TasksOwned (r:2 w:2)
 	
+	// Storage: Task ReviewsEndingPerBlock (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
 	fn reject_task(_s: u32, _x: u32, ) -> Weight {
 		(28_202_000 as Weight)
 			
 			
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			
 			
 			
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 			
 			
 	}
 
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
 	fn revive_task(_s: u32, _x: u32, ) -> Weight {
 		(28_202_000 as Weight)
 			
@@ -258,177 +398,2199 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 			
 			
 	}
-	
-}
 
-// For backwards compatibility and tests
-impl WeightInfo for () {
+	// Storage: Task Tasks (r:1 w:0)
 	
+	// Storage: Task Milestones (r:1 w:1)
 	
-	// Storage: Profile Profiles (r:1 w:0)
+	// Storage: Task ExpiringMilestonesPerBlock (r:1 w:1)
+	
+	fn add_milestone() -> Weight {
+		(24_310_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Milestones (r:1 w:1)
 	
 	// Storage: Timestamp Now (r:1 w:0)
 	
-	// Storage: Task TasksOwned (r:1 w:1)
+	fn submit_milestone() -> Weight {
+		(21_870_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
 	
-	// Storage: Task TaskCount (r:1 w:1)
+	// Storage: Task Milestones (r:1 w:1)
 	
-	// Storage: Task Tasks (r:0 w:1)
+	// Storage: System Account (r:3 w:3)
 	
-	fn create_task(s: u32, _x: u32, ) -> Weight {
-		(25_626_000 as Weight)
-			
-			// Standard Error: 0
-			.saturating_add((3_000 as Weight).saturating_mul(s as Weight))
+	// Storage: Task Escrows (r:1 w:1)
+	
+	fn accept_milestone() -> Weight {
+		(39_540_000 as Weight)
 			
 			
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
 			
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 			
 			
 	}
+
+	// Storage: Task Tasks (r:1 w:0)
 	
+	// Storage: Task Milestones (r:1 w:1)
 	
-	// Storage: Task Tasks (r:1 w:1)
+	fn reject_milestone() -> Weight {
+		(22_105_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
 	
 	// Storage: Profile Profiles (r:1 w:0)
 	
-	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Task Applications (r:1 w:1)
 	
-	fn update_task(s: u32, _x: u32, ) -> Weight {
-		(28_208_000 as Weight)
+	fn apply_for_task() -> Weight {
+		(25_940_000 as Weight)
 			
-			// Standard Error: 0
-			.saturating_add((1_000 as Weight).saturating_mul(s as Weight))
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			
 			
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+
+	// Storage: Task Applications (r:1 w:1)
+	
+	fn withdraw_application() -> Weight {
+		(17_330_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 			
 			
 	}
+
+	// Storage: Task Tasks (r:1 w:0)
 	
+	// Storage: Task Applications (r:1 w:1)
 	
+	fn shortlist_application() -> Weight {
+		(20_615_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
 	// Storage: Task Tasks (r:1 w:1)
 	
+	// Storage: Task Applications (r:1 w:1)
+	
 	// Storage: Task TasksOwned (r:2 w:2)
 	
-	fn start_task(s: u32, _x: u32, ) -> Weight {
-		(25_492_000 as Weight)
-			
-			// Standard Error: 0
-			.saturating_add((5_000 as Weight).saturating_mul(s as Weight))
+	// Storage: Task ExpiringTasksPerBlock (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	fn select_application() -> Weight {
+		(58_270_000 as Weight)
 			
 			
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
 			
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
 			
 			
 	}
-	
-	
+
 	// Storage: Task Tasks (r:1 w:1)
 	
-	// Storage: Task TaskCount (r:1 w:1)
+	// Storage: Task Milestones (r:1 w:0)
 	
-	fn remove_task(_s: u32, _x: u32, ) -> Weight {
-		(23_267_000 as Weight)
+	fn set_volunteer_slots(s: u32, ) -> Weight {
+		(21_480_000 as Weight)
 			
+			// Standard Error: 2_000
+			.saturating_add((412_000 as Weight).saturating_mul(s as Weight))
 			
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 			
 			
 	}
+
+	// Storage: Task Tasks (r:1 w:1)
 	
+	// Storage: Task ArbitratorPool (r:1 w:0)
 	
-	// Storage: Task Tasks (r:1 w:1)
+	// Storage: Randomness CollectiveFlip RandomMaterial (r:1 w:0)
 	
-	// Storage: Task TasksOwned (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
 	
-	fn complete_task(s: u32, _x: u32, ) -> Weight {
-		(26_754_000 as Weight)
-			
-			// Standard Error: 0
-			.saturating_add((1_000 as Weight).saturating_mul(s as Weight))
+	// Storage: Task DisputesEndingPerBlock (r:1 w:1)
+	
+	// Storage: Task Disputes (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	fn open_dispute() -> Weight {
+		(61_350_000 as Weight)
 			
 			
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
 			
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 			
 			
 	}
+
+	// Storage: Task Disputes (r:1 w:1)
 	
+	// Storage: Task DisputesEndingPerBlock (r:1 w:1)
 	
 	// Storage: Task Tasks (r:1 w:1)
 	
-	// Storage: Task TasksOwned (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
 	
-	// Storage: Profile Profiles (r:1 w:1)
+	// Storage: Task TaskCount (r:0 w:1)
 	
-	// Storage: Task TaskCount (r:1 w:1)
+	// Storage: Task TasksByStatus (r:0 w:2)
 	
-	// Storage: Profile CompletedTasks (r:1 w:1)
+	// Storage: Task TasksByOrganization (r:0 w:1)
 	
-	fn accept_task(_s: u32, _x: u32, ) -> Weight {
-		(45_985_000 as Weight)
+	fn vote_on_dispute() -> Weight {
+		(97_820_000 as Weight)
 			
 			
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
 			
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
 			
 			
 	}
+
+	// Storage: Task ArbitratorPool (r:0 w:1)
 	
-	
-	// Storage: Task Tasks (r:1 w:1)
-	
-	// Storage: Task TasksOwned (r:2 w:2)
-	
-	fn reject_task(_s: u32, _x: u32, ) -> Weight {
-		(28_202_000 as Weight)
+	fn set_arbitrator_pool(s: u32, ) -> Weight {
+		(9_870_000 as Weight)
 			
+			// Standard Error: 1_000
+			.saturating_add((96_000 as Weight).saturating_mul(s as Weight))
 			
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 			
 			
 	}
 
-	fn revive_task(_s: u32, _x: u32, ) -> Weight {
-		(28_202_000 as Weight)
+	// Storage: Task Tasks (r:2 w:2)
+	
+	// Storage: Profile Profiles (r:1 w:0)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task ExpiringTasksPerBlock (r:1 w:1)
+	
+	// Storage: Task Subtasks (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn create_subtask() -> Weight {
+		(68_150_000 as Weight)
 			
 			
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
 			
 			
 			
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:2 w:0)
+	
+	// Storage: Task Dependencies (r:1 w:1)
+	
+	// Storage: Task Dependents (r:1 w:1)
+	
+	fn add_dependency() -> Weight {
+		(24_310_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Dependencies (r:1 w:1)
+	
+	// Storage: Task Dependents (r:1 w:1)
+	
+	fn remove_dependency() -> Weight {
+		(19_870_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:2 w:2)
+	
+	// Storage: Profile Profiles (r:1 w:1)
+	
+	// Storage: Task Milestones (r:1 w:1)
+	
+	// Storage: Task ExpiringTasksPerBlock (r:1 w:1)
+	
+	// Storage: Task Bonds (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	fn reclaim_task() -> Weight {
+		(48_620_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:2 w:2)
+	
+	// Storage: Profile Profiles (r:1 w:1)
+	
+	// Storage: Task Milestones (r:1 w:1)
+	
+	// Storage: Task ExpiringTasksPerBlock (r:1 w:1)
+	
+	// Storage: Task Bonds (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	fn abandon_task() -> Weight {
+		(47_910_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+			
+			
+	}
+
+	// Storage: Profile Profiles (r:1 w:0)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Assets Account (r:2 w:2)
+	
+	// Storage: Assets Asset (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task Tasks (r:0 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn create_asset_task() -> Weight {
+		(41_318_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Profile Profiles (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Assets Account (r:3 w:3)
+	
+	// Storage: Assets Asset (r:1 w:1)
+	
+	// Storage: Profile CompletedTasks (r:1 w:1)
+	
+	// Storage: Task PrunableReceiptsPerBlock (r:1 w:1)
+	
+	// Storage: Task ReceiptsByAccount (r:2 w:2)
+	
+	// Storage: Task ReceiptsByOrganization (r:1 w:1)
+	
+	// Storage: Task Receipts (r:0 w:1)
+	
+	// Storage: Task VestingSchedules (r:1 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn accept_asset_task() -> Weight {
+		(78_905_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
+			
+			
+	}
+
+	// Storage: Profile Profiles (r:1 w:0)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Task RecurringTasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task ExpiringTasksPerBlock (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Task RecurringTasksPerBlock (r:1 w:1)
+	
+	// Storage: Task Tasks (r:0 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	fn create_recurring_task() -> Weight {
+		(52_740_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			
+			
+	}
+
+	// Storage: Task RecurringTasks (r:1 w:1)
+	
+	// Storage: Task RecurringTasksPerBlock (r:1 w:1)
+	
+	fn cancel_recurring_task() -> Weight {
+		(19_870_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			
+			
+	}
+
+	// Storage: Dao Organizations (r:1 w:0)
+	
+	// Storage: Dao Members (r:1 w:0)
+	
+	// Storage: Task Templates (r:1 w:1)
+	
+	fn create_template() -> Weight {
+		(18_430_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Templates (r:1 w:1)
+	
+	// Storage: Dao Members (r:1 w:0)
+	
+	fn update_template() -> Weight {
+		(17_210_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Templates (r:1 w:1)
+	
+	// Storage: Dao Members (r:1 w:0)
+	
+	fn remove_template() -> Weight {
+		(16_540_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Templates (r:1 w:0)
+	
+	// Storage: Dao Members (r:1 w:0)
+	
+	// Storage: Profile Profiles (r:1 w:0)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task Tasks (r:0 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn create_task_from_template() -> Weight {
+		(31_980_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			
+			
+	}
+
+	// Storage: Dao Organizations (r:1 w:0)
+	
+	// Storage: Dao Treasurers (r:1 w:0)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Profile Profiles (r:1 w:0)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task Tasks (r:0 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn create_organization_task() -> Weight {
+		(29_874_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			
+			
+	}
+
+	// Storage: Task Receipts (r:1 w:0)
+	
+	// Storage: Task Ratings (r:1 w:1)
+	
+	// Storage: Profile Profiles (r:1 w:1)
+	
+	// Storage: Task RatingsByAccount (r:1 w:1)
+	
+	fn rate_task() -> Weight {
+		(19_870_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Task DyingTasksPerBlock (r:1 w:1)
+	
+	// Storage: Task TasksByCell (r:1 w:1)
+	
+	// Storage: Task ExpiringTasksPerBlock (r:0 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	fn expire_task() -> Weight {
+		(21_734_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:2 w:1)
+	
+	// Storage: Task Subtasks (r:1 w:1)
+	
+	// Storage: Task TasksByCell (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task Milestones (r:0 w:1)
+	
+	// Storage: Task Applications (r:0 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn remove_dead_task() -> Weight {
+		(47_318_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Visibility (r:0 w:1)
+	
+	fn set_task_visibility() -> Weight {
+		(17_208_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Invitations (r:1 w:1)
+	
+	fn invite_volunteer() -> Weight {
+		(19_542_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Invitations (r:1 w:1)
+	
+	fn revoke_invitation() -> Weight {
+		(19_871_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Invitations (r:1 w:1)
+	
+	fn decline_invitation() -> Weight {
+		(18_936_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task VestingSchedules (r:0 w:1)
+	
+	fn set_vesting_schedule() -> Weight {
+		(19_870_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Disputes (r:1 w:0)
+	
+	// Storage: Task VestingPayouts (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	fn claim_vested() -> Weight {
+		(42_310_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	fn set_volunteer_bond() -> Weight {
+		(19_640_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Disputes (r:1 w:1)
+	
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: System Account (r:3 w:3)
+	
+	// Storage: Task Bonds (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:2 w:2)
+	
+	// Storage: Task TasksByCell (r:1 w:1)
+	
+	// Storage: Task Subtasks (r:1 w:1)
+	
+	// Storage: Task Dependencies (r:1 w:1)
+	
+	// Storage: Task DisputesEndingPerBlock (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task Milestones (r:0 w:1)
+	
+	// Storage: Task Applications (r:0 w:1)
+	
+	// Storage: Task Visibility (r:0 w:1)
+	
+	// Storage: Task Invitations (r:0 w:1)
+	
+	// Storage: Task Submissions (r:0 w:1)
+	
+	// Storage: Task VestingSchedules (r:0 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn resolve_dispute() -> Weight {
+		(92_415_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(23 as Weight))
+			
+			
+	}
+
+	// Storage: Task Receipts (r:1 w:1)
+	
+	// Storage: Task ReceiptsByAccount (r:0 w:1)
+	
+	// Storage: Task ReceiptsByOrganization (r:0 w:1)
+	
+	fn prune_receipt() -> Weight {
+		(24_310_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:2)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task ReviewsEndingPerBlock (r:2 w:2)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: System Account (r:3 w:3)
+	
+	// Storage: Task Bonds (r:1 w:1)
+	
+	// Storage: Task Receipts (r:0 w:1)
+	
+	// Storage: Task PrunableReceiptsPerBlock (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn auto_accept_task() -> Weight {
+		(63_180_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+			
+			
+	}
+
+	// Storage: Task RecurringTasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task ExpiringTasksPerBlock (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task RecurringTasksPerBlock (r:1 w:1)
+	
+	// Storage: Task Tasks (r:0 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn spawn_occurrence() -> Weight {
+		(47_260_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task Milestones (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Task ExpiringMilestonesPerBlock (r:1 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:2)
+	
+	fn expire_milestones() -> Weight {
+		(38_540_000 as Weight)
+			
+			
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			
+			
+	}
+	
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	
+	
+	// Storage: Profile Profiles (r:1 w:0)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task Tasks (r:0 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn create_task(s: u32, _x: u32, ) -> Weight {
+		(25_626_000 as Weight)
+			
+			// Standard Error: 0
+			.saturating_add((3_000 as Weight).saturating_mul(s as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Profile Profiles (r:1 w:0)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:2)
+	
+	fn update_task(s: u32, _x: u32, ) -> Weight {
+		(28_208_000 as Weight)
+			
+			// Standard Error: 0
+			.saturating_add((1_000 as Weight).saturating_mul(s as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:2 w:2)
+	
+	// Storage: Task Bonds (r:0 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	fn start_task(s: u32, _x: u32, ) -> Weight {
+		(25_492_000 as Weight)
+			
+			// Standard Error: 0
+			.saturating_add((5_000 as Weight).saturating_mul(s as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn remove_task(_s: u32, _x: u32, ) -> Weight {
+		(23_267_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:2 w:2)
+	
+	// Storage: Task ReviewsEndingPerBlock (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	fn complete_task(s: u32, _x: u32, ) -> Weight {
+		(26_754_000 as Weight)
+			
+			// Standard Error: 0
+			.saturating_add((1_000 as Weight).saturating_mul(s as Weight))
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Profile Profiles (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Profile CompletedTasks (r:1 w:1)
+	
+	// Storage: Task PrunableReceiptsPerBlock (r:1 w:1)
+	
+	// Storage: Task ReceiptsByAccount (r:2 w:2)
+	
+	// Storage: Task ReceiptsByOrganization (r:1 w:1)
+	
+	// Storage: Task Receipts (r:0 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Task VestingSchedules (r:1 w:1)
+	
+	// Storage: Task Bonds (r:1 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn accept_task(_s: u32, _x: u32, ) -> Weight {
+		(61_240_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(16 as Weight))
+			
+			
+	}
+	
+	
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:2 w:2)
+	
+	// Storage: Task ReviewsEndingPerBlock (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	fn reject_task(_s: u32, _x: u32, ) -> Weight {
+		(28_202_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			
+			
+	}
+
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	fn revive_task(_s: u32, _x: u32, ) -> Weight {
+		(28_202_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Milestones (r:1 w:1)
+	
+	// Storage: Task ExpiringMilestonesPerBlock (r:1 w:1)
+	
+	fn add_milestone() -> Weight {
+		(24_310_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Milestones (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	fn submit_milestone() -> Weight {
+		(21_870_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Milestones (r:1 w:1)
+	
+	// Storage: System Account (r:3 w:3)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	fn accept_milestone() -> Weight {
+		(39_540_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Milestones (r:1 w:1)
+	
+	fn reject_milestone() -> Weight {
+		(22_105_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Profile Profiles (r:1 w:0)
+	
+	// Storage: Task Applications (r:1 w:1)
+	
+	fn apply_for_task() -> Weight {
+		(25_940_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Applications (r:1 w:1)
+	
+	fn withdraw_application() -> Weight {
+		(17_330_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Applications (r:1 w:1)
+	
+	fn shortlist_application() -> Weight {
+		(20_615_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task Applications (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:2 w:2)
+	
+	// Storage: Task ExpiringTasksPerBlock (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	fn select_application() -> Weight {
+		(58_270_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task Milestones (r:1 w:0)
+	
+	fn set_volunteer_slots(s: u32, ) -> Weight {
+		(21_480_000 as Weight)
+			
+			// Standard Error: 2_000
+			.saturating_add((412_000 as Weight).saturating_mul(s as Weight))
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task ArbitratorPool (r:1 w:0)
+	
+	// Storage: Randomness CollectiveFlip RandomMaterial (r:1 w:0)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Task DisputesEndingPerBlock (r:1 w:1)
+	
+	// Storage: Task Disputes (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	fn open_dispute() -> Weight {
+		(61_350_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			
+			
+	}
+
+	// Storage: Task Disputes (r:1 w:1)
+	
+	// Storage: Task DisputesEndingPerBlock (r:1 w:1)
+	
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: System Account (r:3 w:3)
+	
+	// Storage: Task TaskCount (r:0 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn vote_on_dispute() -> Weight {
+		(97_820_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+			
+			
+	}
+
+	// Storage: Task ArbitratorPool (r:0 w:1)
+	
+	fn set_arbitrator_pool(s: u32, ) -> Weight {
+		(9_870_000 as Weight)
+			
+			// Standard Error: 1_000
+			.saturating_add((96_000 as Weight).saturating_mul(s as Weight))
+			
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:2 w:2)
+	
+	// Storage: Profile Profiles (r:1 w:0)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task ExpiringTasksPerBlock (r:1 w:1)
+	
+	// Storage: Task Subtasks (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn create_subtask() -> Weight {
+		(68_150_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:2 w:0)
+	
+	// Storage: Task Dependencies (r:1 w:1)
+	
+	// Storage: Task Dependents (r:1 w:1)
+	
+	fn add_dependency() -> Weight {
+		(24_310_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Dependencies (r:1 w:1)
+	
+	// Storage: Task Dependents (r:1 w:1)
+	
+	fn remove_dependency() -> Weight {
+		(19_870_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:2 w:2)
+	
+	// Storage: Profile Profiles (r:1 w:1)
+	
+	// Storage: Task Milestones (r:1 w:1)
+	
+	// Storage: Task ExpiringTasksPerBlock (r:1 w:1)
+	
+	// Storage: Task Bonds (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	fn reclaim_task() -> Weight {
+		(48_620_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:2 w:2)
+	
+	// Storage: Profile Profiles (r:1 w:1)
+	
+	// Storage: Task Milestones (r:1 w:1)
+	
+	// Storage: Task ExpiringTasksPerBlock (r:1 w:1)
+	
+	// Storage: Task Bonds (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	fn abandon_task() -> Weight {
+		(47_910_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+			
+			
+	}
+
+	// Storage: Profile Profiles (r:1 w:0)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Assets Account (r:2 w:2)
+	
+	// Storage: Assets Asset (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task Tasks (r:0 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn create_asset_task() -> Weight {
+		(41_318_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Profile Profiles (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Assets Account (r:3 w:3)
+	
+	// Storage: Assets Asset (r:1 w:1)
+	
+	// Storage: Profile CompletedTasks (r:1 w:1)
+	
+	// Storage: Task PrunableReceiptsPerBlock (r:1 w:1)
+	
+	// Storage: Task ReceiptsByAccount (r:2 w:2)
+	
+	// Storage: Task ReceiptsByOrganization (r:1 w:1)
+	
+	// Storage: Task Receipts (r:0 w:1)
+	
+	// Storage: Task VestingSchedules (r:1 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn accept_asset_task() -> Weight {
+		(78_905_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(18 as Weight))
+			
+			
+	}
+
+	// Storage: Profile Profiles (r:1 w:0)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Task RecurringTasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task ExpiringTasksPerBlock (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Task RecurringTasksPerBlock (r:1 w:1)
+	
+	// Storage: Task Tasks (r:0 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	fn create_recurring_task() -> Weight {
+		(52_740_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			
+			
+	}
+
+	// Storage: Task RecurringTasks (r:1 w:1)
+	
+	// Storage: Task RecurringTasksPerBlock (r:1 w:1)
+	
+	fn cancel_recurring_task() -> Weight {
+		(19_870_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			
+			
+	}
+
+	// Storage: Dao Organizations (r:1 w:0)
+	
+	// Storage: Dao Members (r:1 w:0)
+	
+	// Storage: Task Templates (r:1 w:1)
+	
+	fn create_template() -> Weight {
+		(18_430_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Templates (r:1 w:1)
+	
+	// Storage: Dao Members (r:1 w:0)
+	
+	fn update_template() -> Weight {
+		(17_210_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Templates (r:1 w:1)
+	
+	// Storage: Dao Members (r:1 w:0)
+	
+	fn remove_template() -> Weight {
+		(16_540_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Templates (r:1 w:0)
+	
+	// Storage: Dao Members (r:1 w:0)
+	
+	// Storage: Profile Profiles (r:1 w:0)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task Tasks (r:0 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn create_task_from_template() -> Weight {
+		(31_980_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			
+			
+	}
+
+	// Storage: Dao Organizations (r:1 w:0)
+	
+	// Storage: Dao Treasurers (r:1 w:0)
+	
+	// Storage: System Account (r:1 w:1)
+	
+	// Storage: Profile Profiles (r:1 w:0)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task Tasks (r:0 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn create_organization_task() -> Weight {
+		(29_874_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			
+			
+	}
+
+	// Storage: Task Receipts (r:1 w:0)
+	
+	// Storage: Task Ratings (r:1 w:1)
+	
+	// Storage: Profile Profiles (r:1 w:1)
+	
+	// Storage: Task RatingsByAccount (r:1 w:1)
+	
+	fn rate_task() -> Weight {
+		(19_870_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Task DyingTasksPerBlock (r:1 w:1)
+	
+	// Storage: Task TasksByCell (r:1 w:1)
+	
+	// Storage: Task ExpiringTasksPerBlock (r:0 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	fn expire_task() -> Weight {
+		(21_734_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:2 w:1)
+	
+	// Storage: Task Subtasks (r:1 w:1)
+	
+	// Storage: Task TasksByCell (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task Milestones (r:0 w:1)
+	
+	// Storage: Task Applications (r:0 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn remove_dead_task() -> Weight {
+		(47_318_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Visibility (r:0 w:1)
+	
+	fn set_task_visibility() -> Weight {
+		(17_208_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Invitations (r:1 w:1)
+	
+	fn invite_volunteer() -> Weight {
+		(19_542_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Invitations (r:1 w:1)
+	
+	fn revoke_invitation() -> Weight {
+		(19_871_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task Invitations (r:1 w:1)
+	
+	fn decline_invitation() -> Weight {
+		(18_936_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:0)
+	
+	// Storage: Task VestingSchedules (r:0 w:1)
+	
+	fn set_vesting_schedule() -> Weight {
+		(19_870_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Disputes (r:1 w:0)
+	
+	// Storage: Task VestingPayouts (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	fn claim_vested() -> Weight {
+		(42_310_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	fn set_volunteer_bond() -> Weight {
+		(19_640_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			
+			
+	}
+
+	// Storage: Task Disputes (r:1 w:1)
+	
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: System Account (r:3 w:3)
+	
+	// Storage: Task Bonds (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:2 w:2)
+	
+	// Storage: Task TasksByCell (r:1 w:1)
+	
+	// Storage: Task Subtasks (r:1 w:1)
+	
+	// Storage: Task Dependencies (r:1 w:1)
+	
+	// Storage: Task DisputesEndingPerBlock (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task Milestones (r:0 w:1)
+	
+	// Storage: Task Applications (r:0 w:1)
+	
+	// Storage: Task Visibility (r:0 w:1)
+	
+	// Storage: Task Invitations (r:0 w:1)
+	
+	// Storage: Task Submissions (r:0 w:1)
+	
+	// Storage: Task VestingSchedules (r:0 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:2)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn resolve_dispute() -> Weight {
+		(92_415_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(23 as Weight))
+			
+			
+	}
+
+	// Storage: Task Receipts (r:1 w:1)
+	
+	// Storage: Task ReceiptsByAccount (r:0 w:1)
+	
+	// Storage: Task ReceiptsByOrganization (r:0 w:1)
+	
+	fn prune_receipt() -> Weight {
+		(24_310_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:2)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task ReviewsEndingPerBlock (r:2 w:2)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: System Account (r:3 w:3)
+	
+	// Storage: Task Bonds (r:1 w:1)
+	
+	// Storage: Task Receipts (r:0 w:1)
+	
+	// Storage: Task PrunableReceiptsPerBlock (r:1 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn auto_accept_task() -> Weight {
+		(63_180_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(17 as Weight))
+			
+			
+	}
+
+	// Storage: Task RecurringTasks (r:1 w:1)
+	
+	// Storage: Task TasksOwned (r:1 w:1)
+	
+	// Storage: Task TaskCount (r:1 w:1)
+	
+	// Storage: Task ExpiringTasksPerBlock (r:1 w:1)
+	
+	// Storage: System Account (r:2 w:2)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: Task RecurringTasksPerBlock (r:1 w:1)
+	
+	// Storage: Task Tasks (r:0 w:1)
+	
+	// Storage: Task TasksByStatus (r:0 w:1)
+	
+	// Storage: Task TasksByOrganization (r:0 w:1)
+	
+	fn spawn_occurrence() -> Weight {
+		(47_260_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+			
+			
+	}
+
+	// Storage: Task Tasks (r:1 w:1)
+	
+	// Storage: Task Milestones (r:1 w:1)
+	
+	// Storage: Timestamp Now (r:1 w:0)
+	
+	// Storage: Task ExpiringMilestonesPerBlock (r:1 w:1)
+	
+	// Storage: Task Escrows (r:1 w:1)
+	
+	// Storage: System Account (r:1 w:2)
+	
+	fn expire_milestones() -> Weight {
+		(38_540_000 as Weight)
+			
+			
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			
+			
+			
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
 			
 			
 	}
@@ -19,7 +19,7 @@ use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys, Percent,
 	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature, BoundedVec
+	ApplyExtrinsicResult, DispatchResult, MultiSignature, BoundedVec
 };
 use scale_info::TypeInfo;
 use codec::{Encode, MaxEncodedLen};
@@ -70,6 +70,9 @@ pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::Account
 /// Balance of an account.
 pub type Balance = u128;
 
+/// Identifier of an asset.
+pub type AssetId = u32;
+
 /// Index of a transaction in the chain.
 pub type Index = u32;
 
@@ -112,7 +115,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 104,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -262,6 +265,32 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const AssetDeposit: Balance = 100 * DOLLARS;
+	pub const AssetAccountDeposit: Balance = 1 * DOLLARS;
+	pub const ApprovalDeposit: Balance = 1 * DOLLARS;
+	pub const MetadataDepositBase: Balance = 10 * DOLLARS;
+	pub const MetadataDepositPerByte: Balance = 1 * DOLLARS;
+	pub const StringLimit: u32 = 50;
+}
+
+impl pallet_assets::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type AssetId = AssetId;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_transaction_payment::Config for Runtime {
 	type Event = Event;
 	type OnChargeTransaction = CurrencyAdapter<Balances, DealWithFees<Runtime>>;
@@ -290,8 +319,65 @@ parameter_types! {
 	pub const MaxFeedbackLen: u32 = 5000;
 	#[derive(TypeInfo, MaxEncodedLen, Encode)]
 	pub const MaxKeywordsLen: u32 = 100;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxMilestonesPerTask: u32 = 20;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxApplicationsPerTask: u32 = 50;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxCoverNoteLen: u32 = 1000;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxVolunteerSlots: u32 = 10;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const ArbitratorsPerDispute: u32 = 3;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxArbitratorPool: u32 = 100;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxReceiptsPerAccount: u32 = 1000;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxReceiptsPerOrganization: u32 = 10_000;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxSubtasksPerTask: u32 = 50;
+	pub const MaxSubtaskDepth: u32 = 5;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxDependenciesPerTask: u32 = 20;
+	pub const MaxTasksWalked: u32 = 64;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxInvitationsPerTask: u32 = 20;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxDeliverableReferenceLen: u32 = 128;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxDeliverableDescriptionLen: u32 = 500;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxDeliverablesPerSubmission: u32 = 20;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxSubmissionsPerTask: u32 = 10;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxReviewLen: u32 = 500;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxRatingsPerAccount: u32 = 1000;
+	#[derive(TypeInfo, MaxEncodedLen, Encode)]
+	pub const MaxTasksPerCell: u32 = 1000;
+	// 1 year
+	pub const ReceiptLongevity: BlockNumber = 365 * DAYS;
+	pub const DisputeDeposit: Balance = 10 * DOLLARS;
+	pub const PlatformFee: Permill = Permill::from_percent(2);
+	pub const OrganizationFee: Permill = Permill::from_percent(1);
+	// 3 days
+	pub const DisputeVotingPeriod: BlockNumber = 3 * DAYS;
 	// 2 weeks
 	pub const TaskLongevityAfterExpiration: BlockNumber = 14 * DAYS;
+	// 10% of the block, the rest of the expiring tasks is processed with the weight left at the end of the block
+	pub MaxExpiryWeight: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+	// 10% of the block, the rest of the scheduled work is processed with the weight left at the end of the block
+	pub MaxScheduledWeight: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+	// 1 week
+	pub const DeliveryPeriod: BlockNumber = 7 * DAYS;
+	// 3 days
+	pub const ReviewPeriod: BlockNumber = 3 * DAYS;
+	// 2 weeks
+	pub const RatingPeriod: BlockNumber = 14 * DAYS;
+	// 1 day
+	pub const MinRecurrenceInterval: BlockNumber = 1 * DAYS;
 	pub const MilisPerBlock: u64 = MILLISECS_PER_BLOCK; 
 }
 
@@ -299,7 +385,11 @@ parameter_types! {
 impl pallet_task::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
+	type AssetId = AssetId;
+	type Assets = Assets;
 	type Organization = Runtime;
+	type Profiles = Runtime;
+	type LifecycleHooks = (ProfileBookkeeping,);
 	type MaxTasksOwned = MaxTasksOwned;
 	type Time = Timestamp;
 	type WeightInfo = pallet_task::weights::SubstrateWeight<Runtime>;
@@ -309,15 +399,98 @@ impl pallet_task::Config for Runtime {
 	type MaxAttachmentsLen = MaxAttachmentsLen;
 	type MaxFeedbackLen = MaxFeedbackLen;
 	type MaxKeywordsLen = MaxKeywordsLen;
+	type MaxMilestonesPerTask = MaxMilestonesPerTask;
+	type MaxApplicationsPerTask = MaxApplicationsPerTask;
+	type MaxCoverNoteLen = MaxCoverNoteLen;
+	type MaxVolunteerSlots = MaxVolunteerSlots;
+	type Randomness = RandomnessCollectiveFlip;
+	type DisputeDeposit = DisputeDeposit;
+	type DisputeVotingPeriod = DisputeVotingPeriod;
+	type DisputeSlash = Treasury;
+	type PlatformFee = PlatformFee;
+	type OrganizationFee = OrganizationFee;
+	type PlatformFeeDestination = Treasury;
+	type PlatformFeeAccount = TreasuryAccountID;
+	type ArbitratorsPerDispute = ArbitratorsPerDispute;
+	type MaxArbitratorPool = MaxArbitratorPool;
+	type ReceiptLongevity = ReceiptLongevity;
+	type MaxReceiptsPerAccount = MaxReceiptsPerAccount;
+	type MaxReceiptsPerOrganization = MaxReceiptsPerOrganization;
+	type MaxSubtasksPerTask = MaxSubtasksPerTask;
+	type MaxSubtaskDepth = MaxSubtaskDepth;
+	type MaxDependenciesPerTask = MaxDependenciesPerTask;
+	type MaxTasksWalked = MaxTasksWalked;
+	type MaxInvitationsPerTask = MaxInvitationsPerTask;
+	type MaxDeliverableReferenceLen = MaxDeliverableReferenceLen;
+	type MaxDeliverableDescriptionLen = MaxDeliverableDescriptionLen;
+	type MaxDeliverablesPerSubmission = MaxDeliverablesPerSubmission;
+	type MaxSubmissionsPerTask = MaxSubmissionsPerTask;
 	type MillisecondsPerBlock = MilisPerBlock;
 	type TaskLongevityAfterExpiration = TaskLongevityAfterExpiration;
+	type MaxExpiryWeight = MaxExpiryWeight;
+	type MaxScheduledWeight = MaxScheduledWeight;
+	type DeliveryPeriod = DeliveryPeriod;
+	type ReviewPeriod = ReviewPeriod;
+	type MinRecurrenceInterval = MinRecurrenceInterval;
+	type RatingPeriod = RatingPeriod;
+	type MaxReviewLen = MaxReviewLen;
+	type MaxRatingsPerAccount = MaxRatingsPerAccount;
+	type MaxTasksPerCell = MaxTasksPerCell;
 
 }
 
-impl pallet_task::traits::Organization<Hash> for Runtime {
+impl pallet_task::traits::Organization<Hash, AccountId> for Runtime {
 	fn exists(id: &Hash) -> bool {
 		Dao::does_organization_exist(id)
 	}
+
+	fn members(id: &Hash) -> Vec<AccountId> {
+		Dao::members(id).into_inner()
+	}
+
+	fn account(id: &Hash) -> AccountId {
+		Dao::account_id(id)
+	}
+
+	fn can_spend(id: &Hash, who: &AccountId) -> bool {
+		Dao::can_spend(id, who)
+	}
+}
+
+impl pallet_task::traits::Profiles<AccountId> for Runtime {
+	fn exists(who: &AccountId) -> bool {
+		Profile::profiles(who).is_some()
+	}
+
+	fn location(who: &AccountId) -> Option<pallet_task::NadLocation> {
+		Profile::profiles(who).and_then(|profile| profile.location)
+	}
+}
+
+/// Keeps the reputation, the completed tasks and the ratings of profiles up to date with the lifecycle of tasks.
+pub struct ProfileBookkeeping;
+
+impl pallet_task::traits::TaskLifecycleHooks<AccountId, Hash> for ProfileBookkeeping {
+	fn on_accepted(task_id: &Hash, initiator: &AccountId, volunteers: &[AccountId]) -> DispatchResult {
+		Profile::add_reputation(initiator)?;
+		for volunteer in volunteers {
+			Profile::add_reputation(volunteer)?;
+			Profile::add_task_to_completed_tasks(volunteer, *task_id)?;
+		}
+		Ok(())
+	}
+
+	fn on_abandoned(_task_id: &Hash, volunteers: &[AccountId]) -> DispatchResult {
+		for volunteer in volunteers {
+			// Volunteers without a profile have no reputation to lose
+			let _ = Profile::remove_reputation(volunteer);
+		}
+		Ok(())
+	}
+
+	fn on_rated(_task_id: &Hash, _reviewer: &AccountId, reviewee: &AccountId, rating: u8) -> DispatchResult {
+		Profile::add_rating(reviewee, rating)
+	}
 }
 
 parameter_types! {
@@ -474,6 +647,7 @@ construct_runtime!(
 		Did: pallet_did::{Pallet, Call, Storage, Event<T>},
 		Grant: pallet_grant::{Pallet, Call, Storage, Event<T>},
 		Treasury: pallet_treasury,
+		Assets: pallet_assets,
 	}
 );
 
@@ -498,6 +672,17 @@ pub type SignedExtra = (
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
+/// Storage migrations run on runtime upgrade, before the hooks of the pallets.
+pub type Migrations = (
+	pallet_profile::migrations::v1::MigrateToV1<Runtime>,
+	pallet_task::migrations::v1::MigrateToV1<Runtime>,
+	pallet_task::migrations::v2::MigrateToV2<Runtime>,
+	pallet_task::migrations::v3::MigrateToV3<Runtime>,
+	pallet_task::migrations::v4::MigrateToV4<Runtime>,
+	pallet_dao::migrations::v1::MigrateToV1<Runtime>,
+	pallet_grant::migrations::v1::MigrateToV1<Runtime>,
+	pallet_did::migrations::v1::MigrateToV1<Runtime>,
+);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -505,6 +690,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -517,6 +703,7 @@ mod benches {
 		[frame_benchmarking, BaselineBench::<Runtime>]
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_balances, Balances]
+		[pallet_assets, Assets]
 		[pallet_timestamp, Timestamp]
 		[pallet_profile, Profile]
 		[pallet_task, Task]
@@ -657,6 +844,60 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_task_rpc_runtime_api::TaskApi<Block, AccountId, AssetId, Balance, BlockNumber, Hash> for Runtime {
+		fn tasks_by_status(
+			status: pallet_task::TaskStatus,
+			cursor: Option<Hash>,
+			limit: u32,
+		) -> pallet_task_rpc_runtime_api::TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash> {
+			Task::tasks_by_status(status, cursor, limit).into()
+		}
+		fn tasks_by_owner(
+			owner: AccountId,
+			cursor: Option<Hash>,
+			limit: u32,
+		) -> pallet_task_rpc_runtime_api::TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash> {
+			Task::tasks_by_owner(&owner, cursor, limit).into()
+		}
+		fn tasks_by_organization(
+			organization: Hash,
+			cursor: Option<Hash>,
+			limit: u32,
+		) -> pallet_task_rpc_runtime_api::TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash> {
+			Task::tasks_by_organization(organization, cursor, limit).into()
+		}
+		fn tasks_by_keyword(
+			keyword: Vec<u8>,
+			cursor: Option<Hash>,
+			limit: u32,
+		) -> pallet_task_rpc_runtime_api::TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash> {
+			Task::tasks_by_keyword(&keyword, cursor, limit).into()
+		}
+		fn tasks_expiring_before(
+			block: BlockNumber,
+			cursor: Option<Hash>,
+			limit: u32,
+		) -> pallet_task_rpc_runtime_api::TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash> {
+			Task::tasks_expiring_before(block, cursor, limit).into()
+		}
+		fn tasks_in_area(
+			min: pallet_task::NadLocation,
+			max: pallet_task::NadLocation,
+			cursor: Option<Hash>,
+			limit: u32,
+		) -> pallet_task_rpc_runtime_api::TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash> {
+			Task::tasks_in_area(min, max, cursor, limit).into()
+		}
+		fn tasks_near(
+			account: AccountId,
+			radius: u16,
+			cursor: Option<Hash>,
+			limit: u32,
+		) -> pallet_task_rpc_runtime_api::TaskPage<AccountId, AssetId, Balance, BlockNumber, Hash> {
+			Task::tasks_near(&account, radius, cursor, limit).into()
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (